@@ -0,0 +1 @@
+pub mod get_pull_request_analytics;
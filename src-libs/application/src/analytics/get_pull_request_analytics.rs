@@ -0,0 +1,259 @@
+use crate::{
+    dtos::{DurationStatisticsDto, PullRequestAnalyticsDto, PullRequestMetricsDto},
+    traits::{GitRepositoryRepository, PullRequestLifecycleRepository, RepositoryGroupRepository},
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use domain::{enums::PullRequestStatus, models::PullRequestLifecycle};
+use std::collections::BTreeMap;
+
+/// Responsible for measuring the review process based on the recorded
+/// lifecycles of pull requests
+pub struct PullRequestAnalyticsQuery<L, G, M>
+where
+    L: PullRequestLifecycleRepository,
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+{
+    pull_request_lifecycle_repository: L,
+    git_repository_repository: G,
+    repository_group_repository: M,
+}
+
+impl<L, G, M> PullRequestAnalyticsQuery<L, G, M>
+where
+    L: PullRequestLifecycleRepository,
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_lifecycle_repository` - The repository to access the lifecycles of pull requests
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(
+        pull_request_lifecycle_repository: L,
+        git_repository_repository: G,
+        repository_group_repository: M,
+    ) -> Self {
+        Self {
+            pull_request_lifecycle_repository,
+            git_repository_repository,
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// The time to first review covers the pull requests created within the
+    /// range, the time to merge and the throughput the ones closed within it.
+    /// Repositories, groups and authors without any pull request within the
+    /// range are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The inclusive start of the date range
+    /// * `to` - The exclusive end of the date range
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PullRequestAnalyticsDto>` - The metrics in total and per repository, group and author
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the date range is empty
+    pub async fn execute(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<PullRequestAnalyticsDto> {
+        if from >= to {
+            return Err(anyhow::anyhow!(
+                "The start of the date range must be before its end"
+            ));
+        }
+        let lifecycles = self
+            .pull_request_lifecycle_repository
+            .get_pull_request_lifecycles_in_range(from, to)
+            .await?;
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let repository_groups = self
+            .repository_group_repository
+            .get_all_repository_groups()
+            .await?;
+        let all = lifecycles.iter().collect::<Vec<&PullRequestLifecycle>>();
+        let mut repositories = git_repos
+            .iter()
+            .map(|gr| {
+                let lifecycles = lifecycles
+                    .iter()
+                    .filter(|x| x.git_repository_id == Some(gr.id))
+                    .collect::<Vec<&PullRequestLifecycle>>();
+                to_metrics(Some(gr.id), &gr.name, &lifecycles, from, to)
+            })
+            .filter(has_activity)
+            .collect::<Vec<PullRequestMetricsDto>>();
+        // The pull requests of removed git repositories are kept by their name
+        let mut lifecycles_of_removed = BTreeMap::<&str, Vec<&PullRequestLifecycle>>::new();
+        for lifecycle in lifecycles.iter().filter(|x| x.git_repository_id.is_none()) {
+            lifecycles_of_removed
+                .entry(&lifecycle.git_repository_name)
+                .or_default()
+                .push(lifecycle);
+        }
+        repositories.extend(
+            lifecycles_of_removed
+                .into_iter()
+                .map(|(name, lifecycles)| to_metrics(None, name, &lifecycles, from, to))
+                .filter(has_activity),
+        );
+        repositories.sort_by(|a, b| a.name.cmp(&b.name));
+        let groups = repository_groups
+            .iter()
+            .map(|group| {
+                let lifecycles = lifecycles
+                    .iter()
+                    .filter(|x| {
+                        x.git_repository_id
+                            .is_some_and(|id| group.git_repository_ids.contains(&id))
+                    })
+                    .collect::<Vec<&PullRequestLifecycle>>();
+                to_metrics(Some(group.id), &group.name, &lifecycles, from, to)
+            })
+            .filter(has_activity)
+            .collect::<Vec<PullRequestMetricsDto>>();
+        // Authors are told apart by their id, as display names are neither
+        // unique nor stable
+        let mut lifecycles_by_author = BTreeMap::<&str, Vec<&PullRequestLifecycle>>::new();
+        for lifecycle in &lifecycles {
+            lifecycles_by_author
+                .entry(&lifecycle.creator_id)
+                .or_default()
+                .push(lifecycle);
+        }
+        let mut authors = lifecycles_by_author
+            .into_values()
+            .filter_map(|lifecycles| {
+                let latest = lifecycles.iter().max_by_key(|x| x.created_at)?;
+                Some(to_metrics(
+                    None,
+                    &latest.creator_name,
+                    &lifecycles,
+                    from,
+                    to,
+                ))
+            })
+            .filter(has_activity)
+            .collect::<Vec<PullRequestMetricsDto>>();
+        authors.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(PullRequestAnalyticsDto {
+            from: *from,
+            to: *to,
+            total: to_metrics(None, "Total", &all, from, to),
+            repositories,
+            groups,
+            authors,
+        })
+    }
+}
+
+/// Helper to compute the metrics of a set of pull requests within a date range
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the git repository or repository group
+/// * `name` - The name of what the pull requests belong to
+/// * `lifecycles` - The lifecycles of the pull requests
+/// * `from` - The inclusive start of the date range
+/// * `to` - The exclusive end of the date range
+fn to_metrics(
+    id: Option<u32>,
+    name: &str,
+    lifecycles: &[&PullRequestLifecycle],
+    from: &DateTime<Utc>,
+    to: &DateTime<Utc>,
+) -> PullRequestMetricsDto {
+    let is_in_range = |x: &DateTime<Utc>| x >= from && x < to;
+    let created = lifecycles
+        .iter()
+        .filter(|x| is_in_range(&x.created_at))
+        .collect::<Vec<&&PullRequestLifecycle>>();
+    let closed_with_status = |status: PullRequestStatus| {
+        lifecycles
+            .iter()
+            .filter(move |x| x.status == status && x.closed_at.as_ref().is_some_and(is_in_range))
+    };
+    let time_to_first_review = created
+        .iter()
+        .filter_map(|x| Some(to_hours(x.created_at, x.first_review_at()?)))
+        .collect();
+    let time_to_merge = closed_with_status(PullRequestStatus::Completed)
+        .filter_map(|x| Some(to_hours(x.created_at, x.closed_at?)))
+        .collect();
+    PullRequestMetricsDto {
+        id,
+        name: name.to_string(),
+        created_count: created.len(),
+        completed_count: closed_with_status(PullRequestStatus::Completed).count(),
+        abandoned_count: closed_with_status(PullRequestStatus::Abandoned).count(),
+        time_to_first_review: to_statistics(time_to_first_review),
+        time_to_merge: to_statistics(time_to_merge),
+    }
+}
+
+/// Helper to check whether any pull request was created or closed within the date range
+///
+/// # Arguments
+///
+/// * `metrics` - The metrics within the date range
+fn has_activity(metrics: &PullRequestMetricsDto) -> bool {
+    metrics.created_count + metrics.completed_count + metrics.abandoned_count > 0
+}
+
+/// Helper to get the hours between two dates, never negative
+///
+/// # Arguments
+///
+/// * `start` - The start date
+/// * `end` - The end date
+fn to_hours(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    ((end - start).num_seconds().max(0) as f64) / 3600.0
+}
+
+/// Helper to compute the median and 90th percentile of durations
+///
+/// # Arguments
+///
+/// * `hours` - The durations in hours
+fn to_statistics(mut hours: Vec<f64>) -> DurationStatisticsDto {
+    hours.sort_by(f64::total_cmp);
+    DurationStatisticsDto {
+        count: hours.len(),
+        median_hours: percentile(&hours, 0.5),
+        p90_hours: percentile(&hours, 0.9),
+    }
+}
+
+/// Helper to compute a percentile by linear interpolation between the
+/// closest ranks
+///
+/// # Arguments
+///
+/// * `sorted` - The values in ascending order
+/// * `p` - The percentile between 0 and 1
+///
+/// # Returns
+///
+/// * `Option<f64>` - The percentile, none without values
+fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = p * last as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64))
+}
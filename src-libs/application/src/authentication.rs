@@ -0,0 +1,12 @@
+pub mod complete_device_code_sign_in;
+pub mod start_device_code_sign_in;
+
+/// Get the key the OAuth refresh token of an Azure DevOps organization
+/// is stored with in the secret storage
+///
+/// # Arguments
+///
+/// * `organization_name` - The name of the Azure DevOps organization
+pub fn oauth_credential_key(organization_name: &str) -> String {
+    format!("azuredevops-oauth-{}", organization_name)
+}
@@ -0,0 +1,122 @@
+use super::oauth_credential_key;
+use crate::traits::{GitRepositoryRepository, OAuthRepository, SecretRepository};
+use anyhow::Result;
+use domain::{
+    enums::{CredentialType, GitProvider},
+    models::GitRepository,
+};
+use std::collections::HashSet;
+
+/// Responsible for completing an OAuth sign in using the device code flow
+/// and switching an Azure DevOps organization to use it
+pub struct CompleteDeviceCodeSignInCommand<O, G, S>
+where
+    O: OAuthRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    oauth_repository: O,
+    git_repository_repository: G,
+    secret_repository: S,
+}
+
+impl<O, G, S> CompleteDeviceCodeSignInCommand<O, G, S>
+where
+    O: OAuthRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `oauth_repository` - The repository to access the identity provider
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `secret_repository` - The repository to access secrets
+    pub fn new(oauth_repository: O, git_repository_repository: G, secret_repository: S) -> Self {
+        Self {
+            oauth_repository,
+            git_repository_repository,
+            secret_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// Polls the identity provider once. When the user completed the sign in,
+    /// the refresh token is stored and all imported git repositories of the
+    /// organization are switched to authenticate with it. The PATs they used
+    /// before are removed, unless other git repositories still use them.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_name` - The name of the Azure DevOps organization to sign in for
+    /// * `device_code` - The device code of the started sign in
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether the sign in is completed, `false` while still pending
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the sign in was declined or expired
+    pub async fn execute(&self, organization_name: &str, device_code: &str) -> Result<bool> {
+        let Some(tokens) = self
+            .oauth_repository
+            .poll_device_code_sign_in(device_code)
+            .await?
+        else {
+            return Ok(false);
+        };
+        let refresh_token = tokens.refresh_token.ok_or_else(|| {
+            anyhow::anyhow!("The identity provider did not issue a refresh token")
+        })?;
+        let credential_key = oauth_credential_key(organization_name);
+        self.secret_repository
+            .set_secret(&credential_key, &refresh_token)
+            .await?;
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let (switched_git_repos, other_git_repos): (Vec<GitRepository>, Vec<GitRepository>) =
+            git_repos.into_iter().partition(|x| {
+                matches!(x.git_provider, GitProvider::AzureDevOps)
+                    && x.context.split('/').next() == Some(organization_name)
+            });
+        if switched_git_repos.is_empty() {
+            return Ok(true);
+        }
+        // Only remove the previous secrets no longer referenced by any other
+        // git repository, each of them once
+        let remaining_secret_keys = other_git_repos
+            .iter()
+            .map(|x| x.credential_key.as_str())
+            .chain([credential_key.as_str()])
+            .collect::<HashSet<&str>>();
+        let obsolete_secret_keys = switched_git_repos
+            .iter()
+            .map(|x| x.credential_key.to_string())
+            .filter(|x| !remaining_secret_keys.contains(x.as_str()))
+            .collect::<HashSet<String>>();
+        self.git_repository_repository
+            .update_git_repositories(
+                switched_git_repos
+                    .into_iter()
+                    .map(|x| GitRepository {
+                        credential_type: CredentialType::OAuth,
+                        credential_key: credential_key.to_string(),
+                        ..x
+                    })
+                    .collect(),
+            )
+            .await?;
+        for secret_key in obsolete_secret_keys {
+            // Secrets left behind are reported as orphaned by the secret health check
+            if let Err(err) = self.secret_repository.delete_secret(&secret_key).await {
+                log::warn!("Error deleting secret `{}`: {}", secret_key, err);
+            }
+        }
+        Ok(true)
+    }
+}
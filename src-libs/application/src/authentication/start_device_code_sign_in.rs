@@ -0,0 +1,38 @@
+use crate::{dtos::DeviceCodeChallengeDto, traits::OAuthRepository};
+use anyhow::Result;
+
+/// Responsible for starting an OAuth sign in using the device code flow
+pub struct StartDeviceCodeSignInCommand<O>
+where
+    O: OAuthRepository,
+{
+    oauth_repository: O,
+}
+
+impl<O> StartDeviceCodeSignInCommand<O>
+where
+    O: OAuthRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `oauth_repository` - The repository to access the identity provider
+    pub fn new(oauth_repository: O) -> Self {
+        Self { oauth_repository }
+    }
+
+    /// Execute the command
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DeviceCodeChallengeDto>` - The challenge the user needs to complete in the browser
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<DeviceCodeChallengeDto> {
+        let challenge = self.oauth_repository.start_device_code_sign_in().await?;
+        Ok(challenge.into())
+    }
+}
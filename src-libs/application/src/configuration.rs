@@ -0,0 +1,107 @@
+pub mod export_configuration;
+pub mod import_configuration;
+
+use crate::dtos::{CredentialType, GitProvider, SecretSource};
+use chrono::{DateTime, Utc};
+use domain::models::PassphraseEncryptedData;
+use serde::{Deserialize, Serialize};
+
+/// The version of the configuration file format, increased whenever a
+/// change can not be read by older versions of the application
+const CONFIGURATION_VERSION: u32 = 1;
+
+/// The content of an exported configuration file
+#[derive(Serialize, Deserialize)]
+struct ConfigurationFile {
+    version: u32,
+    #[serde(rename = "exportedAt")]
+    exported_at: DateTime<Utc>,
+    #[serde(rename = "gitRepositories", default)]
+    git_repositories: Vec<ConfigurationGitRepository>,
+    #[serde(rename = "repositoryGroups", default)]
+    repository_groups: Vec<ConfigurationRepositoryGroup>,
+    #[serde(default)]
+    settings: Option<ConfigurationSettings>,
+    /// The PATs by their credential key, only included when exported with a passphrase
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    secrets: Option<ConfigurationSecrets>,
+}
+
+/// An exported git repository
+#[derive(Serialize, Deserialize)]
+struct ConfigurationGitRepository {
+    /// The identifier within the file, the repository groups refer to
+    id: u32,
+    name: String,
+    context: String,
+    #[serde(rename = "gitProvider")]
+    git_provider: GitProvider,
+    #[serde(rename = "isActive")]
+    is_active: bool,
+    #[serde(rename = "credentialType")]
+    credential_type: CredentialType,
+    #[serde(rename = "credentialKey")]
+    credential_key: String,
+    #[serde(rename = "remoteId", default)]
+    remote_id: Option<String>,
+}
+
+/// An exported repository group
+#[derive(Serialize, Deserialize)]
+struct ConfigurationRepositoryGroup {
+    name: String,
+    /// The identifiers of the git repositories within the file
+    #[serde(rename = "gitRepositoryIds", default)]
+    git_repository_ids: Vec<u32>,
+}
+
+/// The exported settings
+///
+/// The secret storage backend is not exported, as it depends on the machine
+/// and changing it would make the already stored secrets inaccessible.
+#[derive(Serialize, Deserialize)]
+struct ConfigurationSettings {
+    #[serde(rename = "secretSources")]
+    secret_sources: Vec<SecretSource>,
+    #[serde(rename = "credentialHelperCommand", default)]
+    credential_helper_command: Option<String>,
+}
+
+/// The exported secrets, encrypted as JSON object with a key derived from
+/// the passphrase entered on export
+#[derive(Serialize, Deserialize)]
+struct ConfigurationSecrets {
+    salt: String,
+    #[serde(rename = "memoryKib")]
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl From<PassphraseEncryptedData> for ConfigurationSecrets {
+    fn from(value: PassphraseEncryptedData) -> Self {
+        Self {
+            salt: value.salt,
+            memory_kib: value.memory_kib,
+            iterations: value.iterations,
+            parallelism: value.parallelism,
+            nonce: value.nonce,
+            ciphertext: value.ciphertext,
+        }
+    }
+}
+
+impl From<&ConfigurationSecrets> for PassphraseEncryptedData {
+    fn from(value: &ConfigurationSecrets) -> Self {
+        Self {
+            salt: value.salt.to_string(),
+            memory_kib: value.memory_kib,
+            iterations: value.iterations,
+            parallelism: value.parallelism,
+            nonce: value.nonce.to_string(),
+            ciphertext: value.ciphertext.to_string(),
+        }
+    }
+}
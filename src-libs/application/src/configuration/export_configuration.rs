@@ -0,0 +1,161 @@
+use super::{
+    CONFIGURATION_VERSION, ConfigurationFile, ConfigurationGitRepository,
+    ConfigurationRepositoryGroup, ConfigurationSettings,
+};
+use crate::traits::{
+    GitRepositoryRepository, PassphraseCipher, RepositoryGroupRepository, SecretRepository,
+    SettingsRepository,
+};
+use anyhow::Result;
+use chrono::Utc;
+use domain::enums::CredentialType;
+use std::collections::BTreeMap;
+
+/// Responsible for exporting the imported git repositories, repository
+/// groups and settings to a versioned JSON document
+pub struct ExportConfigurationQuery<G, M, R, S, C>
+where
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+    R: SettingsRepository,
+    S: SecretRepository,
+    C: PassphraseCipher,
+{
+    git_repository_repository: G,
+    repository_group_repository: M,
+    settings_repository: R,
+    secret_repository: S,
+    passphrase_cipher: C,
+}
+
+impl<G, M, R, S, C> ExportConfigurationQuery<G, M, R, S, C>
+where
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+    R: SettingsRepository,
+    S: SecretRepository,
+    C: PassphraseCipher,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `repository_group_repository` - The repository to access repository groups
+    /// * `settings_repository` - The repository to access the settings
+    /// * `secret_repository` - The repository to access secrets
+    /// * `passphrase_cipher` - The cipher to encrypt the exported secrets with
+    pub fn new(
+        git_repository_repository: G,
+        repository_group_repository: M,
+        settings_repository: R,
+        secret_repository: S,
+        passphrase_cipher: C,
+    ) -> Self {
+        Self {
+            git_repository_repository,
+            repository_group_repository,
+            settings_repository,
+            secret_repository,
+            passphrase_cipher,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// Only PATs are exported as secrets. OAuth sign-ins are bound to the
+    /// signed in user, so they need to be repeated after the import.
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The passphrase to encrypt the secrets with, secrets are excluded without one
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String>` - The configuration as JSON document
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the passphrase is empty
+    pub async fn execute(&self, passphrase: Option<&str>) -> Result<String> {
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let repository_groups = self
+            .repository_group_repository
+            .get_all_repository_groups()
+            .await?;
+        let settings = self.settings_repository.get_settings().await?;
+        let secrets = match passphrase {
+            Some("") => {
+                return Err(anyhow::anyhow!(
+                    "The passphrase to encrypt the secrets with must not be empty"
+                ));
+            }
+            Some(passphrase) => {
+                let mut pats = BTreeMap::<String, String>::new();
+                for gr in git_repos
+                    .iter()
+                    .filter(|x| x.credential_type == CredentialType::Pat)
+                {
+                    if pats.contains_key(&gr.credential_key) {
+                        continue;
+                    }
+                    match self.secret_repository.get_secret(&gr.credential_key).await {
+                        Ok(pat) => {
+                            pats.insert(gr.credential_key.to_string(), pat);
+                        }
+                        Err(err) => log::warn!(
+                            "Secret `{}` is not exported, as it could not be read: {}",
+                            gr.credential_key,
+                            err
+                        ),
+                    }
+                }
+                let plaintext = serde_json::to_vec(&pats)?;
+                Some(
+                    self.passphrase_cipher
+                        .encrypt(passphrase, &plaintext)
+                        .await?
+                        .into(),
+                )
+            }
+            None => None,
+        };
+        let configuration_file = ConfigurationFile {
+            version: CONFIGURATION_VERSION,
+            exported_at: Utc::now(),
+            git_repositories: git_repos
+                .into_iter()
+                .map(|x| ConfigurationGitRepository {
+                    id: x.id,
+                    name: x.name,
+                    context: x.context,
+                    git_provider: x.git_provider.into(),
+                    is_active: x.is_active,
+                    credential_type: x.credential_type.into(),
+                    credential_key: x.credential_key,
+                    remote_id: x.remote_id,
+                })
+                .collect(),
+            repository_groups: repository_groups
+                .into_iter()
+                .map(|x| ConfigurationRepositoryGroup {
+                    name: x.name,
+                    git_repository_ids: x.git_repository_ids,
+                })
+                .collect(),
+            settings: Some(ConfigurationSettings {
+                secret_sources: settings
+                    .secret_sources
+                    .into_iter()
+                    .map(|x| x.into())
+                    .collect(),
+                credential_helper_command: settings.credential_helper_command,
+            }),
+            secrets,
+        };
+        Ok(serde_json::to_string_pretty(&configuration_file)?)
+    }
+}
@@ -0,0 +1,413 @@
+use super::{CONFIGURATION_VERSION, ConfigurationFile, ConfigurationGitRepository};
+use crate::{
+    dtos::{ConfigurationImportResultDto, ConflictResolution, SettingsDto},
+    settings::validate_settings,
+    traits::{
+        GitRepositoryRepository, PassphraseCipher, RepositoryGroupRepository, SecretRepository,
+        SettingsRepository,
+    },
+};
+use anyhow::Result;
+use domain::{
+    errors::SecretNotFoundError,
+    models::{GitRepository, ImportedRepositoryGroup},
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Responsible for importing a configuration exported before, such as on
+/// another machine
+pub struct ImportConfigurationCommand<G, M, R, S, C>
+where
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+    R: SettingsRepository,
+    S: SecretRepository,
+    C: PassphraseCipher,
+{
+    git_repository_repository: G,
+    repository_group_repository: M,
+    settings_repository: R,
+    secret_repository: S,
+    passphrase_cipher: C,
+}
+
+impl<G, M, R, S, C> ImportConfigurationCommand<G, M, R, S, C>
+where
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+    R: SettingsRepository,
+    S: SecretRepository,
+    C: PassphraseCipher,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `repository_group_repository` - The repository to access repository groups
+    /// * `settings_repository` - The repository to access the settings
+    /// * `secret_repository` - The repository to access secrets
+    /// * `passphrase_cipher` - The cipher to decrypt the imported secrets with
+    pub fn new(
+        git_repository_repository: G,
+        repository_group_repository: M,
+        settings_repository: R,
+        secret_repository: S,
+        passphrase_cipher: C,
+    ) -> Self {
+        Self {
+            git_repository_repository,
+            repository_group_repository,
+            settings_repository,
+            secret_repository,
+            passphrase_cipher,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// Git repositories are matched by their remote, repository groups by
+    /// their name. On conflicts, the local configuration is kept when skipping,
+    /// replaced when overwriting and completed by the imported one when merging.
+    /// The whole file is validated and the secrets decrypted before anything
+    /// is changed. The git repositories and repository groups are applied at
+    /// once, the secrets are written last and restored if any of them fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The configuration as JSON document
+    /// * `conflict_resolution` - How to handle what is already configured locally
+    /// * `passphrase` - The passphrase to decrypt the secrets with, secrets are ignored without one
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ConfigurationImportResultDto>` - What was created, updated and skipped
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file is invalid or the passphrase is wrong
+    pub async fn execute(
+        &self,
+        content: &str,
+        conflict_resolution: ConflictResolution,
+        passphrase: Option<&str>,
+    ) -> Result<ConfigurationImportResultDto> {
+        let configuration_file = serde_json::from_str::<ConfigurationFile>(content)
+            .map_err(|err| anyhow::anyhow!("The configuration file is invalid: {}", err))?;
+        if configuration_file.version == 0 || configuration_file.version > CONFIGURATION_VERSION {
+            return Err(anyhow::anyhow!(
+                "The configuration file version `{}` is not supported, please update the application",
+                configuration_file.version
+            ));
+        }
+        let file_ids = configuration_file
+            .git_repositories
+            .iter()
+            .map(|x| x.id)
+            .collect::<HashSet<u32>>();
+        for group in &configuration_file.repository_groups {
+            if group.name.trim().is_empty() {
+                return Err(anyhow::anyhow!(
+                    "The repository group name must not be empty"
+                ));
+            }
+            if let Some(id) = group
+                .git_repository_ids
+                .iter()
+                .find(|x| !file_ids.contains(x))
+            {
+                return Err(anyhow::anyhow!(
+                    "The repository group `{}` refers to the unknown git repository `{}`",
+                    group.name,
+                    id
+                ));
+            }
+        }
+        let mut result = ConfigurationImportResultDto::default();
+        let settings = self
+            .merge_settings(&configuration_file, conflict_resolution)
+            .await?;
+        let pats = match (&configuration_file.secrets, passphrase) {
+            (Some(secrets), Some(passphrase)) => {
+                let plaintext = self
+                    .passphrase_cipher
+                    .decrypt(passphrase, &secrets.into())
+                    .await?;
+                serde_json::from_slice::<BTreeMap<String, String>>(&plaintext)?
+            }
+            (Some(_), None) => {
+                log::info!(
+                    "The secrets of the configuration file are ignored without a passphrase"
+                );
+                BTreeMap::new()
+            }
+            (None, _) => BTreeMap::new(),
+        };
+
+        // Secrets, only checked here as they are written after everything else
+        let mut secrets_to_write = Vec::<(&str, &str, Option<String>)>::new();
+        for (credential_key, pat) in &pats {
+            let previous = match self.secret_repository.get_secret(credential_key).await {
+                Ok(secret) => Some(secret),
+                Err(err) if err.is::<SecretNotFoundError>() => None,
+                Err(err) => return Err(err),
+            };
+            if previous.is_some() && conflict_resolution != ConflictResolution::Overwrite {
+                result.skipped_secrets += 1;
+                continue;
+            }
+            secrets_to_write.push((credential_key, pat, previous));
+        }
+
+        // Git repositories
+        let local_git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let mut git_repos_to_create = Vec::<GitRepository>::new();
+        let mut git_repos_to_update = Vec::<GitRepository>::new();
+        for imported_git_repo in configuration_file
+            .git_repositories
+            .iter()
+            .map(to_git_repository)
+        {
+            if git_repos_to_create
+                .iter()
+                .any(|x| x.is_same_remote(&imported_git_repo))
+            {
+                continue;
+            }
+            let Some(local_git_repo) = local_git_repos
+                .iter()
+                .find(|x| x.is_same_remote(&imported_git_repo))
+            else {
+                git_repos_to_create.push(imported_git_repo);
+                continue;
+            };
+            match conflict_resolution {
+                ConflictResolution::Skip => result.skipped_git_repositories += 1,
+                ConflictResolution::Overwrite => {
+                    git_repos_to_update.push(GitRepository {
+                        id: local_git_repo.id,
+                        is_orphaned: local_git_repo.is_orphaned,
+                        ..imported_git_repo
+                    });
+                }
+                // Only complete what is unknown locally
+                ConflictResolution::Merge => match &local_git_repo.remote_id {
+                    None if imported_git_repo.remote_id.is_some() => {
+                        git_repos_to_update.push(GitRepository {
+                            id: local_git_repo.id,
+                            name: local_git_repo.name.to_string(),
+                            context: local_git_repo.context.to_string(),
+                            git_provider: local_git_repo.git_provider.clone(),
+                            is_active: local_git_repo.is_active,
+                            credential_type: local_git_repo.credential_type,
+                            credential_key: local_git_repo.credential_key.to_string(),
+                            remote_id: imported_git_repo.remote_id,
+                            is_orphaned: local_git_repo.is_orphaned,
+                        });
+                    }
+                    _ => result.skipped_git_repositories += 1,
+                },
+            }
+        }
+        result.created_git_repositories = git_repos_to_create.len();
+        result.updated_git_repositories = git_repos_to_update.len();
+
+        // Repository groups, referring to the git repositories by their remote,
+        // as some of them are only created by the import
+        let imported_git_repos = configuration_file
+            .git_repositories
+            .iter()
+            .map(|x| (x.id, to_git_repository(x)))
+            .collect::<HashMap<u32, GitRepository>>();
+        let local_groups = self
+            .repository_group_repository
+            .get_all_repository_groups()
+            .await?;
+        let mut repository_groups = Vec::<ImportedRepositoryGroup>::new();
+        for group in &configuration_file.repository_groups {
+            let name = group.name.trim();
+            let git_repos = group
+                .git_repository_ids
+                .iter()
+                .filter_map(|x| imported_git_repos.get(x).cloned())
+                .collect::<Vec<GitRepository>>();
+            let Some(local_group) = local_groups.iter().find(|x| x.name == name) else {
+                repository_groups.push(ImportedRepositoryGroup {
+                    id: None,
+                    name: name.to_string(),
+                    assigned_git_repositories: git_repos,
+                    unassigned_git_repository_ids: vec![],
+                });
+                result.created_repository_groups += 1;
+                continue;
+            };
+            // The git repositories created by the import are not assigned yet
+            let local_ids = git_repos
+                .iter()
+                .map(|x| {
+                    local_git_repos
+                        .iter()
+                        .find(|l| l.is_same_remote(x))
+                        .map(|l| l.id)
+                })
+                .collect::<Vec<Option<u32>>>();
+            let missing_git_repos = git_repos
+                .iter()
+                .zip(&local_ids)
+                .filter(|(_, id)| id.is_none_or(|x| !local_group.git_repository_ids.contains(&x)))
+                .map(|(x, _)| x.clone())
+                .collect::<Vec<GitRepository>>();
+            match conflict_resolution {
+                ConflictResolution::Skip => result.skipped_repository_groups += 1,
+                ConflictResolution::Overwrite => {
+                    repository_groups.push(ImportedRepositoryGroup {
+                        id: Some(local_group.id),
+                        name: name.to_string(),
+                        assigned_git_repositories: missing_git_repos,
+                        unassigned_git_repository_ids: local_group
+                            .git_repository_ids
+                            .iter()
+                            .filter(|x| !local_ids.contains(&Some(**x)))
+                            .copied()
+                            .collect(),
+                    });
+                    result.updated_repository_groups += 1;
+                }
+                ConflictResolution::Merge if !missing_git_repos.is_empty() => {
+                    repository_groups.push(ImportedRepositoryGroup {
+                        id: Some(local_group.id),
+                        name: name.to_string(),
+                        assigned_git_repositories: missing_git_repos,
+                        unassigned_git_repository_ids: vec![],
+                    });
+                    result.updated_repository_groups += 1;
+                }
+                ConflictResolution::Merge => result.skipped_repository_groups += 1,
+            }
+        }
+        if !git_repos_to_create.is_empty()
+            || !git_repos_to_update.is_empty()
+            || !repository_groups.is_empty()
+        {
+            self.git_repository_repository
+                .import_git_repositories(
+                    git_repos_to_create,
+                    git_repos_to_update,
+                    repository_groups,
+                )
+                .await?;
+        }
+
+        // Settings
+        if let Some(settings) = settings {
+            self.settings_repository
+                .save_settings((&settings).into())
+                .await?;
+            result.is_settings_updated = true;
+        }
+
+        // Secrets, restoring the ones written before if any of them fails
+        for (i, (credential_key, pat, _)) in secrets_to_write.iter().enumerate() {
+            if let Err(err) = self.secret_repository.set_secret(credential_key, pat).await {
+                for (credential_key, _, previous) in &secrets_to_write[..i] {
+                    let restored = match previous {
+                        Some(secret) => {
+                            self.secret_repository
+                                .set_secret(credential_key, secret)
+                                .await
+                        }
+                        None => self.secret_repository.delete_secret(credential_key).await,
+                    };
+                    if let Err(err) = restored {
+                        log::warn!("Error restoring secret `{}`: {}", credential_key, err);
+                    }
+                }
+                return Err(err.context(format!(
+                    "The secret `{}` could not be imported, the git repositories and settings were imported without secrets",
+                    credential_key
+                )));
+            }
+            result.imported_secrets += 1;
+        }
+        Ok(result)
+    }
+
+    /// Merge the imported settings into the local ones
+    ///
+    /// When merging, the imported secret sources missing locally are appended
+    /// and the credential helper command is only taken if none is set locally.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration_file` - The imported configuration
+    /// * `conflict_resolution` - How to handle the local settings
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<SettingsDto>>` - The validated settings to save, none if they stay unchanged
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the resulting settings are invalid
+    async fn merge_settings(
+        &self,
+        configuration_file: &ConfigurationFile,
+        conflict_resolution: ConflictResolution,
+    ) -> Result<Option<SettingsDto>> {
+        let Some(imported_settings) = &configuration_file.settings else {
+            return Ok(None);
+        };
+        let mut settings = SettingsDto::from(&self.settings_repository.get_settings().await?);
+        match conflict_resolution {
+            ConflictResolution::Skip => return Ok(None),
+            ConflictResolution::Overwrite => {
+                settings.secret_sources = imported_settings.secret_sources.clone();
+                settings.credential_helper_command =
+                    imported_settings.credential_helper_command.clone();
+            }
+            ConflictResolution::Merge => {
+                let mut is_changed = false;
+                for source in &imported_settings.secret_sources {
+                    if !settings.secret_sources.contains(source) {
+                        settings.secret_sources.push(*source);
+                        is_changed = true;
+                    }
+                }
+                if settings.credential_helper_command.is_none()
+                    && imported_settings.credential_helper_command.is_some()
+                {
+                    settings.credential_helper_command =
+                        imported_settings.credential_helper_command.clone();
+                    is_changed = true;
+                }
+                if !is_changed {
+                    return Ok(None);
+                }
+            }
+        }
+        validate_settings(&settings)?;
+        Ok(Some(settings))
+    }
+}
+
+/// Helper to create the git repository to import from its exported counterpart
+///
+/// # Arguments
+///
+/// * `value` - The exported git repository
+fn to_git_repository(value: &ConfigurationGitRepository) -> GitRepository {
+    GitRepository {
+        id: value.id,
+        name: value.name.to_string(),
+        context: value.context.to_string(),
+        git_provider: (&value.git_provider).into(),
+        is_active: value.is_active,
+        credential_type: (&value.credential_type).into(),
+        credential_key: value.credential_key.to_string(),
+        remote_id: value.remote_id.clone(),
+        is_orphaned: false,
+    }
+}
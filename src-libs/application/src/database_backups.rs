@@ -0,0 +1,2 @@
+pub mod create_database_backup;
+pub mod restore_database_backup;
@@ -0,0 +1,48 @@
+use crate::traits::DatabaseBackupRepository;
+use anyhow::Result;
+
+/// Responsible for backing up the database of the active profile
+pub struct CreateDatabaseBackupCommand<B>
+where
+    B: DatabaseBackupRepository,
+{
+    database_backup_repository: B,
+}
+
+impl<B> CreateDatabaseBackupCommand<B>
+where
+    B: DatabaseBackupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `database_backup_repository` - The repository to back up the database with
+    pub fn new(database_backup_repository: B) -> Self {
+        Self {
+            database_backup_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// Secrets are not part of the database, so they are not backed up.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the backup file, which must not exist yet
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file already exists
+    pub async fn execute(&self, file_path: &str) -> Result<()> {
+        let file_path = file_path.trim();
+        if file_path.is_empty() {
+            return Err(anyhow::anyhow!("The backup file path must not be empty"));
+        }
+        self.database_backup_repository
+            .create_backup(file_path)
+            .await?;
+        Ok(())
+    }
+}
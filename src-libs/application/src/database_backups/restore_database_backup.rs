@@ -0,0 +1,56 @@
+use crate::traits::DatabaseBackupRepository;
+use anyhow::Result;
+
+/// Responsible for restoring the database of the active profile from a backup
+pub struct RestoreDatabaseBackupCommand<B>
+where
+    B: DatabaseBackupRepository,
+{
+    database_backup_repository: B,
+}
+
+impl<B> RestoreDatabaseBackupCommand<B>
+where
+    B: DatabaseBackupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `database_backup_repository` - The repository to restore the database with
+    pub fn new(database_backup_repository: B) -> Self {
+        Self {
+            database_backup_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// Backups of older versions of the application are migrated to the
+    /// current schema while restoring them, backups of newer versions are
+    /// rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the backup file
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file is no valid backup
+    pub async fn execute(&self, file_path: &str) -> Result<()> {
+        let backup_schema_version = self
+            .database_backup_repository
+            .get_backup_schema_version(file_path)
+            .await?;
+        let schema_version = self.database_backup_repository.get_schema_version().await?;
+        if backup_schema_version > schema_version {
+            return Err(anyhow::anyhow!(
+                "The backup was created by a newer version of the application, please update it first"
+            ));
+        }
+        self.database_backup_repository
+            .restore_backup(file_path)
+            .await?;
+        Ok(())
+    }
+}
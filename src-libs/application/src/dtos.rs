@@ -1,12 +1,21 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use domain::{
-    enums::GitProvider as DomainGitProvider,
-    models::{GitRepository, PullRequest},
+    enums::{
+        CheckStatus as DomainCheckStatus, CredentialType as DomainCredentialType,
+        GitProvider as DomainGitProvider, ReviewerVote as DomainReviewerVote,
+        SecretSource as DomainSecretSource, SecretStorageBackend as DomainSecretStorageBackend,
+        SlaRuleKind as DomainSlaRuleKind,
+    },
+    models::{
+        ChangeStatistics, DeviceCodeChallenge, GitRepository, Holiday, Profile, PullRequest,
+        PullRequestCheck, PullRequestChecks, RepositoryGroup, Reviewer, Settings, SlaRule,
+        WorkItem,
+    },
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-#[derive(Serialize, Clone, TS)]
+#[derive(Serialize, Deserialize, Clone, TS)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub enum GitProvider {
     AzureDevOps,
@@ -20,6 +29,81 @@ impl From<DomainGitProvider> for GitProvider {
     }
 }
 
+impl From<&GitProvider> for DomainGitProvider {
+    fn from(value: &GitProvider) -> Self {
+        match value {
+            GitProvider::AzureDevOps => DomainGitProvider::AzureDevOps,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum CheckStatus {
+    Passed,
+    Failed,
+    Pending,
+    NotApplicable,
+}
+
+impl From<DomainCheckStatus> for CheckStatus {
+    fn from(value: DomainCheckStatus) -> Self {
+        match value {
+            DomainCheckStatus::Passed => CheckStatus::Passed,
+            DomainCheckStatus::Failed => CheckStatus::Failed,
+            DomainCheckStatus::Pending => CheckStatus::Pending,
+            DomainCheckStatus::NotApplicable => CheckStatus::NotApplicable,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum ReviewerVote {
+    Approved,
+    ApprovedWithSuggestions,
+    NoVote,
+    WaitingForAuthor,
+    Rejected,
+}
+
+impl From<DomainReviewerVote> for ReviewerVote {
+    fn from(value: DomainReviewerVote) -> Self {
+        match value {
+            DomainReviewerVote::Approved => ReviewerVote::Approved,
+            DomainReviewerVote::ApprovedWithSuggestions => ReviewerVote::ApprovedWithSuggestions,
+            DomainReviewerVote::NoVote => ReviewerVote::NoVote,
+            DomainReviewerVote::WaitingForAuthor => ReviewerVote::WaitingForAuthor,
+            DomainReviewerVote::Rejected => ReviewerVote::Rejected,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum CredentialType {
+    Pat,
+    OAuth,
+}
+
+impl From<DomainCredentialType> for CredentialType {
+    fn from(value: DomainCredentialType) -> Self {
+        match value {
+            DomainCredentialType::Pat => CredentialType::Pat,
+            DomainCredentialType::OAuth => CredentialType::OAuth,
+        }
+    }
+}
+
+impl From<&CredentialType> for DomainCredentialType {
+    fn from(value: &CredentialType) -> Self {
+        match value {
+            CredentialType::Pat => DomainCredentialType::Pat,
+            CredentialType::OAuth => DomainCredentialType::OAuth,
+        }
+    }
+}
+
 #[derive(Serialize, TS)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct GitRepositoryDto {
@@ -32,6 +116,12 @@ pub struct GitRepositoryDto {
     #[ts(rename = "isActive")]
     #[serde(rename = "isActive")]
     pub is_active: bool,
+    #[ts(rename = "isOrphaned")]
+    #[serde(rename = "isOrphaned")]
+    pub is_orphaned: bool,
+    #[ts(rename = "credentialType")]
+    #[serde(rename = "credentialType")]
+    pub credential_type: CredentialType,
 }
 
 impl From<&GitRepository> for GitRepositoryDto {
@@ -42,13 +132,74 @@ impl From<&GitRepository> for GitRepositoryDto {
             context: value.context.to_string(),
             git_provider: value.git_provider.clone().into(),
             is_active: value.is_active,
+            is_orphaned: value.is_orphaned,
+            credential_type: value.credential_type.into(),
         }
     }
 }
 
+#[derive(Deserialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(default)]
+pub struct GitRepositoryImportFilterDto {
+    #[ts(rename = "includeProjects")]
+    #[serde(rename = "includeProjects")]
+    pub include_projects: Vec<String>,
+    #[ts(rename = "excludeProjects")]
+    #[serde(rename = "excludeProjects")]
+    pub exclude_projects: Vec<String>,
+    #[ts(rename = "includeRepositories")]
+    #[serde(rename = "includeRepositories")]
+    pub include_repositories: Vec<String>,
+    #[ts(rename = "excludeRepositories")]
+    #[serde(rename = "excludeRepositories")]
+    pub exclude_repositories: Vec<String>,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct DiscoveredProjectDto {
+    pub name: String,
+    pub repositories: Vec<DiscoveredGitRepositoryDto>,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct DiscoveredGitRepositoryDto {
+    #[ts(rename = "remoteId")]
+    #[serde(rename = "remoteId")]
+    pub remote_id: Option<String>,
+    pub name: String,
+    pub context: String,
+    #[ts(rename = "isImported")]
+    #[serde(rename = "isImported")]
+    pub is_imported: bool,
+}
+
+#[derive(Serialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct GitRepositorySyncResultDto {
+    pub added: usize,
+    pub renamed: usize,
+    pub orphaned: usize,
+    pub restored: usize,
+}
+
+#[derive(Deserialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(default)]
+pub struct GitRepositorySelectionDto {
+    pub ids: Vec<u32>,
+    pub pattern: Option<String>,
+}
+
 #[derive(Serialize, TS)]
 #[ts(export, export_to = "../../src/bindings/")]
 pub struct PullRequestDto {
+    pub id: u32,
+    #[ts(rename = "gitRepositoryId")]
+    #[serde(rename = "gitRepositoryId")]
+    pub git_repository_id: u32,
     #[ts(rename = "repositoryName")]
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
@@ -63,6 +214,10 @@ pub struct PullRequestDto {
     #[ts(rename = "creationDate")]
     #[serde(rename = "creationDate")]
     pub creation_date: DateTime<Utc>,
+    #[ts(type = "Date | string | null")]
+    #[ts(rename = "lastUpdatedDate")]
+    #[serde(rename = "lastUpdatedDate")]
+    pub last_updated_date: DateTime<Utc>,
     #[ts(rename = "numberOfComments")]
     #[serde(rename = "numberOfComments")]
     pub number_of_comments: usize,
@@ -70,19 +225,726 @@ pub struct PullRequestDto {
     #[serde(rename = "numberOfClosedComments")]
     pub number_of_closed_comments: usize,
     pub link: String,
+    pub checks: PullRequestChecksDto,
+    #[ts(rename = "workItems")]
+    #[serde(rename = "workItems")]
+    pub work_items: Vec<WorkItemDto>,
+    #[ts(rename = "changeStatistics")]
+    #[serde(rename = "changeStatistics")]
+    pub change_statistics: Option<ChangeStatisticsDto>,
+    #[ts(rename = "latestIterationId")]
+    #[serde(rename = "latestIterationId")]
+    pub latest_iteration_id: Option<u32>,
+    pub reviewers: Vec<ReviewerDto>,
+    #[ts(rename = "hasNewChangesSinceMyReview")]
+    #[serde(rename = "hasNewChangesSinceMyReview")]
+    pub has_new_changes_since_my_review: bool,
+    pub unread: bool,
+    #[ts(rename = "newCommentCount")]
+    #[serde(rename = "newCommentCount")]
+    pub new_comment_count: usize,
+    #[ts(rename = "isPinned")]
+    #[serde(rename = "isPinned")]
+    pub is_pinned: bool,
+    #[ts(rename = "isSnoozed")]
+    #[serde(rename = "isSnoozed")]
+    pub is_snoozed: bool,
+    #[ts(type = "Date | string | null")]
+    #[ts(rename = "snoozedUntil")]
+    #[serde(rename = "snoozedUntil")]
+    pub snoozed_until: Option<DateTime<Utc>>,
+    #[ts(rename = "snoozedUntilNextUpdate")]
+    #[serde(rename = "snoozedUntilNextUpdate")]
+    pub snoozed_until_next_update: bool,
+    pub note: Option<String>,
+    pub tags: Vec<String>,
+    #[ts(rename = "slaViolations")]
+    #[serde(rename = "slaViolations")]
+    pub sla_violations: Vec<SlaViolationDto>,
 }
 
 impl From<&PullRequest> for PullRequestDto {
     fn from(value: &PullRequest) -> Self {
         Self {
+            id: value.id,
+            git_repository_id: value.git_repository_id,
             repository_name: value.repository_name.to_string(),
             title: value.title.to_string(),
             merge_status: value.merge_status.to_string(),
             creator_name: value.creator_name.to_string(),
             creation_date: value.creation_date,
+            last_updated_date: value.last_updated_date,
             number_of_closed_comments: value.number_of_closed_comments,
             number_of_comments: value.number_of_comments,
             link: value.link.to_string(),
+            checks: (&value.checks).into(),
+            work_items: value.work_items.iter().map(|x| x.into()).collect(),
+            change_statistics: value.change_statistics.as_ref().map(|x| x.into()),
+            latest_iteration_id: value.latest_iteration_id,
+            reviewers: value.reviewers.iter().map(|x| x.into()).collect(),
+            has_new_changes_since_my_review: false,
+            unread: true,
+            new_comment_count: value.number_of_comments,
+            is_pinned: false,
+            is_snoozed: false,
+            snoozed_until: None,
+            snoozed_until_next_update: false,
+            note: None,
+            tags: vec![],
+            sla_violations: vec![],
+        }
+    }
+}
+
+#[derive(Deserialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(default)]
+pub struct PullRequestFilterDto {
+    #[ts(rename = "withoutWorkItemsOnly")]
+    #[serde(rename = "withoutWorkItemsOnly")]
+    pub without_work_items_only: bool,
+    #[ts(rename = "includeSnoozed")]
+    #[serde(rename = "includeSnoozed")]
+    pub include_snoozed: bool,
+    pub tag: Option<String>,
+    #[ts(rename = "groupId")]
+    #[serde(rename = "groupId")]
+    pub group_id: Option<u32>,
+}
+
+#[derive(Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PullRequestSnoozeDto {
+    #[ts(type = "Date | string | null")]
+    pub until: Option<DateTime<Utc>>,
+    #[ts(rename = "untilNextUpdate")]
+    #[serde(rename = "untilNextUpdate")]
+    pub until_next_update: bool,
+}
+
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum PullRequestSortField {
+    #[default]
+    CreationDate,
+    FilesChanged,
+    LinesAdded,
+    LinesDeleted,
+    LinesChanged,
+}
+
+#[derive(Deserialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(default)]
+pub struct PullRequestSortingDto {
+    pub field: PullRequestSortField,
+    pub descending: bool,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PullRequestCheckDto {
+    pub name: String,
+    pub status: CheckStatus,
+    pub link: Option<String>,
+}
+
+impl From<&PullRequestCheck> for PullRequestCheckDto {
+    fn from(value: &PullRequestCheck) -> Self {
+        Self {
+            name: value.name.to_string(),
+            status: value.status.into(),
+            link: value.link.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PullRequestChecksDto {
+    pub status: CheckStatus,
+    pub items: Vec<PullRequestCheckDto>,
+}
+
+impl From<&PullRequestChecks> for PullRequestChecksDto {
+    fn from(value: &PullRequestChecks) -> Self {
+        Self {
+            status: value.status.into(),
+            items: value.items.iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct WorkItemDto {
+    pub id: u32,
+    #[ts(rename = "workItemType")]
+    #[serde(rename = "workItemType")]
+    pub work_item_type: String,
+    pub title: String,
+    pub state: String,
+}
+
+impl From<&WorkItem> for WorkItemDto {
+    fn from(value: &WorkItem) -> Self {
+        Self {
+            id: value.id,
+            work_item_type: value.work_item_type.to_string(),
+            title: value.title.to_string(),
+            state: value.state.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ChangeStatisticsDto {
+    #[ts(rename = "iterationId")]
+    #[serde(rename = "iterationId")]
+    pub iteration_id: u32,
+    #[ts(rename = "filesChanged")]
+    #[serde(rename = "filesChanged")]
+    pub files_changed: usize,
+    #[ts(rename = "linesAdded")]
+    #[serde(rename = "linesAdded")]
+    pub lines_added: Option<usize>,
+    #[ts(rename = "linesDeleted")]
+    #[serde(rename = "linesDeleted")]
+    pub lines_deleted: Option<usize>,
+}
+
+impl From<&ChangeStatistics> for ChangeStatisticsDto {
+    fn from(value: &ChangeStatistics) -> Self {
+        Self {
+            iteration_id: value.iteration_id,
+            files_changed: value.files_changed,
+            lines_added: value.lines_added,
+            lines_deleted: value.lines_deleted,
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ReviewerDto {
+    pub id: String,
+    #[ts(rename = "displayName")]
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub vote: ReviewerVote,
+    #[ts(rename = "isRequired")]
+    #[serde(rename = "isRequired")]
+    pub is_required: bool,
+}
+
+impl From<&Reviewer> for ReviewerDto {
+    fn from(value: &Reviewer) -> Self {
+        Self {
+            id: value.id.to_string(),
+            display_name: value.display_name.to_string(),
+            vote: value.vote.into(),
+            is_required: value.is_required,
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct RepositoryGroupDto {
+    pub id: u32,
+    pub name: String,
+    #[ts(rename = "gitRepositoryIds")]
+    #[serde(rename = "gitRepositoryIds")]
+    pub git_repository_ids: Vec<u32>,
+}
+
+impl From<&RepositoryGroup> for RepositoryGroupDto {
+    fn from(value: &RepositoryGroup) -> Self {
+        Self {
+            id: value.id,
+            name: value.name.to_string(),
+            git_repository_ids: value.git_repository_ids.clone(),
         }
     }
 }
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PatExpiryWarningDto {
+    #[ts(rename = "organizationName")]
+    #[serde(rename = "organizationName")]
+    pub organization_name: String,
+    #[ts(rename = "gitRepositoryIds")]
+    #[serde(rename = "gitRepositoryIds")]
+    pub git_repository_ids: Vec<u32>,
+    #[ts(type = "Date | string | null")]
+    #[ts(rename = "expiresAt")]
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+    #[ts(rename = "isExpired")]
+    #[serde(rename = "isExpired")]
+    pub is_expired: bool,
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct DeviceCodeChallengeDto {
+    #[ts(rename = "deviceCode")]
+    #[serde(rename = "deviceCode")]
+    pub device_code: String,
+    #[ts(rename = "userCode")]
+    #[serde(rename = "userCode")]
+    pub user_code: String,
+    #[ts(rename = "verificationUri")]
+    #[serde(rename = "verificationUri")]
+    pub verification_uri: String,
+    pub message: String,
+    #[ts(type = "Date | string | null")]
+    #[ts(rename = "expiresAt")]
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+    #[ts(rename = "intervalSeconds")]
+    #[serde(rename = "intervalSeconds")]
+    pub interval_seconds: u64,
+}
+
+impl From<DeviceCodeChallenge> for DeviceCodeChallengeDto {
+    fn from(value: DeviceCodeChallenge) -> Self {
+        Self {
+            device_code: value.device_code,
+            user_code: value.user_code,
+            verification_uri: value.verification_uri,
+            message: value.message,
+            expires_at: value.expires_at,
+            interval_seconds: value.interval_seconds,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum SecretStorageBackend {
+    #[default]
+    Keyring,
+    EncryptedFile,
+}
+
+impl From<DomainSecretStorageBackend> for SecretStorageBackend {
+    fn from(value: DomainSecretStorageBackend) -> Self {
+        match value {
+            DomainSecretStorageBackend::Keyring => SecretStorageBackend::Keyring,
+            DomainSecretStorageBackend::EncryptedFile => SecretStorageBackend::EncryptedFile,
+        }
+    }
+}
+
+impl From<SecretStorageBackend> for DomainSecretStorageBackend {
+    fn from(value: SecretStorageBackend) -> Self {
+        match value {
+            SecretStorageBackend::Keyring => DomainSecretStorageBackend::Keyring,
+            SecretStorageBackend::EncryptedFile => DomainSecretStorageBackend::EncryptedFile,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum SecretSource {
+    Environment,
+    CredentialHelper,
+    Storage,
+}
+
+impl From<DomainSecretSource> for SecretSource {
+    fn from(value: DomainSecretSource) -> Self {
+        match value {
+            DomainSecretSource::Environment => SecretSource::Environment,
+            DomainSecretSource::CredentialHelper => SecretSource::CredentialHelper,
+            DomainSecretSource::Storage => SecretSource::Storage,
+        }
+    }
+}
+
+impl From<SecretSource> for DomainSecretSource {
+    fn from(value: SecretSource) -> Self {
+        match value {
+            SecretSource::Environment => DomainSecretSource::Environment,
+            SecretSource::CredentialHelper => DomainSecretSource::CredentialHelper,
+            SecretSource::Storage => DomainSecretSource::Storage,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+#[serde(default)]
+pub struct SettingsDto {
+    #[ts(rename = "secretStorageBackend")]
+    #[serde(rename = "secretStorageBackend")]
+    pub secret_storage_backend: SecretStorageBackend,
+    #[ts(rename = "secretSources")]
+    #[serde(rename = "secretSources")]
+    pub secret_sources: Vec<SecretSource>,
+    #[ts(rename = "credentialHelperCommand")]
+    #[serde(rename = "credentialHelperCommand")]
+    pub credential_helper_command: Option<String>,
+    #[ts(rename = "patExpiryWarningDays")]
+    #[serde(rename = "patExpiryWarningDays")]
+    pub pat_expiry_warning_days: u32,
+    #[ts(rename = "businessHoursStart", type = "string")]
+    #[serde(rename = "businessHoursStart")]
+    pub business_hours_start: NaiveTime,
+    #[ts(rename = "businessHoursEnd", type = "string")]
+    #[serde(rename = "businessHoursEnd")]
+    pub business_hours_end: NaiveTime,
+    #[ts(rename = "businessTimeZone")]
+    #[serde(rename = "businessTimeZone")]
+    pub business_time_zone: String,
+}
+
+impl Default for SettingsDto {
+    fn default() -> Self {
+        (&Settings::default()).into()
+    }
+}
+
+impl From<&Settings> for SettingsDto {
+    fn from(value: &Settings) -> Self {
+        Self {
+            secret_storage_backend: value.secret_storage_backend.into(),
+            secret_sources: value.secret_sources.iter().map(|x| (*x).into()).collect(),
+            credential_helper_command: value.credential_helper_command.clone(),
+            pat_expiry_warning_days: value.pat_expiry_warning_days,
+            business_hours_start: value.business_hours_start,
+            business_hours_end: value.business_hours_end,
+            business_time_zone: value.business_time_zone.to_string(),
+        }
+    }
+}
+
+impl From<&SettingsDto> for Settings {
+    fn from(value: &SettingsDto) -> Self {
+        Self {
+            secret_storage_backend: value.secret_storage_backend.into(),
+            secret_sources: value.secret_sources.iter().map(|x| (*x).into()).collect(),
+            credential_helper_command: value
+                .credential_helper_command
+                .as_deref()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty())
+                .map(|x| x.to_string()),
+            pat_expiry_warning_days: value.pat_expiry_warning_days,
+            business_hours_start: value.business_hours_start,
+            business_hours_end: value.business_hours_end,
+            business_time_zone: value.business_time_zone.trim().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct MissingSecretDto {
+    #[ts(rename = "credentialKey")]
+    #[serde(rename = "credentialKey")]
+    pub credential_key: String,
+    #[ts(rename = "gitRepositoryIds")]
+    #[serde(rename = "gitRepositoryIds")]
+    pub git_repository_ids: Vec<u32>,
+}
+
+#[derive(Serialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SecretHealthReportDto {
+    pub present: Vec<String>,
+    pub missing: Vec<MissingSecretDto>,
+    pub orphaned: Vec<String>,
+    pub failed: Vec<FailedSecretDto>,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct FailedSecretDto {
+    #[ts(rename = "credentialKey")]
+    #[serde(rename = "credentialKey")]
+    pub credential_key: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SecretMigrationResultDto {
+    pub migrated: usize,
+    pub missing: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum ConflictResolution {
+    #[default]
+    Skip,
+    Overwrite,
+    Merge,
+}
+
+#[derive(Serialize, Default, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ConfigurationImportResultDto {
+    #[ts(rename = "createdGitRepositories")]
+    #[serde(rename = "createdGitRepositories")]
+    pub created_git_repositories: usize,
+    #[ts(rename = "updatedGitRepositories")]
+    #[serde(rename = "updatedGitRepositories")]
+    pub updated_git_repositories: usize,
+    #[ts(rename = "skippedGitRepositories")]
+    #[serde(rename = "skippedGitRepositories")]
+    pub skipped_git_repositories: usize,
+    #[ts(rename = "createdRepositoryGroups")]
+    #[serde(rename = "createdRepositoryGroups")]
+    pub created_repository_groups: usize,
+    #[ts(rename = "updatedRepositoryGroups")]
+    #[serde(rename = "updatedRepositoryGroups")]
+    pub updated_repository_groups: usize,
+    #[ts(rename = "skippedRepositoryGroups")]
+    #[serde(rename = "skippedRepositoryGroups")]
+    pub skipped_repository_groups: usize,
+    #[ts(rename = "isSettingsUpdated")]
+    #[serde(rename = "isSettingsUpdated")]
+    pub is_settings_updated: bool,
+    #[ts(rename = "importedSecrets")]
+    #[serde(rename = "importedSecrets")]
+    pub imported_secrets: usize,
+    #[ts(rename = "skippedSecrets")]
+    #[serde(rename = "skippedSecrets")]
+    pub skipped_secrets: usize,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ProfileDto {
+    pub id: String,
+    pub name: String,
+    #[ts(rename = "isActive")]
+    #[serde(rename = "isActive")]
+    pub is_active: bool,
+    #[ts(rename = "isDefault")]
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+}
+
+impl From<&Profile> for ProfileDto {
+    fn from(value: &Profile) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name.to_string(),
+            is_active: false,
+            is_default: value.is_default(),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, TS, Default)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct DurationStatisticsDto {
+    pub count: usize,
+    #[ts(rename = "medianHours")]
+    #[serde(rename = "medianHours")]
+    pub median_hours: Option<f64>,
+    #[ts(rename = "p90Hours")]
+    #[serde(rename = "p90Hours")]
+    pub p90_hours: Option<f64>,
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PullRequestMetricsDto {
+    pub id: Option<u32>,
+    pub name: String,
+    #[ts(rename = "createdCount")]
+    #[serde(rename = "createdCount")]
+    pub created_count: usize,
+    #[ts(rename = "completedCount")]
+    #[serde(rename = "completedCount")]
+    pub completed_count: usize,
+    #[ts(rename = "abandonedCount")]
+    #[serde(rename = "abandonedCount")]
+    pub abandoned_count: usize,
+    #[ts(rename = "timeToFirstReview")]
+    #[serde(rename = "timeToFirstReview")]
+    pub time_to_first_review: DurationStatisticsDto,
+    #[ts(rename = "timeToMerge")]
+    #[serde(rename = "timeToMerge")]
+    pub time_to_merge: DurationStatisticsDto,
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct PullRequestAnalyticsDto {
+    #[ts(type = "Date | string | null")]
+    pub from: DateTime<Utc>,
+    #[ts(type = "Date | string | null")]
+    pub to: DateTime<Utc>,
+    pub total: PullRequestMetricsDto,
+    pub repositories: Vec<PullRequestMetricsDto>,
+    pub groups: Vec<PullRequestMetricsDto>,
+    pub authors: Vec<PullRequestMetricsDto>,
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct WaitingPullRequestDto {
+    #[ts(rename = "gitRepositoryId")]
+    #[serde(rename = "gitRepositoryId")]
+    pub git_repository_id: u32,
+    #[ts(rename = "pullRequestId")]
+    #[serde(rename = "pullRequestId")]
+    pub pull_request_id: u32,
+    pub title: String,
+    pub link: String,
+    #[ts(rename = "ageHours")]
+    #[serde(rename = "ageHours")]
+    pub age_hours: f64,
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct ReviewerWorkloadDto {
+    #[ts(rename = "reviewerId")]
+    #[serde(rename = "reviewerId")]
+    pub reviewer_id: String,
+    #[ts(rename = "displayName")]
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[ts(rename = "assignedCount")]
+    #[serde(rename = "assignedCount")]
+    pub assigned_count: usize,
+    #[ts(rename = "pendingVoteCount")]
+    #[serde(rename = "pendingVoteCount")]
+    pub pending_vote_count: usize,
+    #[ts(rename = "requiredPendingVoteCount")]
+    #[serde(rename = "requiredPendingVoteCount")]
+    pub required_pending_vote_count: usize,
+    #[ts(rename = "oldestWaitingPullRequest")]
+    #[serde(rename = "oldestWaitingPullRequest")]
+    pub oldest_waiting_pull_request: Option<WaitingPullRequestDto>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub enum SlaRuleKind {
+    NoReviewerVote,
+    NoUpdate,
+    UnresolvedComments,
+}
+
+impl From<DomainSlaRuleKind> for SlaRuleKind {
+    fn from(value: DomainSlaRuleKind) -> Self {
+        match value {
+            DomainSlaRuleKind::NoReviewerVote => SlaRuleKind::NoReviewerVote,
+            DomainSlaRuleKind::NoUpdate => SlaRuleKind::NoUpdate,
+            DomainSlaRuleKind::UnresolvedComments => SlaRuleKind::UnresolvedComments,
+        }
+    }
+}
+
+impl From<SlaRuleKind> for DomainSlaRuleKind {
+    fn from(value: SlaRuleKind) -> Self {
+        match value {
+            SlaRuleKind::NoReviewerVote => DomainSlaRuleKind::NoReviewerVote,
+            SlaRuleKind::NoUpdate => DomainSlaRuleKind::NoUpdate,
+            SlaRuleKind::UnresolvedComments => DomainSlaRuleKind::UnresolvedComments,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SlaRuleDto {
+    #[serde(default)]
+    pub id: u32,
+    pub name: String,
+    pub kind: SlaRuleKind,
+    #[ts(rename = "thresholdHours")]
+    #[serde(rename = "thresholdHours")]
+    pub threshold_hours: u32,
+    #[ts(rename = "isBusinessHours")]
+    #[serde(rename = "isBusinessHours")]
+    pub is_business_hours: bool,
+    #[ts(rename = "repositoryGroupId")]
+    #[serde(rename = "repositoryGroupId")]
+    pub repository_group_id: Option<u32>,
+}
+
+impl From<&SlaRule> for SlaRuleDto {
+    fn from(value: &SlaRule) -> Self {
+        Self {
+            id: value.id,
+            name: value.name.to_string(),
+            kind: value.kind.into(),
+            threshold_hours: value.threshold_hours,
+            is_business_hours: value.is_business_hours,
+            repository_group_id: value.repository_group_id,
+        }
+    }
+}
+
+impl From<&SlaRuleDto> for SlaRule {
+    fn from(value: &SlaRuleDto) -> Self {
+        Self {
+            id: value.id,
+            name: value.name.trim().to_string(),
+            kind: value.kind.into(),
+            threshold_hours: value.threshold_hours,
+            is_business_hours: value.is_business_hours,
+            repository_group_id: value.repository_group_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct HolidayDto {
+    #[ts(type = "string")]
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub name: String,
+}
+
+impl From<&Holiday> for HolidayDto {
+    fn from(value: &Holiday) -> Self {
+        Self {
+            date: value.date,
+            name: value.name.to_string(),
+        }
+    }
+}
+
+impl From<&HolidayDto> for Holiday {
+    fn from(value: &HolidayDto) -> Self {
+        Self {
+            date: value.date,
+            name: value.name.trim().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export, export_to = "../../src/bindings/")]
+pub struct SlaViolationDto {
+    #[ts(rename = "slaRuleId")]
+    #[serde(rename = "slaRuleId")]
+    pub sla_rule_id: u32,
+    #[ts(rename = "slaRuleName")]
+    #[serde(rename = "slaRuleName")]
+    pub sla_rule_name: String,
+    pub kind: SlaRuleKind,
+    #[ts(rename = "thresholdHours")]
+    #[serde(rename = "thresholdHours")]
+    pub threshold_hours: u32,
+    #[ts(rename = "elapsedHours")]
+    #[serde(rename = "elapsedHours")]
+    pub elapsed_hours: f64,
+    #[ts(rename = "isBusinessHours")]
+    #[serde(rename = "isBusinessHours")]
+    pub is_business_hours: bool,
+}
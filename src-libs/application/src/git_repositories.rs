@@ -1,5 +1,12 @@
+pub mod add_git_repository_by_url;
+mod create_git_repositories_with_secret;
 pub mod get_git_repositories;
 pub mod import_azure_devops_organization_repositories;
+mod parse_git_repository_url;
+pub mod remove_git_repositories;
 pub mod remove_git_repository;
+mod select_git_repositories;
+pub mod set_git_repositories_active_state;
+pub mod sync_azure_devops_organization_repositories;
 pub mod toggle_git_repository_active_state;
 pub mod update_pat_for_git_repository;
@@ -0,0 +1,103 @@
+use super::{
+    create_git_repositories_with_secret::create_git_repositories_with_secret,
+    parse_git_repository_url::parse_git_repository_url,
+};
+use crate::traits::{AzureDevOpsRepository, GitRepositoryRepository, SecretRepository};
+use anyhow::Result;
+use domain::{enums::GitProvider, models::Credential};
+
+/// Responsible for importing a single git repository, identified by its URL
+pub struct AddGitRepositoryByUrlCommand<A, G, S>
+where
+    A: AzureDevOpsRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    azure_devops_repository: A,
+    git_repository_repository: G,
+    secret_repository: S,
+}
+
+impl<A, G, S> AddGitRepositoryByUrlCommand<A, G, S>
+where
+    A: AzureDevOpsRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `azure_devops_repository` - The repository to get the git repository from Azure DevOps
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `secret_repository` - The repository to access secrets
+    pub fn new(
+        azure_devops_repository: A,
+        git_repository_repository: G,
+        secret_repository: S,
+    ) -> Self {
+        Self {
+            azure_devops_repository,
+            git_repository_repository,
+            secret_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The clone or web URL of the git repository
+    /// * `pat` - The PAT to access the git repository
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the URL is not supported, the
+    /// git repository can not be accessed or is already imported
+    pub async fn execute(&self, url: &str, pat: &str) -> Result<()> {
+        let parsed_url = parse_git_repository_url(url)?;
+        let mut git_repo = match parsed_url.git_provider {
+            GitProvider::AzureDevOps => {
+                self.azure_devops_repository
+                    .get_repository(
+                        &Credential::Pat(pat.to_string()),
+                        &parsed_url.context,
+                        &parsed_url.name,
+                    )
+                    .await?
+            }
+        };
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        if git_repos.iter().any(|x| x.is_same_remote(&git_repo)) {
+            return Err(anyhow::anyhow!(
+                "The git repository `{}` is already imported",
+                git_repo.name
+            ));
+        }
+        if git_repos
+            .iter()
+            .any(|x| x.credential_key == git_repo.credential_key)
+        {
+            // Do not overwrite the PAT already shared by other imported git
+            // repositories, as it might be scoped differently
+            git_repo.credential_key = format!(
+                "{}-{}",
+                git_repo.credential_key,
+                git_repo.remote_id.as_deref().unwrap_or(&git_repo.name)
+            );
+        }
+        let credential_key = git_repo.credential_key.to_string();
+        create_git_repositories_with_secret(
+            &self.git_repository_repository,
+            &self.secret_repository,
+            &credential_key,
+            pat,
+            vec![git_repo],
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::traits::{GitRepositoryRepository, SecretRepository};
+use anyhow::Result;
+use domain::models::GitRepository;
+
+/// Store the PAT and create the git repositories referencing it atomically
+///
+/// The git repositories are created in a single transaction. If that fails,
+/// the previously stored PAT is restored, or the secret removed if there was
+/// none, so neither an orphan secret nor a partial import is left behind.
+///
+/// # Arguments
+///
+/// * `git_repository_repository` - The repository to access git repositories
+/// * `secret_repository` - The repository to access secrets
+/// * `credential_key` - The key to store the PAT with
+/// * `pat` - The PAT to store
+/// * `git_repositories` - The git repositories to create
+///
+/// # Errors
+///
+/// Any errors that might occur while storing the PAT or creating the git repositories
+pub(crate) async fn create_git_repositories_with_secret<G, S>(
+    git_repository_repository: &G,
+    secret_repository: &S,
+    credential_key: &str,
+    pat: &str,
+    git_repositories: Vec<GitRepository>,
+) -> Result<()>
+where
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    apply_with_secret(
+        secret_repository,
+        credential_key,
+        pat,
+        git_repository_repository.create_git_repositories(git_repositories),
+    )
+    .await
+}
+
+/// Store the PAT and apply changes to the git repositories referencing it atomically
+///
+/// If applying the changes fails, the previously stored PAT is restored, or the
+/// secret removed if there was none.
+///
+/// # Arguments
+///
+/// * `secret_repository` - The repository to access secrets
+/// * `credential_key` - The key to store the PAT with
+/// * `pat` - The PAT to store
+/// * `changes` - The changes to apply in a single transaction after the PAT is stored
+///
+/// # Errors
+///
+/// Any errors that might occur while storing the PAT or applying the changes
+pub(crate) async fn apply_with_secret<S, F>(
+    secret_repository: &S,
+    credential_key: &str,
+    pat: &str,
+    changes: F,
+) -> Result<()>
+where
+    S: SecretRepository,
+    F: Future<Output = Result<()>>,
+{
+    let previous_pat = secret_repository.get_secret(credential_key).await.ok();
+    secret_repository.set_secret(credential_key, pat).await?;
+    if let Err(err) = changes.await {
+        let rollback_result = match previous_pat {
+            Some(previous_pat) => {
+                secret_repository
+                    .set_secret(credential_key, &previous_pat)
+                    .await
+            }
+            None => secret_repository.delete_secret(credential_key).await,
+        };
+        if let Err(rollback_err) = rollback_result {
+            log::error!(
+                "Error rolling back secret `{}`: {}",
+                credential_key,
+                rollback_err.to_string()
+            );
+        }
+        return Err(err);
+    }
+    Ok(())
+}
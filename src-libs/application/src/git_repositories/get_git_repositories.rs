@@ -1,31 +1,43 @@
-use crate::{dtos::GitRepositoryDto, traits::GitRepositoryRepository};
+use crate::{
+    dtos::GitRepositoryDto,
+    traits::{GitRepositoryRepository, RepositoryGroupRepository},
+};
 use anyhow::Result;
 
 /// Responsible for getting all imported git repositories
-pub struct GitRepositoriesQuery<G>
+pub struct GitRepositoriesQuery<G, M>
 where
     G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
 {
     git_repository_repository: G,
+    repository_group_repository: M,
 }
 
-impl<G> GitRepositoriesQuery<G>
+impl<G, M> GitRepositoriesQuery<G, M>
 where
     G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
 {
     /// Create a new instance of the query
     ///
     /// # Arguments
     ///
     /// * `git_repository_repository` - The repository to for git repositories access
-    pub fn new(git_repository_repository: G) -> Self {
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(git_repository_repository: G, repository_group_repository: M) -> Self {
         Self {
             git_repository_repository,
+            repository_group_repository,
         }
     }
 
     /// Execute the query
     ///
+    /// # Arguments
+    ///
+    /// * `group_id` - Optional repository group to restrict the result to
+    ///
     /// # Returns
     ///
     /// * `Result<Vec<GitRepositoryDto>, String>` The list of retrieved git repositories
@@ -33,11 +45,18 @@ where
     /// # Errors
     ///
     /// Any errors that might occur
-    pub async fn execute(&self) -> Result<Vec<GitRepositoryDto>> {
-        let git_repos = self
+    pub async fn execute(&self, group_id: Option<u32>) -> Result<Vec<GitRepositoryDto>> {
+        let mut git_repos = self
             .git_repository_repository
             .get_all_git_repositories()
             .await?;
+        if let Some(group_id) = group_id {
+            let group = self
+                .repository_group_repository
+                .get_repository_group_by_id(&group_id)
+                .await?;
+            git_repos.retain(|x| group.git_repository_ids.contains(&x.id));
+        }
         let result = git_repos.iter().map(|x| x.into()).collect();
         Ok(result)
     }
@@ -1,24 +1,36 @@
-use crate::traits::{AzureDevOpsRepository, GitRepositoryRepository, SecretRepository};
+use super::create_git_repositories_with_secret::create_git_repositories_with_secret;
+use crate::{
+    dtos::{DiscoveredGitRepositoryDto, DiscoveredProjectDto, GitRepositoryImportFilterDto},
+    traits::{
+        AzureDevOpsRepository, GitRepositoryRepository, PatMetadataRepository, SecretRepository,
+    },
+};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use domain::models::{Credential, GitRepository, PatMetadata};
+use glob::{MatchOptions, Pattern};
 
-/// Responsible for importing all git repositories from a single
+/// Responsible for importing the git repositories from a single
 /// Azure DevOps organization
-pub struct DevOpsOrgaImporter<A, G, S>
+pub struct DevOpsOrgaImporter<A, G, S, K>
 where
     A: AzureDevOpsRepository,
     G: GitRepositoryRepository,
     S: SecretRepository,
+    K: PatMetadataRepository,
 {
     azure_devops_repository: A,
     git_repository_repository: G,
     secret_repository: S,
+    pat_metadata_repository: K,
 }
 
-impl<A, G, S> DevOpsOrgaImporter<A, G, S>
+impl<A, G, S, K> DevOpsOrgaImporter<A, G, S, K>
 where
     A: AzureDevOpsRepository,
     G: GitRepositoryRepository,
     S: SecretRepository,
+    K: PatMetadataRepository,
 {
     /// Create a new instance of the importer
     ///
@@ -27,43 +39,223 @@ where
     /// * `azure_devops_repository` - The repository to get the git repositories from Azure DevOps
     /// * `git_repository_repository` - The repository to access git repositories
     /// * `secret_repository` - The repository to access secrets
+    /// * `pat_metadata_repository` - The repository to access the metadata of PATs
     pub fn new(
         azure_devops_repository: A,
         git_repository_repository: G,
         secret_repository: S,
+        pat_metadata_repository: K,
     ) -> Self {
         Self {
             azure_devops_repository,
             git_repository_repository,
             secret_repository,
+            pat_metadata_repository,
         }
     }
 
-    /// Import all the git repositories by querying the from the Azure
-    /// DevOps organization, store them in the database and store the
-    /// PAT in the secret storage
+    /// Discover the projects and git repositories of the Azure DevOps
+    /// organization, without persisting anything
     ///
     /// # Arguments
     ///
     /// * `organization_name` - The name of the Azure DevOps organization
     /// * `pat` - The PAT to access all git repositories
-    pub async fn import(&self, organization_name: &str, pat: &str) -> Result<()> {
-        let git_repositories = self
-            .azure_devops_repository
-            .get_repositories_in_organization(pat, organization_name)
+    /// * `filter` - The patterns the project and repository names must match
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<DiscoveredProjectDto>>` - The matching git repositories grouped by project
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when a pattern is invalid
+    pub async fn discover(
+        &self,
+        organization_name: &str,
+        pat: &str,
+        filter: &GitRepositoryImportFilterDto,
+    ) -> Result<Vec<DiscoveredProjectDto>> {
+        let remote_git_repos = self
+            .get_filtered_repositories(organization_name, &Credential::Pat(pat.to_string()), filter)
+            .await?;
+        let local_git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let mut result = Vec::<DiscoveredProjectDto>::new();
+        for gr in remote_git_repos {
+            let project_name = project_name(&gr).to_string();
+            let repository = DiscoveredGitRepositoryDto {
+                is_imported: local_git_repos.iter().any(|x| x.is_same_remote(&gr)),
+                remote_id: gr.remote_id,
+                name: gr.name,
+                context: gr.context,
+            };
+            match result.iter_mut().find(|x| x.name == project_name) {
+                Some(project) => project.repositories.push(repository),
+                None => result.push(DiscoveredProjectDto {
+                    name: project_name,
+                    repositories: vec![repository],
+                }),
+            }
+        }
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        result
+            .iter_mut()
+            .for_each(|x| x.repositories.sort_by(|a, b| a.name.cmp(&b.name)));
+        Ok(result)
+    }
+
+    /// Import the git repositories by querying them from the Azure
+    /// DevOps organization, store the ones not yet imported in the
+    /// database and store the PAT in the secret storage
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_name` - The name of the Azure DevOps organization
+    /// * `pat` - The PAT to access all git repositories
+    /// * `filter` - The patterns the project and repository names must match
+    /// * `remote_ids` - Optional subset of discovered git repositories to import
+    /// * `pat_expires_at` - When the PAT expires, if known
+    ///
+    /// # Returns
+    ///
+    /// * `Result<usize>` - The number of imported git repositories
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the PAT is invalid
+    pub async fn import(
+        &self,
+        organization_name: &str,
+        pat: &str,
+        filter: &GitRepositoryImportFilterDto,
+        remote_ids: Option<&[String]>,
+        pat_expires_at: Option<DateTime<Utc>>,
+    ) -> Result<usize> {
+        let credential = Credential::Pat(pat.to_string());
+        self.azure_devops_repository
+            .validate_credential(&credential, organization_name)
             .await?;
+        let local_git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let git_repositories = self
+            .get_filtered_repositories(organization_name, &credential, filter)
+            .await?
+            .into_iter()
+            .filter(|x| {
+                remote_ids.is_none_or(|ids| {
+                    x.remote_id
+                        .as_ref()
+                        .is_some_and(|remote_id| ids.contains(remote_id))
+                })
+            })
+            .filter(|x| !local_git_repos.iter().any(|l| l.is_same_remote(x)))
+            .collect::<Vec<GitRepository>>();
+        let imported = git_repositories.len();
         if let Some(first) = git_repositories.first() {
             // When importing a whole organization, all repositories share the same
             // PAT and therefore only one secret needs to be stored
-            self.secret_repository
-                .set_secret(&first.pat_secret_key, pat)?;
-            // Store all the repositories after the secret is created
-            for gr in git_repositories {
-                self.git_repository_repository
-                    .create_git_repository(gr)
-                    .await?;
-            }
+            let credential_key = first.credential_key.to_string();
+            create_git_repositories_with_secret(
+                &self.git_repository_repository,
+                &self.secret_repository,
+                &credential_key,
+                pat,
+                git_repositories,
+            )
+            .await?;
+            self.pat_metadata_repository
+                .save_pat_metadata(PatMetadata {
+                    credential_key,
+                    expires_at: pat_expires_at,
+                    validated_at: Some(Utc::now()),
+                })
+                .await?;
         }
-        Ok(())
+        Ok(imported)
+    }
+
+    /// Get the git repositories of the organization, that match the filter
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_name` - The name of the Azure DevOps organization
+    /// * `credential` - The credential to access all git repositories
+    /// * `filter` - The patterns the project and repository names must match
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_filtered_repositories(
+        &self,
+        organization_name: &str,
+        credential: &Credential,
+        filter: &GitRepositoryImportFilterDto,
+    ) -> Result<Vec<GitRepository>> {
+        let include_projects = parse_patterns(&filter.include_projects)?;
+        let exclude_projects = parse_patterns(&filter.exclude_projects)?;
+        let include_repositories = parse_patterns(&filter.include_repositories)?;
+        let exclude_repositories = parse_patterns(&filter.exclude_repositories)?;
+        let git_repositories = self
+            .azure_devops_repository
+            .get_repositories_in_organization(credential, organization_name)
+            .await?
+            .into_iter()
+            .filter(|x| {
+                let project_name = project_name(x);
+                is_included(&include_projects, &exclude_projects, project_name)
+                    && is_included(&include_repositories, &exclude_repositories, &x.name)
+            })
+            .collect();
+        Ok(git_repositories)
     }
 }
+
+/// Parse the glob patterns, ignoring empty ones
+///
+/// # Arguments
+///
+/// * `patterns` - The raw glob patterns
+///
+/// # Errors
+///
+/// If any of the patterns is invalid
+fn parse_patterns(patterns: &[String]) -> Result<Vec<Pattern>> {
+    let result = patterns
+        .iter()
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(Pattern::new)
+        .collect::<Result<Vec<Pattern>, _>>()?;
+    Ok(result)
+}
+
+/// Check whether the value matches any include pattern (or there are none)
+/// and none of the exclude patterns, ignoring the case
+///
+/// # Arguments
+///
+/// * `include` - The patterns of which at least one must match
+/// * `exclude` - The patterns of which none must match
+/// * `value` - The value to check
+fn is_included(include: &[Pattern], exclude: &[Pattern], value: &str) -> bool {
+    let options = MatchOptions {
+        case_sensitive: false,
+        ..Default::default()
+    };
+    (include.is_empty() || include.iter().any(|x| x.matches_with(value, options)))
+        && !exclude.iter().any(|x| x.matches_with(value, options))
+}
+
+/// Get the name of the Azure DevOps project, a git repository belongs to
+///
+/// # Arguments
+///
+/// * `git_repository` - The git repository, with a context in the form of `organization/project`
+fn project_name(git_repository: &GitRepository) -> &str {
+    git_repository.context.split('/').nth(1).unwrap_or_default()
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use domain::enums::GitProvider;
+
+/// The parts of a git repository, identified by its URL
+pub(crate) struct ParsedGitRepositoryUrl {
+    pub git_provider: GitProvider,
+    pub context: String,
+    pub name: String,
+}
+
+/// Parse a clone or web URL of a git repository
+///
+/// Supported are the Azure DevOps formats
+///
+/// * `https://dev.azure.com/{organization}/{project}/_git/{repository}`
+/// * `https://{organization}.visualstudio.com/{project}/_git/{repository}`
+/// * `git@ssh.dev.azure.com:v3/{organization}/{project}/{repository}`
+/// * `{organization}@vs-ssh.visualstudio.com:v3/{organization}/{project}/{repository}`
+///
+/// # Arguments
+///
+/// * `url` - The URL to parse
+///
+/// # Returns
+///
+/// * `Result<ParsedGitRepositoryUrl>` - The git provider, context and name of the git repository
+///
+/// # Errors
+///
+/// If the URL does not belong to a supported git provider or is malformed
+pub(crate) fn parse_git_repository_url(url: &str) -> Result<ParsedGitRepositoryUrl> {
+    let url = url.trim();
+    let unsupported = || anyhow::anyhow!("The URL `{}` is not a supported git repository URL", url);
+    let (authority, path) = match ["https://", "http://", "ssh://"]
+        .iter()
+        .find_map(|x| url.strip_prefix(x))
+    {
+        Some(rest) => rest.split_once('/').ok_or_else(unsupported)?,
+        // SCP like syntax of ssh URLs, e.g. `git@host:path`
+        None => url.split_once(':').ok_or_else(unsupported)?,
+    };
+    // Remove the user info and port from the authority
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default().to_lowercase();
+    let segments = path
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|x| !x.is_empty())
+        .map(percent_decode)
+        .collect::<Vec<String>>();
+    let segments = segments.iter().map(|x| x.as_str()).collect::<Vec<&str>>();
+    let (organization, project, name) = match host.as_str() {
+        "dev.azure.com" => match segments.as_slice() {
+            [organization, rest @ ..] => {
+                let (project, name) = parse_web_path(rest).ok_or_else(unsupported)?;
+                (organization.to_string(), project, name)
+            }
+            _ => return Err(unsupported()),
+        },
+        "ssh.dev.azure.com" | "vs-ssh.visualstudio.com" => match segments.as_slice() {
+            ["v3", organization, project, name] => (
+                organization.to_string(),
+                project.to_string(),
+                name.to_string(),
+            ),
+            _ => return Err(unsupported()),
+        },
+        host if host.ends_with(".visualstudio.com") => {
+            let organization = host.trim_end_matches(".visualstudio.com");
+            // Old URLs may contain the name of the default collection
+            let rest = match segments.as_slice() {
+                [first, rest @ ..] if first.eq_ignore_ascii_case("DefaultCollection") => rest,
+                rest => rest,
+            };
+            let (project, name) = parse_web_path(rest).ok_or_else(unsupported)?;
+            (organization.to_string(), project, name)
+        }
+        _ => return Err(unsupported()),
+    };
+    Ok(ParsedGitRepositoryUrl {
+        git_provider: GitProvider::AzureDevOps,
+        context: format!("{}/{}", organization, project),
+        name,
+    })
+}
+
+/// Parse the project and repository name from the path of an Azure DevOps
+/// web URL, following the organization
+///
+/// The project can be omitted if the repository is named like the project,
+/// any segments following the repository name are ignored
+///
+/// # Arguments
+///
+/// * `segments` - The path segments following the organization
+fn parse_web_path(segments: &[&str]) -> Option<(String, String)> {
+    match segments {
+        ["_git", name, ..] => Some((name.to_string(), name.to_string())),
+        [project, "_git", name, ..] => Some((project.to_string(), name.to_string())),
+        _ => None,
+    }
+}
+
+/// Decode the percent-encoded characters of a single URL path segment
+///
+/// # Arguments
+///
+/// * `segment` - The encoded path segment
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::<u8>::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|x| std::str::from_utf8(x).ok())
+            .and_then(|x| u8::from_str_radix(x, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
@@ -0,0 +1,83 @@
+use super::select_git_repositories::select_git_repositories;
+use crate::{
+    dtos::GitRepositorySelectionDto,
+    traits::{GitRepositoryRepository, SecretRepository},
+};
+use anyhow::Result;
+use std::collections::HashSet;
+
+/// Responsible for removing multiple imported git repositories at once
+pub struct RemoveGitRepositoriesCommand<G, S>
+where
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    git_repository_repository: G,
+    secret_repository: S,
+}
+
+impl<G, S> RemoveGitRepositoriesCommand<G, S>
+where
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `secret_repository` - The repository to access secrets
+    pub fn new(git_repository_repository: G, secret_repository: S) -> Self {
+        Self {
+            git_repository_repository,
+            secret_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `selection` - The ids and/or pattern to select the git repositories by
+    ///
+    /// # Returns
+    ///
+    /// * `Result<usize>` - The number of removed git repositories
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, selection: &GitRepositorySelectionDto) -> Result<usize> {
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let target_repos = select_git_repositories(&git_repos, selection)?;
+        if target_repos.is_empty() {
+            return Ok(0);
+        }
+        let ids = target_repos.iter().map(|x| x.id).collect::<Vec<u32>>();
+        self.git_repository_repository
+            .delete_git_repositories(&ids)
+            .await?;
+        // Only remove the secrets no longer referenced by any remaining
+        // git repository, each of them once
+        let remaining_secret_keys = git_repos
+            .iter()
+            .filter(|x| !ids.contains(&x.id))
+            .map(|x| x.credential_key.as_str())
+            .collect::<HashSet<&str>>();
+        let obsolete_secret_keys = target_repos
+            .iter()
+            .map(|x| x.credential_key.as_str())
+            .filter(|x| !remaining_secret_keys.contains(x))
+            .collect::<HashSet<&str>>();
+        for secret_key in obsolete_secret_keys {
+            // Secrets left behind are reported as orphaned by the secret health check
+            if let Err(err) = self.secret_repository.delete_secret(secret_key).await {
+                log::warn!("Error deleting secret `{}`: {}", secret_key, err);
+            }
+        }
+        Ok(ids.len())
+    }
+}
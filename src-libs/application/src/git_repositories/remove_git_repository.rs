@@ -1,14 +1,20 @@
-use crate::traits::{GitRepositoryRepository, SecretRepository};
+use super::remove_git_repositories::RemoveGitRepositoriesCommand;
+use crate::{
+    dtos::GitRepositorySelectionDto,
+    traits::{GitRepositoryRepository, SecretRepository},
+};
 use anyhow::Result;
 
 /// Responsible for removing an imported git repository
+///
+/// The removal is delegated to the bulk removal, so the git repository is
+/// deleted before its secret, which is only removed if no longer referenced.
 pub struct RemoveGitRepositoryCommand<G, S>
 where
     G: GitRepositoryRepository,
     S: SecretRepository,
 {
-    git_repository_repository: G,
-    secret_repository: S,
+    remove_git_repositories_command: RemoveGitRepositoriesCommand<G, S>,
 }
 
 impl<G, S> RemoveGitRepositoryCommand<G, S>
@@ -24,8 +30,10 @@ where
     /// * `secret_repository` - The repository to access secrets
     pub fn new(git_repository_repository: G, secret_repository: S) -> Self {
         Self {
-            git_repository_repository,
-            secret_repository,
+            remove_git_repositories_command: RemoveGitRepositoriesCommand::new(
+                git_repository_repository,
+                secret_repository,
+            ),
         }
     }
 
@@ -37,31 +45,18 @@ where
     ///
     /// # Errors
     ///
-    /// Any errors that might occur
+    /// Any errors that might occur, e.g. when the git repository does not exist
     pub async fn execute(&self, id: &u32) -> Result<()> {
-        let git_repos = self
-            .git_repository_repository
-            .get_all_git_repositories()
+        let removed_count = self
+            .remove_git_repositories_command
+            .execute(&GitRepositorySelectionDto {
+                ids: vec![*id],
+                pattern: None,
+            })
             .await?;
-        let target_repo = git_repos
-            .iter()
-            .find(|x| x.id == *id)
-            .ok_or_else(|| anyhow::anyhow!("No git repository with id found"))?;
-        if git_repos
-            .iter()
-            .filter(|x| x.pat_secret_key == target_repo.pat_secret_key)
-            .count()
-            == 1
-        {
-            // Only remove the secret if the git repository to remove is the
-            // last one referencing it
-            let _ = self
-                .secret_repository
-                .delete_secret(&target_repo.pat_secret_key);
+        if removed_count == 0 {
+            return Err(anyhow::anyhow!("No git repository with id found"));
         }
-        self.git_repository_repository
-            .delete_git_repository(id)
-            .await?;
         Ok(())
     }
 }
@@ -0,0 +1,50 @@
+use crate::dtos::GitRepositorySelectionDto;
+use anyhow::Result;
+use domain::models::GitRepository;
+use glob::{MatchOptions, Pattern};
+
+/// Resolve a selection of git repositories for bulk operations
+///
+/// A git repository is selected if its id is listed explicitly or its name
+/// or context matches the glob pattern (case insensitive, e.g. `team-*`)
+///
+/// # Arguments
+///
+/// * `git_repositories` - All imported git repositories
+/// * `selection` - The ids and/or pattern to select git repositories by
+///
+/// # Returns
+///
+/// * `Result<Vec<&GitRepository>>` - The selected git repositories
+///
+/// # Errors
+///
+/// If the selection is empty, or the pattern is invalid
+pub(crate) fn select_git_repositories<'a>(
+    git_repositories: &'a [GitRepository],
+    selection: &GitRepositorySelectionDto,
+) -> Result<Vec<&'a GitRepository>> {
+    let pattern = match selection.pattern.as_deref().map(str::trim) {
+        Some(pattern) if !pattern.is_empty() => Some(Pattern::new(pattern)?),
+        _ => None,
+    };
+    if selection.ids.is_empty() && pattern.is_none() {
+        return Err(anyhow::anyhow!(
+            "Either ids or a pattern must be given to select git repositories"
+        ));
+    }
+    let options = MatchOptions {
+        case_sensitive: false,
+        ..Default::default()
+    };
+    let result = git_repositories
+        .iter()
+        .filter(|x| {
+            selection.ids.contains(&x.id)
+                || pattern.as_ref().is_some_and(|p| {
+                    p.matches_with(&x.name, options) || p.matches_with(&x.context, options)
+                })
+        })
+        .collect();
+    Ok(result)
+}
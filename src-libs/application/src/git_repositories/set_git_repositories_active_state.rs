@@ -0,0 +1,64 @@
+use super::select_git_repositories::select_git_repositories;
+use crate::{dtos::GitRepositorySelectionDto, traits::GitRepositoryRepository};
+use anyhow::Result;
+
+/// Responsible for activating or deactivating multiple imported
+/// git repositories at once
+pub struct SetGitRepositoriesActiveStateCommand<G>
+where
+    G: GitRepositoryRepository,
+{
+    git_repository_repository: G,
+}
+
+impl<G> SetGitRepositoriesActiveStateCommand<G>
+where
+    G: GitRepositoryRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    pub fn new(git_repository_repository: G) -> Self {
+        Self {
+            git_repository_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `selection` - The ids and/or pattern to select the git repositories by
+    /// * `is_active` - The new active state
+    ///
+    /// # Returns
+    ///
+    /// * `Result<usize>` - The number of changed git repositories
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(
+        &self,
+        selection: &GitRepositorySelectionDto,
+        is_active: bool,
+    ) -> Result<usize> {
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let ids = select_git_repositories(&git_repos, selection)?
+            .iter()
+            .filter(|x| x.is_active != is_active)
+            .map(|x| x.id)
+            .collect::<Vec<u32>>();
+        if !ids.is_empty() {
+            self.git_repository_repository
+                .set_git_repositories_active_state(&ids, is_active)
+                .await?;
+        }
+        Ok(ids.len())
+    }
+}
@@ -0,0 +1,201 @@
+use super::create_git_repositories_with_secret::apply_with_secret;
+use crate::{
+    authentication::oauth_credential_key,
+    dtos::GitRepositorySyncResultDto,
+    traits::{AzureDevOpsRepository, GitRepositoryRepository, SecretRepository},
+};
+use anyhow::Result;
+use domain::{
+    enums::{CredentialType, GitProvider},
+    models::{Credential, GitRepository},
+};
+use std::collections::HashSet;
+
+/// Responsible for synchronizing the imported git repositories of a single
+/// Azure DevOps organization with the ones existing on the remote
+pub struct SyncAzureDevOpsOrganizationRepositoriesCommand<A, G, S>
+where
+    A: AzureDevOpsRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    azure_devops_repository: A,
+    git_repository_repository: G,
+    secret_repository: S,
+}
+
+impl<A, G, S> SyncAzureDevOpsOrganizationRepositoriesCommand<A, G, S>
+where
+    A: AzureDevOpsRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `azure_devops_repository` - The repository to get the git repositories from Azure DevOps
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `secret_repository` - The repository to access secrets
+    pub fn new(
+        azure_devops_repository: A,
+        git_repository_repository: G,
+        secret_repository: S,
+    ) -> Self {
+        Self {
+            azure_devops_repository,
+            git_repository_repository,
+            secret_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// New remote git repositories are imported, the ones no longer existing on
+    /// the remote are marked as orphaned and renamed or moved ones are updated
+    /// in place, identified by their stable remote identifier
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_name` - The name of the Azure DevOps organization
+    /// * `pat` - The PAT to access all git repositories, the stored credential is used if not set
+    /// * `activate_new` - Whether newly imported git repositories are marked as active
+    ///
+    /// # Returns
+    ///
+    /// * `Result<GitRepositorySyncResultDto>` - The summary of the applied changes
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the PAT is invalid or a project
+    /// could not be listed, in which case nothing is changed
+    pub async fn execute(
+        &self,
+        organization_name: &str,
+        pat: Option<&str>,
+        activate_new: bool,
+    ) -> Result<GitRepositorySyncResultDto> {
+        let local_git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?
+            .into_iter()
+            .filter(|x| {
+                matches!(x.git_provider, GitProvider::AzureDevOps)
+                    && x.context.split('/').next() == Some(organization_name)
+            })
+            .collect::<Vec<GitRepository>>();
+        let credential = match (pat, local_git_repos.first()) {
+            // A newly supplied PAT is only stored once it is known to be valid
+            (Some(pat), _) => {
+                let credential = Credential::Pat(pat.to_string());
+                self.azure_devops_repository
+                    .validate_credential(&credential, organization_name)
+                    .await?;
+                credential
+            }
+            (None, Some(first)) => match first.credential_type {
+                CredentialType::Pat => Credential::Pat(
+                    self.secret_repository
+                        .get_secret(&first.credential_key)
+                        .await?,
+                ),
+                CredentialType::OAuth => Credential::OAuth(first.credential_key.to_string()),
+            },
+            // Allows to import the git repositories of an organization right
+            // after signing in with OAuth
+            (None, None) => {
+                let credential_key = oauth_credential_key(organization_name);
+                if self
+                    .secret_repository
+                    .get_secret(&credential_key)
+                    .await
+                    .is_err()
+                {
+                    return Err(anyhow::anyhow!(
+                        "No PAT given and no git repositories of the organization imported"
+                    ));
+                }
+                Credential::OAuth(credential_key)
+            }
+        };
+        let remote_git_repos = self
+            .azure_devops_repository
+            .get_repositories_in_organization(&credential, organization_name)
+            .await?;
+        let credential_key = remote_git_repos
+            .first()
+            .map(|x| x.credential_key.to_string())
+            .unwrap_or_default();
+        let mut result = GitRepositorySyncResultDto::default();
+        let mut synced_ids = HashSet::<u32>::new();
+        let mut created_git_repos = Vec::<GitRepository>::new();
+        let mut updated_git_repos = Vec::<GitRepository>::new();
+        for mut remote in remote_git_repos {
+            // Prefer the stable remote identifier, but fall back to name and context
+            // for git repositories imported before it was stored
+            let local = local_git_repos
+                .iter()
+                .find(|x| x.remote_id.is_some() && x.remote_id == remote.remote_id)
+                .or_else(|| {
+                    local_git_repos.iter().find(|x| {
+                        x.remote_id.is_none()
+                            && x.name == remote.name
+                            && x.context == remote.context
+                    })
+                });
+            match local {
+                Some(local) => {
+                    synced_ids.insert(local.id);
+                    let is_renamed = local.name != remote.name || local.context != remote.context;
+                    if !is_renamed && !local.is_orphaned && local.remote_id == remote.remote_id {
+                        continue;
+                    }
+                    if is_renamed {
+                        result.renamed += 1;
+                    }
+                    if local.is_orphaned {
+                        result.restored += 1;
+                    }
+                    updated_git_repos.push(GitRepository {
+                        id: local.id,
+                        name: remote.name,
+                        context: remote.context,
+                        git_provider: remote.git_provider,
+                        is_active: local.is_active,
+                        credential_type: local.credential_type,
+                        credential_key: local.credential_key.to_string(),
+                        remote_id: remote.remote_id,
+                        is_orphaned: false,
+                    });
+                }
+                None => {
+                    remote.is_active = activate_new;
+                    created_git_repos.push(remote);
+                    result.added += 1;
+                }
+            }
+        }
+        for mut local in local_git_repos {
+            if synced_ids.contains(&local.id) || local.is_orphaned {
+                continue;
+            }
+            local.is_orphaned = true;
+            updated_git_repos.push(local);
+            result.orphaned += 1;
+        }
+        // Renames, orphan flags, new git repositories and the updated PAT are
+        // applied together, so a failure never leaves the synchronization half applied
+        let changes = self
+            .git_repository_repository
+            .sync_git_repositories(updated_git_repos, created_git_repos);
+        match credential {
+            Credential::Pat(pat) if !credential_key.is_empty() => {
+                apply_with_secret(&self.secret_repository, &credential_key, &pat, changes).await?;
+            }
+            // The refresh token is already stored when signing in
+            _ => changes.await?,
+        }
+        Ok(result)
+    }
+}
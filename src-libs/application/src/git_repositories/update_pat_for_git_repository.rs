@@ -1,31 +1,53 @@
-use crate::traits::{GitRepositoryRepository, SecretRepository};
+use crate::traits::{
+    AzureDevOpsRepository, GitRepositoryRepository, PatMetadataRepository, SecretRepository,
+};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use domain::{
+    enums::{CredentialType, GitProvider},
+    models::{Credential, PatMetadata},
+};
 
 /// Responsible for updating the stored PAT for a single imported git repository
-pub struct UpdatePatForGitRepositoryCommand<G, S>
+pub struct UpdatePatForGitRepositoryCommand<A, G, S, K>
 where
+    A: AzureDevOpsRepository,
     G: GitRepositoryRepository,
     S: SecretRepository,
+    K: PatMetadataRepository,
 {
+    azure_devops_repository: A,
     git_repository_repository: G,
     secret_repository: S,
+    pat_metadata_repository: K,
 }
 
-impl<G, S> UpdatePatForGitRepositoryCommand<G, S>
+impl<A, G, S, K> UpdatePatForGitRepositoryCommand<A, G, S, K>
 where
+    A: AzureDevOpsRepository,
     G: GitRepositoryRepository,
     S: SecretRepository,
+    K: PatMetadataRepository,
 {
     /// Create a new instance of the command
     ///
     /// # Arguments
     ///
+    /// * `azure_devops_repository` - The repository to validate the PAT against Azure DevOps
     /// * `git_repository_repository` - The repository to access git repositories
     /// * `secret_repository` - The repository to access secrets
-    pub fn new(git_repository_repository: G, secret_repository: S) -> Self {
+    /// * `pat_metadata_repository` - The repository to access the metadata of PATs
+    pub fn new(
+        azure_devops_repository: A,
+        git_repository_repository: G,
+        secret_repository: S,
+        pat_metadata_repository: K,
+    ) -> Self {
         Self {
+            azure_devops_repository,
             git_repository_repository,
             secret_repository,
+            pat_metadata_repository,
         }
     }
 
@@ -35,17 +57,45 @@ where
     ///
     /// * `id` - The unique identifier of the targeted git repository
     /// * `pat` - The PAT to store
+    /// * `expires_at` - When the PAT expires, if known
     ///
     /// # Errors
     ///
-    /// Any errors that might occur
-    pub async fn execute(&self, id: &u32, pat: &str) -> Result<()> {
+    /// Any errors that might occur, e.g. when the PAT is invalid
+    pub async fn execute(
+        &self,
+        id: &u32,
+        pat: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
         let git_repo = self
             .git_repository_repository
             .get_git_repository_by_id(id)
             .await?;
+        if git_repo.credential_type == CredentialType::OAuth {
+            return Err(anyhow::anyhow!(
+                "Git repository `{}` is accessed via OAuth sign in and has no PAT",
+                git_repo.name
+            ));
+        }
+        match git_repo.git_provider {
+            GitProvider::AzureDevOps => {
+                let organization_name = git_repo.context.split('/').next().unwrap_or_default();
+                self.azure_devops_repository
+                    .validate_credential(&Credential::Pat(pat.to_string()), organization_name)
+                    .await?;
+            }
+        }
         self.secret_repository
-            .set_secret(&git_repo.pat_secret_key, pat)?;
+            .set_secret(&git_repo.credential_key, pat)
+            .await?;
+        self.pat_metadata_repository
+            .save_pat_metadata(PatMetadata {
+                credential_key: git_repo.credential_key,
+                expires_at,
+                validated_at: Some(Utc::now()),
+            })
+            .await?;
         Ok(())
     }
 }
@@ -1,4 +1,14 @@
+pub mod analytics;
+pub mod authentication;
+pub mod configuration;
+pub mod database_backups;
 pub mod dtos;
 pub mod git_repositories;
+pub mod personal_access_tokens;
+pub mod profiles;
 pub mod pull_requests;
+pub mod repository_groups;
+pub mod secrets;
+pub mod settings;
+pub mod sla_rules;
 pub mod traits;
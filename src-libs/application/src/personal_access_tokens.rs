@@ -0,0 +1,2 @@
+pub mod get_pat_expiry_warnings;
+pub mod set_pat_expiry_date;
@@ -0,0 +1,105 @@
+use crate::{
+    dtos::PatExpiryWarningDto,
+    traits::{GitRepositoryRepository, PatMetadataRepository, SettingsRepository},
+};
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+/// Responsible for getting the stored PATs that are expired or
+/// about to expire
+pub struct GetPatExpiryWarningsQuery<G, K, R>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    R: SettingsRepository,
+{
+    git_repository_repository: G,
+    pat_metadata_repository: K,
+    settings_repository: R,
+}
+
+impl<G, K, R> GetPatExpiryWarningsQuery<G, K, R>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    R: SettingsRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pat_metadata_repository` - The repository to access the metadata of PATs
+    /// * `settings_repository` - The repository to get the configured warning period
+    pub fn new(
+        git_repository_repository: G,
+        pat_metadata_repository: K,
+        settings_repository: R,
+    ) -> Self {
+        Self {
+            git_repository_repository,
+            pat_metadata_repository,
+            settings_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - How many days before their expiry PATs are included, the
+    ///   configured warning period if none
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PatExpiryWarningDto>>` - The warnings, ordered by the expiry date
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, days: Option<i64>) -> Result<Vec<PatExpiryWarningDto>> {
+        let days = match days {
+            Some(days) => days,
+            None => self
+                .settings_repository
+                .get_settings()
+                .await?
+                .pat_expiry_warning_days
+                .into(),
+        };
+        let now = Utc::now();
+        let warn_before = now + Duration::days(days);
+        let git_repos = self
+            .git_repository_repository
+            .get_all_git_repositories()
+            .await?;
+        let pat_metadata = self.pat_metadata_repository.get_all_pat_metadata().await?;
+        let mut result = Vec::<PatExpiryWarningDto>::new();
+        for metadata in pat_metadata {
+            let Some(expires_at) = metadata.expires_at.filter(|x| *x <= warn_before) else {
+                continue;
+            };
+            // PATs no longer referenced by any git repository are irrelevant
+            let git_repos_using_pat = git_repos
+                .iter()
+                .filter(|x| x.credential_key == metadata.credential_key)
+                .collect::<Vec<_>>();
+            let Some(first) = git_repos_using_pat.first() else {
+                continue;
+            };
+            result.push(PatExpiryWarningDto {
+                organization_name: first
+                    .context
+                    .split('/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                git_repository_ids: git_repos_using_pat.iter().map(|x| x.id).collect(),
+                expires_at,
+                is_expired: expires_at <= now,
+            });
+        }
+        result.sort_by_key(|x| x.expires_at);
+        Ok(result)
+    }
+}
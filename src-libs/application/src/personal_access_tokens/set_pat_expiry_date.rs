@@ -0,0 +1,74 @@
+use crate::traits::{GitRepositoryRepository, PatMetadataRepository};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use domain::{enums::CredentialType, models::PatMetadata};
+
+/// Responsible for setting the expiry date of the PAT used by a single
+/// imported git repository, as entered by the user
+pub struct SetPatExpiryDateCommand<G, K>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+{
+    git_repository_repository: G,
+    pat_metadata_repository: K,
+}
+
+impl<G, K> SetPatExpiryDateCommand<G, K>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pat_metadata_repository` - The repository to access the metadata of PATs
+    pub fn new(git_repository_repository: G, pat_metadata_repository: K) -> Self {
+        Self {
+            git_repository_repository,
+            pat_metadata_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of a git repository using the PAT
+    /// * `expires_at` - When the PAT expires, removed if not set
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(
+        &self,
+        git_repository_id: &u32,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let git_repo = self
+            .git_repository_repository
+            .get_git_repository_by_id(git_repository_id)
+            .await?;
+        if git_repo.credential_type == CredentialType::OAuth {
+            return Err(anyhow::anyhow!(
+                "Git repository `{}` is accessed via OAuth sign in and has no PAT",
+                git_repo.name
+            ));
+        }
+        let validated_at = self
+            .pat_metadata_repository
+            .get_pat_metadata(&git_repo.credential_key)
+            .await?
+            .and_then(|x| x.validated_at);
+        self.pat_metadata_repository
+            .save_pat_metadata(PatMetadata {
+                credential_key: git_repo.credential_key,
+                expires_at,
+                validated_at,
+            })
+            .await?;
+        Ok(())
+    }
+}
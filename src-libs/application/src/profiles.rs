@@ -0,0 +1,5 @@
+pub mod create_profile;
+pub mod get_profiles;
+pub mod remove_profile;
+pub mod rename_profile;
+pub mod switch_profile;
@@ -0,0 +1,53 @@
+use crate::{dtos::ProfileDto, traits::ProfileRepository};
+use anyhow::Result;
+
+/// Responsible for creating a new profile
+pub struct CreateProfileCommand<P>
+where
+    P: ProfileRepository,
+{
+    profile_repository: P,
+}
+
+impl<P> CreateProfileCommand<P>
+where
+    P: ProfileRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to access profiles
+    pub fn new(profile_repository: P) -> Self {
+        Self { profile_repository }
+    }
+
+    /// Execute the command
+    ///
+    /// The profile starts without any git repositories and secrets, it
+    /// needs to be switched to before importing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the profile
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ProfileDto>` - The created profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the name is empty or already taken
+    pub async fn execute(&self, name: &str) -> Result<ProfileDto> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("The profile name must not be empty"));
+        }
+        let profiles = self.profile_repository.get_profiles().await?;
+        if profiles.iter().any(|x| x.name.eq_ignore_ascii_case(name)) {
+            return Err(anyhow::anyhow!("The profile `{}` already exists", name));
+        }
+        let profile = self.profile_repository.create_profile(name).await?;
+        Ok((&profile).into())
+    }
+}
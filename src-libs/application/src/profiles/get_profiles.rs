@@ -0,0 +1,46 @@
+use crate::{dtos::ProfileDto, traits::ProfileRepository};
+use anyhow::Result;
+
+/// Responsible for getting all profiles
+pub struct ProfilesQuery<P>
+where
+    P: ProfileRepository,
+{
+    profile_repository: P,
+}
+
+impl<P> ProfilesQuery<P>
+where
+    P: ProfileRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to access profiles
+    pub fn new(profile_repository: P) -> Self {
+        Self { profile_repository }
+    }
+
+    /// Execute the query
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<ProfileDto>>` - All profiles, marking the active one
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<Vec<ProfileDto>> {
+        let active_profile = self.profile_repository.get_active_profile().await?;
+        let profiles = self.profile_repository.get_profiles().await?;
+        Ok(profiles
+            .iter()
+            .map(|x| {
+                let mut dto = ProfileDto::from(x);
+                dto.is_active = x.id == active_profile.id;
+                dto
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::traits::ProfileRepository;
+use anyhow::Result;
+
+/// Responsible for removing a profile including its data
+pub struct RemoveProfileCommand<P>
+where
+    P: ProfileRepository,
+{
+    profile_repository: P,
+}
+
+impl<P> RemoveProfileCommand<P>
+where
+    P: ProfileRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to access profiles
+    pub fn new(profile_repository: P) -> Self {
+        Self { profile_repository }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the profile is the default or
+    /// the active one
+    pub async fn execute(&self, id: &str) -> Result<()> {
+        let profile = self
+            .profile_repository
+            .get_profiles()
+            .await?
+            .into_iter()
+            .find(|x| x.id == id)
+            .ok_or_else(|| anyhow::anyhow!("The profile `{}` does not exist", id))?;
+        if profile.is_default() {
+            return Err(anyhow::anyhow!("The default profile can not be removed"));
+        }
+        let active_profile = self.profile_repository.get_active_profile().await?;
+        if active_profile.id == profile.id {
+            return Err(anyhow::anyhow!(
+                "The active profile can not be removed, please switch to another one first"
+            ));
+        }
+        self.profile_repository.delete_profile(id).await?;
+        Ok(())
+    }
+}
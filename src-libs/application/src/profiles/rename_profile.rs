@@ -0,0 +1,52 @@
+use crate::traits::ProfileRepository;
+use anyhow::Result;
+
+/// Responsible for renaming a profile
+pub struct RenameProfileCommand<P>
+where
+    P: ProfileRepository,
+{
+    profile_repository: P,
+}
+
+impl<P> RenameProfileCommand<P>
+where
+    P: ProfileRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to access profiles
+    pub fn new(profile_repository: P) -> Self {
+        Self { profile_repository }
+    }
+
+    /// Execute the command
+    ///
+    /// Only the display name changes, the data of the profile is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the profile
+    /// * `name` - The new name of the profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the name is empty or already taken
+    pub async fn execute(&self, id: &str, name: &str) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("The profile name must not be empty"));
+        }
+        let profiles = self.profile_repository.get_profiles().await?;
+        if profiles
+            .iter()
+            .any(|x| x.id != id && x.name.eq_ignore_ascii_case(name))
+        {
+            return Err(anyhow::anyhow!("The profile `{}` already exists", name));
+        }
+        self.profile_repository.rename_profile(id, name).await?;
+        Ok(())
+    }
+}
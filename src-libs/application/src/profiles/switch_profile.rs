@@ -0,0 +1,67 @@
+use crate::{
+    dtos::ProfileDto,
+    traits::{ProfileActivator, ProfileRepository},
+};
+use anyhow::Result;
+
+/// Responsible for switching to another profile without restarting the application
+pub struct SwitchProfileCommand<P, A>
+where
+    P: ProfileRepository,
+    A: ProfileActivator,
+{
+    profile_repository: P,
+    profile_activator: A,
+}
+
+impl<P, A> SwitchProfileCommand<P, A>
+where
+    P: ProfileRepository,
+    A: ProfileActivator,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to access profiles
+    /// * `profile_activator` - Replaces the dependencies bound to the active profile
+    pub fn new(profile_repository: P, profile_activator: A) -> Self {
+        Self {
+            profile_repository,
+            profile_activator,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// The profile is only remembered for the next start, once it was
+    /// activated successfully.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the profile to switch to
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ProfileDto>` - The now active profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the profile does not exist
+    pub async fn execute(&self, id: &str) -> Result<ProfileDto> {
+        let profile = self
+            .profile_repository
+            .get_profiles()
+            .await?
+            .into_iter()
+            .find(|x| x.id == id)
+            .ok_or_else(|| anyhow::anyhow!("The profile `{}` does not exist", id))?;
+        self.profile_activator.activate_profile(&profile).await?;
+        self.profile_repository
+            .set_active_profile(&profile.id)
+            .await?;
+        let mut dto = ProfileDto::from(&profile);
+        dto.is_active = true;
+        Ok(dto)
+    }
+}
@@ -1 +1,256 @@
+pub mod cleanup_pull_request_annotations;
+pub mod clear_pull_request_snooze;
 pub mod get_open_pull_requests;
+pub mod get_reviewer_workload;
+pub mod mark_pull_request_reviewed;
+pub mod mark_pull_request_seen;
+pub mod mark_pull_request_unread;
+pub mod open_pull_request_source;
+pub mod set_pull_request_pin_state;
+pub mod snooze_pull_request;
+pub mod track_pull_request_annotations;
+pub mod track_pull_request_lifecycles;
+pub mod track_pull_request_reviews;
+pub mod update_pull_request_annotation;
+
+use crate::traits::{
+    AzureDevOpsRepository, GitRepositoryRepository, RepositoryGroupRepository, SecretRepository,
+};
+use anyhow::Result;
+use domain::{
+    enums::{CredentialType, GitProvider},
+    models::{Credential, GitRepository, PullRequest, PullRequestReviewers},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio::task::JoinSet;
+
+/// Get the git repositories whose open pull requests are retrieved, which
+/// are the ones marked as active and optionally limited to a repository group
+///
+/// # Arguments
+///
+/// * `git_repository_repository` - The repository to get imported git repositories
+/// * `repository_group_repository` - The repository to get the repository groups
+/// * `group_id` - The unique identifier of the repository group to limit the git repositories to
+///
+/// # Returns
+///
+/// * `Result<Vec<GitRepository>>` - The git repositories to query
+///
+/// # Errors
+///
+/// Any errors that might occur, e.g. when the repository group does not exist
+pub(crate) async fn get_active_git_repositories<G, M>(
+    git_repository_repository: &G,
+    repository_group_repository: &M,
+    group_id: Option<u32>,
+) -> Result<Vec<GitRepository>>
+where
+    G: GitRepositoryRepository,
+    M: RepositoryGroupRepository,
+{
+    let git_repos = git_repository_repository.get_all_git_repositories().await?;
+    let group_git_repo_ids = match group_id {
+        Some(group_id) => Some(
+            repository_group_repository
+                .get_repository_group_by_id(&group_id)
+                .await?
+                .git_repository_ids,
+        ),
+        None => None,
+    };
+    let result = git_repos
+        .into_iter()
+        .filter(|x| x.is_active && !x.is_orphaned)
+        .filter(|x| {
+            group_git_repo_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&x.id))
+        })
+        .collect();
+    Ok(result)
+}
+
+/// Get the credentials to query the git repositories with
+///
+/// Each distinct PAT is looked up only once, as the git repositories of an
+/// organization usually share it.
+///
+/// # Arguments
+///
+/// * `secret_repository` - The repositories to get secrets
+/// * `git_repos` - The git repositories to query
+///
+/// # Returns
+///
+/// * `Result<Vec<(Credential, GitRepository)>>` - The git repositories with their credential
+///
+/// # Errors
+///
+/// Any errors that might occur, e.g. when a PAT is missing
+pub(crate) async fn resolve_credentials<S>(
+    secret_repository: &S,
+    git_repos: Vec<GitRepository>,
+) -> Result<Vec<(Credential, GitRepository)>>
+where
+    S: SecretRepository,
+{
+    let mut pats = HashMap::<String, String>::new();
+    for gr in git_repos
+        .iter()
+        .filter(|x| x.credential_type == CredentialType::Pat)
+    {
+        if !pats.contains_key(&gr.credential_key) {
+            let pat = secret_repository.get_secret(&gr.credential_key).await?;
+            pats.insert(gr.credential_key.to_string(), pat);
+        }
+    }
+    let mut result = vec![];
+    for gr in git_repos {
+        let credential = Credential::for_git_repository(&gr, |x| {
+            pats.get(x)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No PAT found for key `{}`", x))
+        })?;
+        result.push((credential, gr));
+    }
+    Ok(result)
+}
+
+/// Retrieve the open pull requests of multiple git repositories concurrently
+///
+/// # Arguments
+///
+/// * `azure_devops_repository` - The repository to get pull requests from Azure DevOps
+/// * `git_repos` - The git repositories to query and their credential
+///
+/// # Returns
+///
+/// * `Result<(Vec<PullRequest>, HashSet<u32>)>` - The retrieved pull requests and
+///   the git repositories whose open pull requests were retrieved
+///
+/// # Errors
+///
+/// Any errors that might occur
+pub(crate) async fn retrieve_open_pull_requests<A>(
+    azure_devops_repository: &Arc<A>,
+    git_repos: &[(Credential, GitRepository)],
+) -> Result<(Vec<PullRequest>, HashSet<u32>)>
+where
+    A: AzureDevOpsRepository + 'static,
+{
+    retrieve_per_git_repository(git_repos, |credential, gr| {
+        let azure_devops_repo = Arc::clone(azure_devops_repository);
+        async move {
+            match gr.git_provider {
+                GitProvider::AzureDevOps => {
+                    let mut res = azure_devops_repo
+                        .get_open_pull_requests_in_repository(&credential, &gr.context, &gr.name)
+                        .await?;
+                    res.iter_mut().for_each(|x| x.git_repository_id = gr.id);
+                    Ok(res)
+                }
+            }
+        }
+    })
+    .await
+}
+
+/// Retrieve the reviewers of the open pull requests of multiple git repositories
+/// concurrently, without retrieving the details of each pull request
+///
+/// # Arguments
+///
+/// * `azure_devops_repository` - The repository to get pull requests from Azure DevOps
+/// * `git_repos` - The git repositories to query and their credential
+///
+/// # Returns
+///
+/// * `Result<(Vec<PullRequestReviewers>, HashSet<u32>)>` - The retrieved reviewers
+///   per pull request and the git repositories whose pull requests were retrieved
+///
+/// # Errors
+///
+/// Any errors that might occur
+pub(crate) async fn retrieve_open_pull_request_reviewers<A>(
+    azure_devops_repository: &Arc<A>,
+    git_repos: &[(Credential, GitRepository)],
+) -> Result<(Vec<PullRequestReviewers>, HashSet<u32>)>
+where
+    A: AzureDevOpsRepository + 'static,
+{
+    retrieve_per_git_repository(git_repos, |credential, gr| {
+        let azure_devops_repo = Arc::clone(azure_devops_repository);
+        async move {
+            match gr.git_provider {
+                GitProvider::AzureDevOps => {
+                    let mut res = azure_devops_repo
+                        .get_open_pull_request_reviewers_in_repository(
+                            &credential,
+                            &gr.context,
+                            &gr.name,
+                        )
+                        .await?;
+                    res.iter_mut().for_each(|x| x.git_repository_id = gr.id);
+                    Ok(res)
+                }
+            }
+        }
+    })
+    .await
+}
+
+/// Helper to run a retrieval for multiple git repositories concurrently
+///
+/// # Arguments
+///
+/// * `git_repos` - The git repositories to query and their credential
+/// * `retrieve` - Creates the task to retrieve the items of a single git repository
+///
+/// # Returns
+///
+/// * `Result<(Vec<T>, HashSet<u32>)>` - The retrieved items and the git
+///   repositories whose items were retrieved
+///
+/// # Errors
+///
+/// Any errors that might occur
+async fn retrieve_per_git_repository<T, F, Fut>(
+    git_repos: &[(Credential, GitRepository)],
+    retrieve: F,
+) -> Result<(Vec<T>, HashSet<u32>)>
+where
+    T: Send + 'static,
+    F: Fn(Credential, GitRepository) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    // Build the join set to retrieve the items for all git repositories
+    let mut join_set = JoinSet::<Result<(u32, Vec<T>)>>::new();
+    for (credential, gr) in git_repos {
+        let git_repo_id = gr.id;
+        let task = retrieve(credential.clone(), gr.clone());
+        join_set.spawn(async move { Ok((git_repo_id, task.await?)) });
+    }
+    // Collect the results from all tasks
+    let mut items = vec![];
+    let mut queried_git_repo_ids = HashSet::<u32>::new();
+    while let Some(res) = join_set.join_next().await {
+        match res {
+            Ok(x) => {
+                let (git_repo_id, res) = x?;
+                queried_git_repo_ids.insert(git_repo_id);
+                items.extend(res);
+            }
+            Err(err) => {
+                log::info!(
+                    "Error getting Azure DevOps git repository: {:?}",
+                    err.to_string()
+                );
+            }
+        }
+    }
+    Ok((items, queried_git_repo_ids))
+}
@@ -0,0 +1,50 @@
+use crate::traits::PullRequestAnnotationRepository;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+/// Responsible for removing the notes and tags of pull requests that
+/// are closed for longer than the retention period
+pub struct CleanupPullRequestAnnotationsCommand<N>
+where
+    N: PullRequestAnnotationRepository,
+{
+    pull_request_annotation_repository: N,
+}
+
+impl<N> CleanupPullRequestAnnotationsCommand<N>
+where
+    N: PullRequestAnnotationRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_annotation_repository` - The repository to access notes and tags
+    pub fn new(pull_request_annotation_repository: N) -> Self {
+        Self {
+            pull_request_annotation_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `retention` - How long notes and tags are kept after a pull request was closed
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u64>` - The number of removed annotations
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, retention: &Duration) -> Result<u64> {
+        let closed_before = Utc::now() - *retention;
+        let removed = self
+            .pull_request_annotation_repository
+            .delete_closed_pull_request_annotations(&closed_before)
+            .await?;
+        Ok(removed)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::traits::PullRequestTriageRepository;
+use anyhow::Result;
+
+/// Responsible for waking up a snoozed pull request before any of its
+/// wake conditions is met
+pub struct ClearPullRequestSnoozeCommand<T>
+where
+    T: PullRequestTriageRepository,
+{
+    pull_request_triage_repository: T,
+}
+
+impl<T> ClearPullRequestSnoozeCommand<T>
+where
+    T: PullRequestTriageRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_triage_repository` - The repository to access the pin and snooze state
+    pub fn new(pull_request_triage_repository: T) -> Self {
+        Self {
+            pull_request_triage_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, git_repository_id: &u32, pull_request_id: &u32) -> Result<()> {
+        let Some(mut triage) = self
+            .pull_request_triage_repository
+            .get_pull_request_triage(git_repository_id, pull_request_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        if triage.is_pinned {
+            triage.snoozed_at = None;
+            triage.snoozed_until = None;
+            triage.snoozed_until_next_update = false;
+            self.pull_request_triage_repository
+                .save_pull_request_triage(triage)
+                .await?;
+        } else {
+            self.pull_request_triage_repository
+                .delete_pull_request_triage(git_repository_id, pull_request_id)
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -1,102 +1,263 @@
+use super::{
+    open_pull_request_source::{OpenPullRequestSource, OpenPullRequests},
+    track_pull_request_annotations::PullRequestAnnotationTracker,
+    track_pull_request_lifecycles::PullRequestLifecycleTracker,
+    track_pull_request_reviews::PullRequestReviewTracker,
+};
 use crate::{
-    dtos::PullRequestDto,
-    traits::{AzureDevOpsRepository, GitRepositoryRepository, SecretRepository},
+    dtos::{PullRequestDto, PullRequestFilterDto, PullRequestSortField, PullRequestSortingDto},
+    sla_rules::evaluate_sla_rules::SlaRuleEvaluator,
+    traits::{
+        AzureDevOpsRepository, GitRepositoryRepository, PullRequestAnnotationRepository,
+        PullRequestLifecycleRepository, PullRequestReviewRepository, PullRequestStateRepository,
+        PullRequestTriageRepository, RepositoryGroupRepository, SecretRepository,
+        SettingsRepository, SlaRuleRepository,
+    },
 };
 use anyhow::Result;
-use domain::{
-    enums::GitProvider,
-    models::{GitRepository, PullRequest},
-};
-use std::sync::Arc;
-use tokio::task::JoinSet;
+use chrono::Utc;
+use domain::models::{PullRequestActivity, PullRequestState, PullRequestTriage};
+use std::{cmp::Ordering, collections::HashMap};
 
 /// Responsible for getting all open pull requests across all
 /// imported git repositories
-pub struct GetOpenPullRequestsQuery<A, G, S>
+pub struct GetOpenPullRequestsQuery<A, G, S, M, P, T, R, N, L, K, E>
 where
     A: AzureDevOpsRepository,
     G: GitRepositoryRepository,
     S: SecretRepository,
+    M: RepositoryGroupRepository,
+    P: PullRequestStateRepository,
+    T: PullRequestTriageRepository,
+    R: PullRequestReviewRepository,
+    N: PullRequestAnnotationRepository,
+    L: PullRequestLifecycleRepository,
+    K: SlaRuleRepository,
+    E: SettingsRepository,
 {
-    azure_devops_repository: Arc<A>,
-    git_repository_repository: G,
-    secret_repository: S,
+    open_pull_request_source: OpenPullRequestSource<A, G, S, M>,
+    pull_request_state_repository: P,
+    pull_request_triage_repository: T,
+    pull_request_review_tracker: PullRequestReviewTracker<R>,
+    pull_request_annotation_tracker: PullRequestAnnotationTracker<N>,
+    pull_request_lifecycle_tracker: PullRequestLifecycleTracker<A, L>,
+    sla_rule_evaluator: SlaRuleEvaluator<K, E>,
 }
 
-impl<A, G, S> GetOpenPullRequestsQuery<A, G, S>
+impl<A, G, S, M, P, T, R, N, L, K, E> GetOpenPullRequestsQuery<A, G, S, M, P, T, R, N, L, K, E>
 where
     A: AzureDevOpsRepository + 'static,
     G: GitRepositoryRepository,
     S: SecretRepository,
+    M: RepositoryGroupRepository,
+    P: PullRequestStateRepository,
+    T: PullRequestTriageRepository,
+    R: PullRequestReviewRepository,
+    N: PullRequestAnnotationRepository,
+    L: PullRequestLifecycleRepository,
+    K: SlaRuleRepository,
+    E: SettingsRepository,
 {
     /// Create a new instance of the query
     ///
     /// # Arguments
     ///
-    /// * `azure_devops_repository` - The repository to get pull requests from Azure DevOps
-    /// * `git_repository_repository` - The repository to get imported git repositories
-    /// * `secret_repository` - The repositories to get secrets
+    /// * `open_pull_request_source` - The source to retrieve the open pull requests from
+    /// * `pull_request_state_repository` - The repository to get the read state of pull requests
+    /// * `pull_request_triage_repository` - The repository to get the pin and snooze state of pull requests
+    /// * `pull_request_review_tracker` - The tracker of the reviews of the current user
+    /// * `pull_request_annotation_tracker` - The tracker of the notes and tags of pull requests
+    /// * `pull_request_lifecycle_tracker` - The tracker of the lifecycles of pull requests
+    /// * `sla_rule_evaluator` - The evaluator of the SLA rules
     pub fn new(
-        azure_devops_repository: A,
-        git_repository_repository: G,
-        secret_repository: S,
+        open_pull_request_source: OpenPullRequestSource<A, G, S, M>,
+        pull_request_state_repository: P,
+        pull_request_triage_repository: T,
+        pull_request_review_tracker: PullRequestReviewTracker<R>,
+        pull_request_annotation_tracker: PullRequestAnnotationTracker<N>,
+        pull_request_lifecycle_tracker: PullRequestLifecycleTracker<A, L>,
+        sla_rule_evaluator: SlaRuleEvaluator<K, E>,
     ) -> Self {
         Self {
-            azure_devops_repository: Arc::new(azure_devops_repository),
-            git_repository_repository,
-            secret_repository,
+            open_pull_request_source,
+            pull_request_state_repository,
+            pull_request_triage_repository,
+            pull_request_review_tracker,
+            pull_request_annotation_tracker,
+            pull_request_lifecycle_tracker,
+            sla_rule_evaluator,
         }
     }
 
     /// Execute the query
     ///
+    /// # Arguments
+    ///
+    /// * `filter` - The criteria the returned pull requests must match
+    /// * `sorting` - The order of the returned pull requests
+    ///
     /// # Errors
     ///
     /// Any errors that might occur
-    pub async fn execute(&self) -> Result<Vec<PullRequestDto>> {
+    pub async fn execute(
+        &self,
+        filter: &PullRequestFilterDto,
+        sorting: &PullRequestSortingDto,
+    ) -> Result<Vec<PullRequestDto>> {
         // Get the relevant git repositories, marked as active
         let git_repos = self
-            .git_repository_repository
-            .get_all_git_repositories()
+            .open_pull_request_source
+            .get_git_repositories(filter.group_id)
+            .await?;
+        let OpenPullRequests {
+            pull_requests,
+            queried_git_repo_ids,
+            user_ids,
+        } = self
+            .open_pull_request_source
+            .get_open_pull_requests(&git_repos)
+            .await?;
+        let reviews = self
+            .pull_request_review_tracker
+            .track(&pull_requests, &user_ids)
             .await?;
-        let active_git_repos = git_repos
+        let states = self
+            .pull_request_state_repository
+            .get_pull_request_states()
+            .await?
             .into_iter()
-            .filter(|x| x.is_active)
-            .collect::<Vec<GitRepository>>();
-        // Get instances that can be shared across threads safely
-        // Build the join set to retrieve the pull requests for all git repositories
-        let mut join_set = JoinSet::<Result<Vec<PullRequest>>>::new();
-        for gr in active_git_repos {
-            let pat = self.secret_repository.get_secret(&gr.pat_secret_key)?;
-            let azure_devops_repo = Arc::clone(&self.azure_devops_repository);
-            join_set.spawn(async move {
-                match gr.git_provider {
-                    GitProvider::AzureDevOps => {
-                        let res = azure_devops_repo
-                            .get_open_pull_requests_in_repository(&pat, &gr.context, &gr.name)
-                            .await?;
-                        Ok(res)
-                    }
-                }
-            });
-        }
-        // Collect the results from all tasks
+            .map(|x| ((x.git_repository_id, x.pull_request_id), x))
+            .collect::<HashMap<(u32, u32), PullRequestState>>();
+        let triages = self
+            .pull_request_triage_repository
+            .get_pull_request_triages()
+            .await?
+            .into_iter()
+            .map(|x| ((x.git_repository_id, x.pull_request_id), x))
+            .collect::<HashMap<(u32, u32), PullRequestTriage>>();
+        let annotations = self
+            .pull_request_annotation_tracker
+            .track(&pull_requests, &queried_git_repo_ids)
+            .await?;
+        self.pull_request_lifecycle_tracker
+            .track(&pull_requests, &queried_git_repo_ids, &git_repos)
+            .await?;
+        let repository_groups = self
+            .open_pull_request_source
+            .get_repository_groups()
+            .await?;
+        let now = Utc::now();
+        let mut sla_violations = self
+            .sla_rule_evaluator
+            .evaluate(&pull_requests, &repository_groups, now)
+            .await?;
         let mut result = vec![];
-        while let Some(res) = join_set.join_next().await {
-            match res {
-                Ok(x) => {
-                    let dto: Vec<PullRequestDto> = x?.iter().map(|x| x.into()).collect();
-                    result.extend(dto);
+        for pr in pull_requests
+            .iter()
+            .filter(|x| !filter.without_work_items_only || x.work_items.is_empty())
+        {
+            let mut dto: PullRequestDto = pr.into();
+            dto.has_new_changes_since_my_review = match (
+                reviews.get(&(pr.git_repository_id, pr.id)),
+                pr.latest_iteration_id,
+            ) {
+                (Some(review), Some(latest_iteration_id)) => {
+                    latest_iteration_id > review.iteration_id
                 }
-                Err(err) => {
-                    log::info!(
-                        "Error getting Azure DevOps git repository: {:?}",
-                        err.to_string()
-                    );
+                _ => false,
+            };
+            // Pull requests that were never seen are unread with all comments being new,
+            // while the activity of the current user never marks them as unread
+            if let Some(state) = states.get(&(pr.git_repository_id, pr.id)) {
+                let user_id = user_ids.get(&pr.git_repository_id);
+                let is_own = |x: &&PullRequestActivity| user_id.is_some_and(|y| x.author_id == *y);
+                let own_new_comment_count = pr
+                    .activities
+                    .iter()
+                    .filter(|x| x.is_new_comment && x.date > state.last_seen_at)
+                    .filter(is_own)
+                    .count();
+                dto.new_comment_count = pr
+                    .number_of_comments
+                    .saturating_sub(state.last_seen_comment_count + own_new_comment_count);
+                dto.unread = pr
+                    .activities
+                    .iter()
+                    .filter(|x| !is_own(x))
+                    .any(|x| x.date > state.last_seen_at)
+                    || dto.new_comment_count > 0;
+            }
+            if let Some(triage) = triages.get(&(pr.git_repository_id, pr.id)) {
+                dto.is_pinned = triage.is_pinned;
+                dto.is_snoozed = triage.is_snoozed(now, pr.last_updated_date);
+                if dto.is_snoozed {
+                    dto.snoozed_until = triage.snoozed_until;
+                    dto.snoozed_until_next_update = triage.snoozed_until_next_update;
                 }
             }
+            if let Some(annotation) = annotations.get(&(pr.git_repository_id, pr.id)) {
+                dto.note = annotation.note.clone();
+                dto.tags = annotation.tags.clone();
+            }
+            dto.sla_violations = sla_violations
+                .remove(&(pr.git_repository_id, pr.id))
+                .unwrap_or_default();
+            if dto.is_snoozed && !filter.include_snoozed {
+                continue;
+            }
+            if filter
+                .tag
+                .as_ref()
+                .is_some_and(|tag| !dto.tags.contains(tag))
+            {
+                continue;
+            }
+            result.push(dto);
         }
-        result.sort_by(|a, b| a.creation_date.cmp(&b.creation_date));
+        sort_pull_requests(&mut result, sorting);
+        // Pinned pull requests are listed first, keeping the requested order among them
+        result.sort_by_key(|x| !x.is_pinned);
         Ok(result)
     }
 }
+
+/// Sort the pull requests in place
+///
+/// Pull requests are always ordered by their creation date first, so that pull
+/// requests with equal values for the sort field keep a deterministic order.
+/// Pull requests without a value for the sort field (e.g. unknown change
+/// statistics) are placed last, regardless of the direction.
+///
+/// # Arguments
+///
+/// * `pull_requests` - The pull requests to sort
+/// * `sorting` - The order to apply
+fn sort_pull_requests(pull_requests: &mut [PullRequestDto], sorting: &PullRequestSortingDto) {
+    pull_requests.sort_by_key(|x| x.creation_date);
+    let sort_value = |x: &PullRequestDto| -> Option<usize> {
+        let statistics = x.change_statistics.as_ref()?;
+        match sorting.field {
+            PullRequestSortField::CreationDate => None,
+            PullRequestSortField::FilesChanged => Some(statistics.files_changed),
+            PullRequestSortField::LinesAdded => statistics.lines_added,
+            PullRequestSortField::LinesDeleted => statistics.lines_deleted,
+            PullRequestSortField::LinesChanged => {
+                Some(statistics.lines_added? + statistics.lines_deleted?)
+            }
+        }
+    };
+    match sorting.field {
+        PullRequestSortField::CreationDate => {
+            if sorting.descending {
+                pull_requests.reverse();
+            }
+        }
+        _ => pull_requests.sort_by(|a, b| match (sort_value(a), sort_value(b)) {
+            (Some(a), Some(b)) if sorting.descending => b.cmp(&a),
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }),
+    }
+}
@@ -0,0 +1,138 @@
+use super::{
+    get_active_git_repositories, resolve_credentials, retrieve_open_pull_request_reviewers,
+};
+use crate::{
+    dtos::{ReviewerWorkloadDto, WaitingPullRequestDto},
+    traits::{
+        AzureDevOpsRepository, GitRepositoryRepository, RepositoryGroupRepository, SecretRepository,
+    },
+};
+use anyhow::Result;
+use chrono::Utc;
+use domain::enums::ReviewerVote;
+use std::{collections::HashMap, sync::Arc};
+
+/// Responsible for getting how many open pull requests are assigned to
+/// each reviewer and how many of them still wait for their vote
+pub struct GetReviewerWorkloadQuery<A, G, S, M>
+where
+    A: AzureDevOpsRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+    M: RepositoryGroupRepository,
+{
+    azure_devops_repository: Arc<A>,
+    git_repository_repository: G,
+    secret_repository: S,
+    repository_group_repository: M,
+}
+
+impl<A, G, S, M> GetReviewerWorkloadQuery<A, G, S, M>
+where
+    A: AzureDevOpsRepository + 'static,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `azure_devops_repository` - The repository to get pull requests from Azure DevOps
+    /// * `git_repository_repository` - The repository to get imported git repositories
+    /// * `secret_repository` - The repositories to get secrets
+    /// * `repository_group_repository` - The repository to get the repository groups
+    pub fn new(
+        azure_devops_repository: A,
+        git_repository_repository: G,
+        secret_repository: S,
+        repository_group_repository: M,
+    ) -> Self {
+        Self {
+            azure_devops_repository: Arc::new(azure_devops_repository),
+            git_repository_repository,
+            secret_repository,
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// A vote is pending as long as the reviewer did not vote at all. The age
+    /// of a waiting pull request is measured from its creation, as the provider
+    /// does not expose when a reviewer was added.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the repository group to limit the pull requests to
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<ReviewerWorkloadDto>>` - The workload per reviewer, the ones
+    ///   with the most pending votes first
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, group_id: Option<u32>) -> Result<Vec<ReviewerWorkloadDto>> {
+        let active_git_repos = get_active_git_repositories(
+            &self.git_repository_repository,
+            &self.repository_group_repository,
+            group_id,
+        )
+        .await?;
+        let git_repos = resolve_credentials(&self.secret_repository, active_git_repos).await?;
+        // Only the reviewers are needed, so the details of each pull request
+        // are not retrieved
+        let (pull_requests, _) =
+            retrieve_open_pull_request_reviewers(&self.azure_devops_repository, &git_repos).await?;
+        let now = Utc::now();
+        let mut workloads = HashMap::<String, ReviewerWorkloadDto>::new();
+        for pr in &pull_requests {
+            for reviewer in &pr.reviewers {
+                let workload = workloads.entry(reviewer.id.to_string()).or_insert_with(|| {
+                    ReviewerWorkloadDto {
+                        reviewer_id: reviewer.id.to_string(),
+                        display_name: reviewer.display_name.to_string(),
+                        assigned_count: 0,
+                        pending_vote_count: 0,
+                        required_pending_vote_count: 0,
+                        oldest_waiting_pull_request: None,
+                    }
+                });
+                workload.assigned_count += 1;
+                if reviewer.vote != ReviewerVote::NoVote {
+                    continue;
+                }
+                workload.pending_vote_count += 1;
+                if reviewer.is_required {
+                    workload.required_pending_vote_count += 1;
+                }
+                let age_hours = ((now - pr.creation_date).num_seconds().max(0) as f64) / 3600.0;
+                if workload
+                    .oldest_waiting_pull_request
+                    .as_ref()
+                    .is_none_or(|x| age_hours > x.age_hours)
+                {
+                    workload.oldest_waiting_pull_request = Some(WaitingPullRequestDto {
+                        git_repository_id: pr.git_repository_id,
+                        pull_request_id: pr.id,
+                        title: pr.title.to_string(),
+                        link: pr.link.to_string(),
+                        age_hours,
+                    });
+                }
+            }
+        }
+        let mut result = workloads
+            .into_values()
+            .collect::<Vec<ReviewerWorkloadDto>>();
+        result.sort_by(|a, b| {
+            b.pending_vote_count
+                .cmp(&a.pending_vote_count)
+                .then_with(|| b.assigned_count.cmp(&a.assigned_count))
+                .then_with(|| a.display_name.cmp(&b.display_name))
+        });
+        Ok(result)
+    }
+}
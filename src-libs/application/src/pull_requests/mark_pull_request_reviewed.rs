@@ -0,0 +1,75 @@
+use crate::traits::{GitRepositoryRepository, PullRequestReviewRepository};
+use anyhow::Result;
+use chrono::Utc;
+use domain::{enums::ReviewerVote, models::PullRequestReview};
+
+/// Responsible for remembering that the current user reviewed a specific
+/// iteration of a pull request, without necessarily voting on it
+pub struct MarkPullRequestReviewedCommand<G, R>
+where
+    G: GitRepositoryRepository,
+    R: PullRequestReviewRepository,
+{
+    git_repository_repository: G,
+    pull_request_review_repository: R,
+}
+
+impl<G, R> MarkPullRequestReviewedCommand<G, R>
+where
+    G: GitRepositoryRepository,
+    R: PullRequestReviewRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pull_request_review_repository` - The repository to track the reviews of the current user
+    pub fn new(git_repository_repository: G, pull_request_review_repository: R) -> Self {
+        Self {
+            git_repository_repository,
+            pull_request_review_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `iteration_id` - The identifier of the reviewed iteration
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+        iteration_id: &u32,
+    ) -> Result<()> {
+        let git_repo = self
+            .git_repository_repository
+            .get_git_repository_by_id(git_repository_id)
+            .await?;
+        // Keep the last known vote, so that an unchanged vote is not mistaken
+        // for a new review during the next refresh
+        let vote = self
+            .pull_request_review_repository
+            .get_pull_request_review(&git_repo.id, pull_request_id)
+            .await?
+            .map(|x| x.vote)
+            .unwrap_or(ReviewerVote::NoVote);
+        self.pull_request_review_repository
+            .save_pull_request_review(PullRequestReview {
+                git_repository_id: git_repo.id,
+                pull_request_id: *pull_request_id,
+                iteration_id: *iteration_id,
+                vote,
+                reviewed_at: Utc::now(),
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::traits::{GitRepositoryRepository, PullRequestStateRepository};
+use anyhow::Result;
+use chrono::Utc;
+use domain::models::PullRequestState;
+
+/// Responsible for marking a single pull request as seen (read) by the user
+pub struct MarkPullRequestSeenCommand<G, P>
+where
+    G: GitRepositoryRepository,
+    P: PullRequestStateRepository,
+{
+    git_repository_repository: G,
+    pull_request_state_repository: P,
+}
+
+impl<G, P> MarkPullRequestSeenCommand<G, P>
+where
+    G: GitRepositoryRepository,
+    P: PullRequestStateRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pull_request_state_repository` - The repository to access the read state of pull requests
+    pub fn new(git_repository_repository: G, pull_request_state_repository: P) -> Self {
+        Self {
+            git_repository_repository,
+            pull_request_state_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `comment_count` - The total number of comments the user has seen
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+        comment_count: &usize,
+    ) -> Result<()> {
+        let git_repo = self
+            .git_repository_repository
+            .get_git_repository_by_id(git_repository_id)
+            .await?;
+        self.pull_request_state_repository
+            .save_pull_request_state(PullRequestState {
+                git_provider: git_repo.git_provider,
+                git_repository_id: git_repo.id,
+                pull_request_id: *pull_request_id,
+                last_seen_at: Utc::now(),
+                last_seen_comment_count: *comment_count,
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::traits::PullRequestStateRepository;
+use anyhow::Result;
+
+/// Responsible for marking a single pull request as unread again
+pub struct MarkPullRequestUnreadCommand<P>
+where
+    P: PullRequestStateRepository,
+{
+    pull_request_state_repository: P,
+}
+
+impl<P> MarkPullRequestUnreadCommand<P>
+where
+    P: PullRequestStateRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_state_repository` - The repository to access the read state of pull requests
+    pub fn new(pull_request_state_repository: P) -> Self {
+        Self {
+            pull_request_state_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, git_repository_id: &u32, pull_request_id: &u32) -> Result<()> {
+        // Without a tracked state a pull request is considered as never seen
+        self.pull_request_state_repository
+            .delete_pull_request_state(git_repository_id, pull_request_id)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use super::{get_active_git_repositories, resolve_credentials, retrieve_open_pull_requests};
+use crate::traits::{
+    AzureDevOpsRepository, GitRepositoryRepository, RepositoryGroupRepository, SecretRepository,
+};
+use anyhow::Result;
+use domain::models::{Credential, GitRepository, PullRequest, RepositoryGroup};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio::task::JoinSet;
+
+/// The open pull requests retrieved from the git providers
+pub(crate) struct OpenPullRequests {
+    /// The retrieved pull requests
+    pub pull_requests: Vec<PullRequest>,
+    /// The git repositories whose open pull requests were retrieved
+    pub queried_git_repo_ids: HashSet<u32>,
+    /// The authenticated user per git repository
+    pub user_ids: HashMap<u32, String>,
+}
+
+/// Responsible for retrieving the open pull requests of the imported
+/// git repositories from their git providers
+pub struct OpenPullRequestSource<A, G, S, M>
+where
+    A: AzureDevOpsRepository,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+    M: RepositoryGroupRepository,
+{
+    azure_devops_repository: Arc<A>,
+    git_repository_repository: G,
+    secret_repository: S,
+    repository_group_repository: M,
+}
+
+impl<A, G, S, M> OpenPullRequestSource<A, G, S, M>
+where
+    A: AzureDevOpsRepository + 'static,
+    G: GitRepositoryRepository,
+    S: SecretRepository,
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the source
+    ///
+    /// # Arguments
+    ///
+    /// * `azure_devops_repository` - The repository to get pull requests from Azure DevOps
+    /// * `git_repository_repository` - The repository to get imported git repositories
+    /// * `secret_repository` - The repositories to get secrets
+    /// * `repository_group_repository` - The repository to get the repository groups
+    pub fn new(
+        azure_devops_repository: A,
+        git_repository_repository: G,
+        secret_repository: S,
+        repository_group_repository: M,
+    ) -> Self {
+        Self {
+            azure_devops_repository: Arc::new(azure_devops_repository),
+            git_repository_repository,
+            secret_repository,
+            repository_group_repository,
+        }
+    }
+
+    /// Get the active git repositories to query and their credential
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The unique identifier of the repository group to limit the git repositories to
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<(Credential, GitRepository)>>` - The git repositories with their credential
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when a PAT is missing
+    pub(crate) async fn get_git_repositories(
+        &self,
+        group_id: Option<u32>,
+    ) -> Result<Vec<(Credential, GitRepository)>> {
+        let active_git_repos = get_active_git_repositories(
+            &self.git_repository_repository,
+            &self.repository_group_repository,
+            group_id,
+        )
+        .await?;
+        resolve_credentials(&self.secret_repository, active_git_repos).await
+    }
+
+    /// Get all repository groups
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<RepositoryGroup>>` - The repository groups
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub(crate) async fn get_repository_groups(&self) -> Result<Vec<RepositoryGroup>> {
+        self.repository_group_repository
+            .get_all_repository_groups()
+            .await
+    }
+
+    /// Retrieve the open pull requests of the git repositories and the
+    /// authenticated user of each of them concurrently
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repos` - The git repositories to query and their credential
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OpenPullRequests>` - The retrieved pull requests
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub(crate) async fn get_open_pull_requests(
+        &self,
+        git_repos: &[(Credential, GitRepository)],
+    ) -> Result<OpenPullRequests> {
+        // The authenticated user is the same for all git repositories of an
+        // organization that share the same credential
+        let mut git_repo_ids_by_organization =
+            HashMap::<(String, String), (Credential, Vec<u32>)>::new();
+        for (credential, gr) in git_repos {
+            let organization_name = gr.context.split('/').next().unwrap_or_default();
+            git_repo_ids_by_organization
+                .entry((organization_name.to_string(), gr.credential_key.to_string()))
+                .or_insert_with(|| (credential.clone(), vec![]))
+                .1
+                .push(gr.id);
+        }
+        let mut user_id_join_set = JoinSet::<(Vec<u32>, Result<String>)>::new();
+        for ((organization_name, _), (credential, git_repo_ids)) in git_repo_ids_by_organization {
+            let azure_devops_repo = Arc::clone(&self.azure_devops_repository);
+            user_id_join_set.spawn(async move {
+                let user_id = azure_devops_repo
+                    .get_authenticated_user_id(&credential, &organization_name)
+                    .await;
+                (git_repo_ids, user_id)
+            });
+        }
+        let (pull_requests, queried_git_repo_ids) =
+            retrieve_open_pull_requests(&self.azure_devops_repository, git_repos).await?;
+        let mut user_ids = HashMap::<u32, String>::new();
+        while let Some(res) = user_id_join_set.join_next().await {
+            match res {
+                Ok((git_repo_ids, Ok(user_id))) => {
+                    for id in git_repo_ids {
+                        user_ids.insert(id, user_id.to_string());
+                    }
+                }
+                Ok((_, Err(err))) => {
+                    log::warn!("Error getting authenticated user: {:?}", err.to_string());
+                }
+                Err(err) => {
+                    log::info!("Error getting authenticated user: {:?}", err.to_string());
+                }
+            }
+        }
+        Ok(OpenPullRequests {
+            pull_requests,
+            queried_git_repo_ids,
+            user_ids,
+        })
+    }
+}
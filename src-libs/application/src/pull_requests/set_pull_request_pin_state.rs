@@ -0,0 +1,81 @@
+use crate::traits::{GitRepositoryRepository, PullRequestTriageRepository};
+use anyhow::Result;
+use domain::models::PullRequestTriage;
+
+/// Responsible for pinning a single pull request to the top of the list
+/// or unpinning it again
+pub struct SetPullRequestPinStateCommand<G, T>
+where
+    G: GitRepositoryRepository,
+    T: PullRequestTriageRepository,
+{
+    git_repository_repository: G,
+    pull_request_triage_repository: T,
+}
+
+impl<G, T> SetPullRequestPinStateCommand<G, T>
+where
+    G: GitRepositoryRepository,
+    T: PullRequestTriageRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pull_request_triage_repository` - The repository to access the pin and snooze state
+    pub fn new(git_repository_repository: G, pull_request_triage_repository: T) -> Self {
+        Self {
+            git_repository_repository,
+            pull_request_triage_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `is_pinned` - Whether the pull request should be pinned
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+        is_pinned: bool,
+    ) -> Result<()> {
+        let git_repo = self
+            .git_repository_repository
+            .get_git_repository_by_id(git_repository_id)
+            .await?;
+        let mut triage = self
+            .pull_request_triage_repository
+            .get_pull_request_triage(git_repository_id, pull_request_id)
+            .await?
+            .unwrap_or(PullRequestTriage {
+                git_provider: git_repo.git_provider,
+                git_repository_id: git_repo.id,
+                pull_request_id: *pull_request_id,
+                is_pinned: false,
+                snoozed_at: None,
+                snoozed_until: None,
+                snoozed_until_next_update: false,
+            });
+        triage.is_pinned = is_pinned;
+        // Only keep the state while it actually changes the behavior
+        if !triage.is_pinned && triage.snoozed_at.is_none() {
+            self.pull_request_triage_repository
+                .delete_pull_request_triage(git_repository_id, pull_request_id)
+                .await?;
+        } else {
+            self.pull_request_triage_repository
+                .save_pull_request_triage(triage)
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    dtos::PullRequestSnoozeDto,
+    traits::{GitRepositoryRepository, PullRequestTriageRepository},
+};
+use anyhow::Result;
+use chrono::Utc;
+use domain::models::PullRequestTriage;
+
+/// Responsible for snoozing a single pull request, so that it is hidden
+/// until a date or until its next update
+pub struct SnoozePullRequestCommand<G, T>
+where
+    G: GitRepositoryRepository,
+    T: PullRequestTriageRepository,
+{
+    git_repository_repository: G,
+    pull_request_triage_repository: T,
+}
+
+impl<G, T> SnoozePullRequestCommand<G, T>
+where
+    G: GitRepositoryRepository,
+    T: PullRequestTriageRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pull_request_triage_repository` - The repository to access the pin and snooze state
+    pub fn new(git_repository_repository: G, pull_request_triage_repository: T) -> Self {
+        Self {
+            git_repository_repository,
+            pull_request_triage_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `snooze` - The conditions to wake the pull request up again
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when no wake condition is given
+    pub async fn execute(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+        snooze: &PullRequestSnoozeDto,
+    ) -> Result<()> {
+        let now = Utc::now();
+        if snooze.until.is_none() && !snooze.until_next_update {
+            return Err(anyhow::anyhow!(
+                "A snoozed pull request needs at least one condition to wake up"
+            ));
+        }
+        if snooze.until.is_some_and(|x| x <= now) {
+            return Err(anyhow::anyhow!("The snooze date must be in the future"));
+        }
+        let git_repo = self
+            .git_repository_repository
+            .get_git_repository_by_id(git_repository_id)
+            .await?;
+        let is_pinned = self
+            .pull_request_triage_repository
+            .get_pull_request_triage(git_repository_id, pull_request_id)
+            .await?
+            .is_some_and(|x| x.is_pinned);
+        self.pull_request_triage_repository
+            .save_pull_request_triage(PullRequestTriage {
+                git_provider: git_repo.git_provider,
+                git_repository_id: git_repo.id,
+                pull_request_id: *pull_request_id,
+                is_pinned,
+                snoozed_at: Some(now),
+                snoozed_until: snooze.until,
+                snoozed_until_next_update: snooze.until_next_update,
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::traits::PullRequestAnnotationRepository;
+use anyhow::Result;
+use chrono::Utc;
+use domain::models::{PullRequest, PullRequestAnnotation};
+use std::collections::{HashMap, HashSet};
+
+/// Responsible for tracking which annotated pull requests are still open
+pub struct PullRequestAnnotationTracker<N>
+where
+    N: PullRequestAnnotationRepository,
+{
+    pull_request_annotation_repository: N,
+}
+
+impl<N> PullRequestAnnotationTracker<N>
+where
+    N: PullRequestAnnotationRepository,
+{
+    /// Create a new instance of the tracker
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_annotation_repository` - The repository to get the notes and tags of pull requests
+    pub fn new(pull_request_annotation_repository: N) -> Self {
+        Self {
+            pull_request_annotation_repository,
+        }
+    }
+
+    /// Remember which annotated pull requests are no longer open, so that their
+    /// notes and tags can be cleaned up after the retention period
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_requests` - The retrieved open pull requests
+    /// * `queried_git_repo_ids` - The git repositories whose open pull requests were retrieved
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HashMap<(u32, u32), PullRequestAnnotation>>` - The annotations
+    ///   by git repository and pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub(crate) async fn track(
+        &self,
+        pull_requests: &[PullRequest],
+        queried_git_repo_ids: &HashSet<u32>,
+    ) -> Result<HashMap<(u32, u32), PullRequestAnnotation>> {
+        let open_pull_requests = pull_requests
+            .iter()
+            .map(|x| (x.git_repository_id, x.id))
+            .collect::<HashSet<(u32, u32)>>();
+        let mut result = HashMap::new();
+        for mut annotation in self
+            .pull_request_annotation_repository
+            .get_pull_request_annotations()
+            .await?
+        {
+            let key = (annotation.git_repository_id, annotation.pull_request_id);
+            // Pull requests of inactive or failed git repositories are unknown and
+            // therefore not considered as closed
+            if queried_git_repo_ids.contains(&annotation.git_repository_id) {
+                let is_open = open_pull_requests.contains(&key);
+                if is_open == annotation.closed_at.is_some() {
+                    annotation.closed_at = match is_open {
+                        true => None,
+                        false => Some(Utc::now()),
+                    };
+                    self.pull_request_annotation_repository
+                        .save_pull_request_annotation(annotation.clone())
+                        .await?;
+                }
+            }
+            result.insert(key, annotation);
+        }
+        Ok(result)
+    }
+}
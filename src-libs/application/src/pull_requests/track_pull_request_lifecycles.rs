@@ -0,0 +1,245 @@
+use crate::traits::{AzureDevOpsRepository, PullRequestLifecycleRepository};
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use domain::{
+    enums::{GitProvider, PullRequestStatus, ReviewerVote},
+    models::{Credential, GitRepository, PullRequest, PullRequestLifecycle},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio::task::JoinSet;
+
+/// The first delay before the status of a pull request is looked up again,
+/// after the lookup failed. It doubles with every further failure.
+const STATUS_LOOKUP_BACKOFF_MINUTES: i64 = 15;
+/// The longest delay before the status of a pull request is looked up again
+const STATUS_LOOKUP_MAX_BACKOFF_MINUTES: i64 = 24 * 60;
+
+/// Responsible for recording the lifecycles of pull requests, to measure
+/// the review process over time
+pub struct PullRequestLifecycleTracker<A, L>
+where
+    A: AzureDevOpsRepository,
+    L: PullRequestLifecycleRepository,
+{
+    azure_devops_repository: Arc<A>,
+    pull_request_lifecycle_repository: L,
+}
+
+impl<A, L> PullRequestLifecycleTracker<A, L>
+where
+    A: AzureDevOpsRepository + 'static,
+    L: PullRequestLifecycleRepository,
+{
+    /// Create a new instance of the tracker
+    ///
+    /// # Arguments
+    ///
+    /// * `azure_devops_repository` - The repository to look up closed pull requests in Azure DevOps
+    /// * `pull_request_lifecycle_repository` - The repository to record the lifecycles of pull requests
+    pub fn new(azure_devops_repository: A, pull_request_lifecycle_repository: L) -> Self {
+        Self {
+            azure_devops_repository: Arc::new(azure_devops_repository),
+            pull_request_lifecycle_repository,
+        }
+    }
+
+    /// Record the milestones of the pull requests observed since the last
+    /// refresh
+    ///
+    /// The first vote is dated by the earliest time a reviewer voted, or by the
+    /// refresh that observes it if the provider does not expose when a vote was
+    /// cast. Pull requests that are no longer
+    /// open are looked up concurrently, to find out whether they were completed
+    /// or abandoned. Failed lookups are retried on later refreshes, with an
+    /// increasing delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_requests` - The retrieved open pull requests
+    /// * `queried_git_repo_ids` - The git repositories whose open pull requests were retrieved
+    /// * `git_repos` - The queried git repositories and their credential
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub(crate) async fn track(
+        &self,
+        pull_requests: &[PullRequest],
+        queried_git_repo_ids: &HashSet<u32>,
+        git_repos: &[(Credential, GitRepository)],
+    ) -> Result<()> {
+        // Lifecycles of removed git repositories are kept, but no longer tracked
+        let lifecycles = self
+            .pull_request_lifecycle_repository
+            .get_pull_request_lifecycles(
+                &queried_git_repo_ids.iter().copied().collect::<Vec<u32>>(),
+            )
+            .await?
+            .into_iter()
+            .filter_map(|x| Some(((x.git_repository_id?, x.pull_request_id), x)))
+            .collect::<HashMap<(u32, u32), PullRequestLifecycle>>();
+        let now = Utc::now();
+        let mut changed_lifecycles = vec![];
+        for pr in pull_requests {
+            let voters = pr
+                .reviewers
+                .iter()
+                .filter(|x| x.vote != ReviewerVote::NoVote)
+                .collect::<Vec<_>>();
+            let first_vote_at = match voters.is_empty() {
+                true => None,
+                false => Some(
+                    voters
+                        .iter()
+                        .filter_map(|x| x.voted_at)
+                        .min()
+                        .unwrap_or(now),
+                ),
+            };
+            let Some(lifecycle) = lifecycles.get(&(pr.git_repository_id, pr.id)) else {
+                changed_lifecycles.push(PullRequestLifecycle {
+                    git_provider: pr.git_provider.clone(),
+                    git_repository_id: Some(pr.git_repository_id),
+                    git_repository_name: pr.repository_name.to_string(),
+                    pull_request_id: pr.id,
+                    creator_id: pr.creator_id.to_string(),
+                    creator_name: pr.creator_name.to_string(),
+                    status: PullRequestStatus::Active,
+                    created_at: pr.creation_date,
+                    first_vote_at,
+                    first_comment_at: pr.first_comment_date,
+                    closed_at: None,
+                    status_lookup_failure_count: 0,
+                    status_lookup_failed_at: None,
+                });
+                continue;
+            };
+            let mut lifecycle = lifecycle.clone();
+            let mut is_changed = false;
+            if lifecycle.first_vote_at.is_none() && first_vote_at.is_some() {
+                lifecycle.first_vote_at = first_vote_at;
+                is_changed = true;
+            }
+            if lifecycle.first_comment_at.is_none() && pr.first_comment_date.is_some() {
+                lifecycle.first_comment_at = pr.first_comment_date;
+                is_changed = true;
+            }
+            // Keep the names of renamed git repositories and users up to date
+            if lifecycle.git_repository_name != pr.repository_name
+                || lifecycle.creator_id != pr.creator_id
+                || lifecycle.creator_name != pr.creator_name
+            {
+                lifecycle.git_repository_name = pr.repository_name.to_string();
+                lifecycle.creator_id = pr.creator_id.to_string();
+                lifecycle.creator_name = pr.creator_name.to_string();
+                is_changed = true;
+            }
+            // Abandoned pull requests can be reactivated
+            if lifecycle.status != PullRequestStatus::Active {
+                lifecycle.status = PullRequestStatus::Active;
+                lifecycle.closed_at = None;
+                is_changed = true;
+            }
+            if is_changed {
+                changed_lifecycles.push(lifecycle);
+            }
+        }
+        let open_pull_requests = pull_requests
+            .iter()
+            .map(|x| (x.git_repository_id, x.id))
+            .collect::<HashSet<(u32, u32)>>();
+        // Pull requests of inactive or failed git repositories are unknown and
+        // therefore not considered as closed
+        let mut join_set = JoinSet::new();
+        for (key, lifecycle) in lifecycles.into_iter().filter(|(key, x)| {
+            x.status == PullRequestStatus::Active
+                && queried_git_repo_ids.contains(&key.0)
+                && !open_pull_requests.contains(key)
+                && is_status_lookup_due(x, &now)
+        }) {
+            let Some((credential, gr)) = git_repos.iter().find(|(_, gr)| gr.id == key.0) else {
+                continue;
+            };
+            let azure_devops_repo = Arc::clone(&self.azure_devops_repository);
+            let (credential, gr) = (credential.clone(), gr.clone());
+            join_set.spawn(async move {
+                let status = match gr.git_provider {
+                    GitProvider::AzureDevOps => {
+                        azure_devops_repo
+                            .get_pull_request_status(
+                                &credential,
+                                &gr.context,
+                                &gr.name,
+                                &lifecycle.pull_request_id,
+                            )
+                            .await
+                    }
+                };
+                (lifecycle, status)
+            });
+        }
+        while let Some(res) = join_set.join_next().await {
+            let (lifecycle, status) = match res {
+                Ok(x) => x,
+                Err(err) => {
+                    log::info!(
+                        "Error getting the status of a pull request: {:?}",
+                        err.to_string()
+                    );
+                    continue;
+                }
+            };
+            match status {
+                Ok((PullRequestStatus::Active, _)) => {}
+                Ok((status, closed_at)) => changed_lifecycles.push(PullRequestLifecycle {
+                    status,
+                    closed_at: Some(closed_at.unwrap_or(now)),
+                    status_lookup_failure_count: 0,
+                    status_lookup_failed_at: None,
+                    ..lifecycle
+                }),
+                Err(err) => {
+                    log::warn!(
+                        "Error getting the status of pull request `{}`: {:?}",
+                        lifecycle.pull_request_id,
+                        err.to_string()
+                    );
+                    changed_lifecycles.push(PullRequestLifecycle {
+                        status_lookup_failure_count: lifecycle.status_lookup_failure_count + 1,
+                        status_lookup_failed_at: Some(now),
+                        ..lifecycle
+                    });
+                }
+            }
+        }
+        if !changed_lifecycles.is_empty() {
+            self.pull_request_lifecycle_repository
+                .save_pull_request_lifecycles(changed_lifecycles)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper to check whether the status of a pull request that is no longer
+/// open should be looked up, backing off after failed lookups
+///
+/// # Arguments
+///
+/// * `lifecycle` - The lifecycle of the pull request
+/// * `now` - The current date
+fn is_status_lookup_due(lifecycle: &PullRequestLifecycle, now: &DateTime<Utc>) -> bool {
+    let Some(failed_at) = lifecycle.status_lookup_failed_at else {
+        return true;
+    };
+    let exponent = lifecycle
+        .status_lookup_failure_count
+        .saturating_sub(1)
+        .min(16);
+    let backoff_minutes =
+        (STATUS_LOOKUP_BACKOFF_MINUTES << exponent).min(STATUS_LOOKUP_MAX_BACKOFF_MINUTES);
+    *now >= failed_at + Duration::minutes(backoff_minutes)
+}
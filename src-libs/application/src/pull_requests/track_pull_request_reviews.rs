@@ -0,0 +1,124 @@
+use crate::traits::PullRequestReviewRepository;
+use anyhow::Result;
+use chrono::Utc;
+use domain::{
+    enums::ReviewerVote,
+    models::{PullRequest, PullRequestReview},
+};
+use std::collections::HashMap;
+
+/// Responsible for tracking the reviews of the current user, to know
+/// which pull requests have new changes since they were reviewed
+pub struct PullRequestReviewTracker<R>
+where
+    R: PullRequestReviewRepository,
+{
+    pull_request_review_repository: R,
+}
+
+impl<R> PullRequestReviewTracker<R>
+where
+    R: PullRequestReviewRepository,
+{
+    /// Create a new instance of the tracker
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_review_repository` - The repository to track the reviews of the current user
+    pub fn new(pull_request_review_repository: R) -> Self {
+        Self {
+            pull_request_review_repository,
+        }
+    }
+
+    /// Remember the iteration of every pull request the current user voted on
+    /// since the last refresh
+    ///
+    /// The reviewed iteration is the latest one when the vote was cast, so pushes
+    /// between the vote and the refresh are recognized as new changes.
+    ///
+    /// Votes that are reset by a push are remembered as well, so voting again
+    /// with the same vote is recognized as a new review.
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_requests` - The retrieved open pull requests
+    /// * `user_ids` - The authenticated user per imported git repository
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HashMap<(u32, u32), PullRequestReview>>` - The tracked reviews
+    ///   by git repository and pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub(crate) async fn track(
+        &self,
+        pull_requests: &[PullRequest],
+        user_ids: &HashMap<u32, String>,
+    ) -> Result<HashMap<(u32, u32), PullRequestReview>> {
+        let mut reviews = self
+            .pull_request_review_repository
+            .get_pull_request_reviews()
+            .await?
+            .into_iter()
+            .map(|x| ((x.git_repository_id, x.pull_request_id), x))
+            .collect::<HashMap<(u32, u32), PullRequestReview>>();
+        for pr in pull_requests {
+            let (Some(user_id), Some(latest_iteration_id)) =
+                (user_ids.get(&pr.git_repository_id), pr.latest_iteration_id)
+            else {
+                continue;
+            };
+            let Some(reviewer) = pr.reviewers.iter().find(|x| x.id == *user_id) else {
+                continue;
+            };
+            let key = (pr.git_repository_id, pr.id);
+            let tracked = reviews.get(&key);
+            let review = match reviewer.vote {
+                ReviewerVote::NoVote => match tracked.filter(|x| x.vote != ReviewerVote::NoVote) {
+                    // The reviewed iteration stays the same, only the reset is remembered
+                    Some(tracked) => PullRequestReview {
+                        vote: ReviewerVote::NoVote,
+                        ..tracked.clone()
+                    },
+                    None => continue,
+                },
+                vote => {
+                    let iteration_id = reviewer
+                        .voted_at
+                        .and_then(|voted_at| {
+                            pr.iterations
+                                .iter()
+                                .filter(|x| x.created_date <= voted_at)
+                                .max_by_key(|x| x.id)
+                        })
+                        .map(|x| x.id)
+                        .unwrap_or(latest_iteration_id);
+                    // An unchanged vote that was not cast again since the tracked
+                    // review belongs to an already tracked iteration
+                    if tracked.is_some_and(|x| {
+                        x.vote == vote
+                            && (x.iteration_id == iteration_id
+                                || reviewer.voted_at.is_none_or(|y| y <= x.reviewed_at))
+                    }) {
+                        continue;
+                    }
+                    PullRequestReview {
+                        git_repository_id: pr.git_repository_id,
+                        pull_request_id: pr.id,
+                        iteration_id,
+                        vote,
+                        reviewed_at: reviewer.voted_at.unwrap_or_else(Utc::now),
+                    }
+                }
+            };
+            self.pull_request_review_repository
+                .save_pull_request_review(review.clone())
+                .await?;
+            reviews.insert(key, review);
+        }
+        Ok(reviews)
+    }
+}
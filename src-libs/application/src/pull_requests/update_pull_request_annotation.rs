@@ -0,0 +1,92 @@
+use crate::traits::{GitRepositoryRepository, PullRequestAnnotationRepository};
+use anyhow::Result;
+use chrono::Utc;
+use domain::models::PullRequestAnnotation;
+
+/// Responsible for attaching a private note and local tags to a single pull request
+pub struct UpdatePullRequestAnnotationCommand<G, N>
+where
+    G: GitRepositoryRepository,
+    N: PullRequestAnnotationRepository,
+{
+    git_repository_repository: G,
+    pull_request_annotation_repository: N,
+}
+
+impl<G, N> UpdatePullRequestAnnotationCommand<G, N>
+where
+    G: GitRepositoryRepository,
+    N: PullRequestAnnotationRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pull_request_annotation_repository` - The repository to access notes and tags
+    pub fn new(git_repository_repository: G, pull_request_annotation_repository: N) -> Self {
+        Self {
+            git_repository_repository,
+            pull_request_annotation_repository,
+        }
+    }
+
+    /// Execute the command, replacing the existing note and tags
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `note` - The free text note, removed when empty
+    /// * `tags` - The free-form local tags
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+        note: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<()> {
+        let note = note.map(|x| x.trim().to_string()).filter(|x| !x.is_empty());
+        let mut normalized_tags: Vec<String> = vec![];
+        for tag in tags.iter().map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            if !normalized_tags.iter().any(|x| x == tag) {
+                normalized_tags.push(tag.to_string());
+            }
+        }
+        if note.is_none() && normalized_tags.is_empty() {
+            self.pull_request_annotation_repository
+                .delete_pull_request_annotation(git_repository_id, pull_request_id)
+                .await?;
+            return Ok(());
+        }
+        let git_repo = self
+            .git_repository_repository
+            .get_git_repository_by_id(git_repository_id)
+            .await?;
+        let closed_at = self
+            .pull_request_annotation_repository
+            .get_pull_request_annotations()
+            .await?
+            .into_iter()
+            .find(|x| {
+                x.git_repository_id == *git_repository_id && x.pull_request_id == *pull_request_id
+            })
+            .and_then(|x| x.closed_at);
+        self.pull_request_annotation_repository
+            .save_pull_request_annotation(PullRequestAnnotation {
+                git_provider: git_repo.git_provider,
+                git_repository_id: git_repo.id,
+                pull_request_id: *pull_request_id,
+                note,
+                tags: normalized_tags,
+                updated_at: Utc::now(),
+                closed_at,
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod assign_git_repositories_to_group;
+pub mod create_repository_group;
+pub mod get_repository_groups;
+pub mod remove_repository_group;
+pub mod rename_repository_group;
+pub mod unassign_git_repositories_from_group;
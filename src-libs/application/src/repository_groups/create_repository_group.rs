@@ -0,0 +1,53 @@
+use crate::traits::RepositoryGroupRepository;
+use anyhow::Result;
+
+/// Responsible for creating a new, empty repository group
+pub struct CreateRepositoryGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    repository_group_repository: M,
+}
+
+impl<M> CreateRepositoryGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(repository_group_repository: M) -> Self {
+        Self {
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the repository group
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u32>` - The unique identifier of the created repository group
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the name is empty or already taken
+    pub async fn execute(&self, name: &str) -> Result<u32> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!(
+                "The repository group name must not be empty"
+            ));
+        }
+        let id = self
+            .repository_group_repository
+            .create_repository_group(name)
+            .await?;
+        Ok(id)
+    }
+}
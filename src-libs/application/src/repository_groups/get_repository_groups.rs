@@ -0,0 +1,44 @@
+use crate::{dtos::RepositoryGroupDto, traits::RepositoryGroupRepository};
+use anyhow::Result;
+
+/// Responsible for getting all repository groups
+pub struct RepositoryGroupsQuery<M>
+where
+    M: RepositoryGroupRepository,
+{
+    repository_group_repository: M,
+}
+
+impl<M> RepositoryGroupsQuery<M>
+where
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(repository_group_repository: M) -> Self {
+        Self {
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<RepositoryGroupDto>>` The list of retrieved repository groups
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<Vec<RepositoryGroupDto>> {
+        let groups = self
+            .repository_group_repository
+            .get_all_repository_groups()
+            .await?;
+        let result = groups.iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::traits::RepositoryGroupRepository;
+use anyhow::Result;
+
+/// Responsible for removing a single repository group, while keeping
+/// the assigned git repositories imported
+pub struct RemoveRepositoryGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    repository_group_repository: M,
+}
+
+impl<M> RemoveRepositoryGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(repository_group_repository: M) -> Self {
+        Self {
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group to remove
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, id: &u32) -> Result<()> {
+        self.repository_group_repository
+            .delete_repository_group(id)
+            .await?;
+        Ok(())
+    }
+}
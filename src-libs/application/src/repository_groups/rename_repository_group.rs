@@ -0,0 +1,49 @@
+use crate::traits::RepositoryGroupRepository;
+use anyhow::Result;
+
+/// Responsible for renaming a single repository group
+pub struct RenameRepositoryGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    repository_group_repository: M,
+}
+
+impl<M> RenameRepositoryGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(repository_group_repository: M) -> Self {
+        Self {
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    /// * `name` - The new name of the repository group
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the name is empty or already taken
+    pub async fn execute(&self, id: &u32, name: &str) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!(
+                "The repository group name must not be empty"
+            ));
+        }
+        self.repository_group_repository
+            .rename_repository_group(id, name)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::traits::RepositoryGroupRepository;
+use anyhow::Result;
+
+/// Responsible for removing multiple git repositories from a single
+/// repository group at once
+pub struct UnassignGitRepositoriesFromGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    repository_group_repository: M,
+}
+
+impl<M> UnassignGitRepositoriesFromGroupCommand<M>
+where
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(repository_group_repository: M) -> Self {
+        Self {
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    /// * `git_repository_ids` - The unique identifiers of the git repositories to remove
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, id: &u32, git_repository_ids: &[u32]) -> Result<()> {
+        self.repository_group_repository
+            .unassign_git_repositories(id, git_repository_ids)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+pub mod check_secret_health;
+pub mod migrate_secrets;
+
+use crate::traits::{GitRepositoryRepository, PatMetadataRepository, SecretRepository};
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The keys of all secrets the application stored and may still store
+struct KnownSecretKeys {
+    /// The keys referenced by imported git repositories, with the unique
+    /// identifiers of the git repositories referencing them
+    referenced: BTreeMap<String, Vec<u32>>,
+    /// The keys no imported git repository references anymore, but a secret
+    /// is stored for or was stored for at some point
+    unreferenced: BTreeSet<String>,
+}
+
+/// Get the keys of all secrets the application is aware of
+///
+/// Keys are known from the imported git repositories, the keys the secret
+/// storage lists and the metadata kept about stored PATs. The latter covers
+/// secrets stored before the secret storage listed its keys.
+///
+/// # Arguments
+///
+/// * `git_repository_repository` - The repository to access git repositories
+/// * `pat_metadata_repository` - The repository to access the metadata of PATs
+/// * `secret_repository` - The secret storage to list the stored keys of
+///
+/// # Errors
+///
+/// Any errors that might occur
+async fn get_known_secret_keys<G, K, S>(
+    git_repository_repository: &G,
+    pat_metadata_repository: &K,
+    secret_repository: &S,
+) -> Result<KnownSecretKeys>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    S: SecretRepository,
+{
+    let mut referenced = BTreeMap::<String, Vec<u32>>::new();
+    for git_repo in git_repository_repository.get_all_git_repositories().await? {
+        referenced
+            .entry(git_repo.credential_key)
+            .or_default()
+            .push(git_repo.id);
+    }
+    let unreferenced = secret_repository
+        .get_secret_keys()
+        .await?
+        .into_iter()
+        .chain(
+            pat_metadata_repository
+                .get_all_pat_metadata()
+                .await?
+                .into_iter()
+                .map(|x| x.credential_key),
+        )
+        .filter(|x| !referenced.contains_key(x))
+        .collect();
+    Ok(KnownSecretKeys {
+        referenced,
+        unreferenced,
+    })
+}
@@ -0,0 +1,92 @@
+use super::get_known_secret_keys;
+use crate::{
+    dtos::{FailedSecretDto, MissingSecretDto, SecretHealthReportDto},
+    traits::{GitRepositoryRepository, PatMetadataRepository, SecretRepository},
+};
+use anyhow::Result;
+use domain::errors::SecretNotFoundError;
+
+/// Responsible for checking that the secrets referenced by imported git
+/// repositories are present and no longer referenced ones were removed
+pub struct CheckSecretHealthQuery<G, K, S>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    S: SecretRepository,
+{
+    git_repository_repository: G,
+    pat_metadata_repository: K,
+    secret_repository: S,
+}
+
+impl<G, K, S> CheckSecretHealthQuery<G, K, S>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    S: SecretRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pat_metadata_repository` - The repository to access the metadata of PATs
+    /// * `secret_repository` - The repository to access secrets
+    pub fn new(
+        git_repository_repository: G,
+        pat_metadata_repository: K,
+        secret_repository: S,
+    ) -> Self {
+        Self {
+            git_repository_repository,
+            pat_metadata_repository,
+            secret_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SecretHealthReportDto>` - The present, missing and orphaned secrets,
+    ///   as well as the ones that could not be checked
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<SecretHealthReportDto> {
+        let known_keys = get_known_secret_keys(
+            &self.git_repository_repository,
+            &self.pat_metadata_repository,
+            &self.secret_repository,
+        )
+        .await?;
+        let mut result = SecretHealthReportDto::default();
+        for (credential_key, git_repository_ids) in known_keys.referenced {
+            match self.secret_repository.get_secret(&credential_key).await {
+                Ok(_) => result.present.push(credential_key),
+                Err(err) if err.is::<SecretNotFoundError>() => {
+                    result.missing.push(MissingSecretDto {
+                        credential_key,
+                        git_repository_ids,
+                    })
+                }
+                Err(err) => result.failed.push(FailedSecretDto {
+                    credential_key,
+                    error: err.to_string(),
+                }),
+            }
+        }
+        for credential_key in known_keys.unreferenced {
+            match self.secret_repository.get_secret(&credential_key).await {
+                Ok(_) => result.orphaned.push(credential_key),
+                Err(err) if err.is::<SecretNotFoundError>() => {}
+                Err(err) => result.failed.push(FailedSecretDto {
+                    credential_key,
+                    error: err.to_string(),
+                }),
+            }
+        }
+        Ok(result)
+    }
+}
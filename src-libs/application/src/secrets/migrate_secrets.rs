@@ -0,0 +1,144 @@
+use super::get_known_secret_keys;
+use crate::{
+    dtos::{SecretMigrationResultDto, SecretStorageBackend},
+    traits::{
+        GitRepositoryRepository, PatMetadataRepository, SecretRepository, SettingsRepository,
+    },
+};
+use anyhow::Result;
+use domain::errors::SecretNotFoundError;
+
+/// Responsible for migrating all secrets from one secret storage backend
+/// to another one and selecting the latter afterwards
+pub struct MigrateSecretsCommand<G, K, S, T, R>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    S: SecretRepository,
+    T: SecretRepository,
+    R: SettingsRepository,
+{
+    git_repository_repository: G,
+    pat_metadata_repository: K,
+    source_secret_repository: S,
+    target_secret_repository: T,
+    settings_repository: R,
+}
+
+impl<G, K, S, T, R> MigrateSecretsCommand<G, K, S, T, R>
+where
+    G: GitRepositoryRepository,
+    K: PatMetadataRepository,
+    S: SecretRepository,
+    T: SecretRepository,
+    R: SettingsRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_repository` - The repository to access git repositories
+    /// * `pat_metadata_repository` - The repository to access the metadata of PATs
+    /// * `source_secret_repository` - The backend to migrate the secrets from
+    /// * `target_secret_repository` - The backend to migrate the secrets to
+    /// * `settings_repository` - The repository to access the settings
+    pub fn new(
+        git_repository_repository: G,
+        pat_metadata_repository: K,
+        source_secret_repository: S,
+        target_secret_repository: T,
+        settings_repository: R,
+    ) -> Self {
+        Self {
+            git_repository_repository,
+            pat_metadata_repository,
+            source_secret_repository,
+            target_secret_repository,
+            settings_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// All secrets are copied before any is removed from the source, so a
+    /// failed migration leaves the source backend untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_backend` - The backend the secrets are migrated from
+    /// * `target_backend` - The backend the secrets are migrated to, selected afterwards
+    /// * `remove_from_source` - Whether the migrated secrets are removed from the source backend
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SecretMigrationResultDto>` - The number of migrated secrets and the keys missing in the source
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, in which case the source backend stays selected
+    pub async fn execute(
+        &self,
+        source_backend: SecretStorageBackend,
+        target_backend: SecretStorageBackend,
+        remove_from_source: bool,
+    ) -> Result<SecretMigrationResultDto> {
+        if source_backend == target_backend {
+            return Err(anyhow::anyhow!(
+                "The secrets can not be migrated to the same backend"
+            ));
+        }
+        let known_keys = get_known_secret_keys(
+            &self.git_repository_repository,
+            &self.pat_metadata_repository,
+            &self.source_secret_repository,
+        )
+        .await?;
+        let mut result = SecretMigrationResultDto::default();
+        let mut migrated_keys = vec![];
+        for credential_key in known_keys
+            .referenced
+            .into_keys()
+            .chain(known_keys.unreferenced)
+        {
+            match self
+                .source_secret_repository
+                .get_secret(&credential_key)
+                .await
+            {
+                Ok(secret) => {
+                    self.target_secret_repository
+                        .set_secret(&credential_key, &secret)
+                        .await?;
+                    migrated_keys.push(credential_key);
+                }
+                Err(err) if err.is::<SecretNotFoundError>() => result.missing.push(credential_key),
+                Err(err) => {
+                    return Err(err.context(format!(
+                        "The secret `{}` could not be read from the source",
+                        credential_key
+                    )));
+                }
+            }
+        }
+        let mut settings = self.settings_repository.get_settings().await?;
+        settings.secret_storage_backend = target_backend.into();
+        self.settings_repository.save_settings(settings).await?;
+        if remove_from_source {
+            for credential_key in &migrated_keys {
+                if let Err(err) = self
+                    .source_secret_repository
+                    .delete_secret(credential_key)
+                    .await
+                {
+                    log::warn!(
+                        "Error removing migrated secret `{}` from the source: {}",
+                        credential_key,
+                        err
+                    );
+                }
+            }
+        }
+        result.migrated = migrated_keys.len();
+        Ok(result)
+    }
+}
@@ -0,0 +1,57 @@
+pub mod get_settings;
+pub mod unlock_secret_storage;
+pub mod update_settings;
+
+use crate::dtos::{SecretSource, SettingsDto};
+use anyhow::Result;
+use chrono_tz::Tz;
+
+/// Validate settings before they are saved
+///
+/// # Arguments
+///
+/// * `settings` - The settings to validate
+///
+/// # Errors
+///
+/// If the secret sources, the business hours or the time zone are invalid
+pub(crate) fn validate_settings(settings: &SettingsDto) -> Result<()> {
+    let sources = &settings.secret_sources;
+    if !sources.contains(&SecretSource::Storage) {
+        return Err(anyhow::anyhow!(
+            "The secret storage must be one of the secret sources"
+        ));
+    }
+    if sources
+        .iter()
+        .enumerate()
+        .any(|(i, x)| sources[..i].contains(x))
+    {
+        return Err(anyhow::anyhow!(
+            "Each secret source must only be listed once"
+        ));
+    }
+    if sources.contains(&SecretSource::CredentialHelper)
+        && settings
+            .credential_helper_command
+            .as_deref()
+            .is_none_or(|x| x.trim().is_empty())
+    {
+        return Err(anyhow::anyhow!(
+            "The credential helper requires a command to run"
+        ));
+    }
+    if settings.business_hours_start >= settings.business_hours_end {
+        return Err(anyhow::anyhow!(
+            "The business hours must start before they end"
+        ));
+    }
+    settings
+        .business_time_zone
+        .trim()
+        .parse::<Tz>()
+        .map_err(|_| {
+            anyhow::anyhow!("The time zone `{}` is unknown", settings.business_time_zone)
+        })?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::{dtos::SettingsDto, traits::SettingsRepository};
+use anyhow::Result;
+
+/// Responsible for getting the current settings
+pub struct SettingsQuery<R>
+where
+    R: SettingsRepository,
+{
+    settings_repository: R,
+}
+
+impl<R> SettingsQuery<R>
+where
+    R: SettingsRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `settings_repository` - The repository to access the settings
+    pub fn new(settings_repository: R) -> Self {
+        Self {
+            settings_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SettingsDto>` - The current settings
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<SettingsDto> {
+        let settings = self.settings_repository.get_settings().await?;
+        Ok((&settings).into())
+    }
+}
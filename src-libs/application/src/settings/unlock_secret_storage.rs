@@ -0,0 +1,41 @@
+use crate::traits::LockableSecretRepository;
+use anyhow::Result;
+
+/// Responsible for unlocking the secret storage with a passphrase, when
+/// secrets are stored encrypted
+pub struct UnlockSecretStorageCommand<L>
+where
+    L: LockableSecretRepository,
+{
+    secret_repository: L,
+}
+
+impl<L> UnlockSecretStorageCommand<L>
+where
+    L: LockableSecretRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_repository` - The repository to access secrets
+    pub fn new(secret_repository: L) -> Self {
+        Self { secret_repository }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The passphrase the secrets are encrypted with
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the passphrase is wrong
+    pub async fn execute(&self, passphrase: &str) -> Result<()> {
+        if passphrase.is_empty() {
+            return Err(anyhow::anyhow!("The passphrase must not be empty"));
+        }
+        self.secret_repository.unlock(passphrase).await
+    }
+}
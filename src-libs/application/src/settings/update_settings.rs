@@ -0,0 +1,47 @@
+use super::validate_settings;
+use crate::{dtos::SettingsDto, traits::SettingsRepository};
+use anyhow::Result;
+
+/// Responsible for updating the settings
+pub struct UpdateSettingsCommand<R>
+where
+    R: SettingsRepository,
+{
+    settings_repository: R,
+}
+
+impl<R> UpdateSettingsCommand<R>
+where
+    R: SettingsRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `settings_repository` - The repository to access the settings
+    pub fn new(settings_repository: R) -> Self {
+        Self {
+            settings_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// Changing the secret storage backend does not move already stored
+    /// secrets, they are migrated with the `MigrateSecretsCommand` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The settings to apply
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the secret sources are invalid
+    pub async fn execute(&self, settings: &SettingsDto) -> Result<()> {
+        validate_settings(settings)?;
+        self.settings_repository
+            .save_settings(settings.into())
+            .await?;
+        Ok(())
+    }
+}
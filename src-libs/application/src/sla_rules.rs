@@ -0,0 +1,51 @@
+pub mod create_sla_rule;
+pub mod evaluate_sla_rules;
+pub mod get_holidays;
+pub mod get_sla_rules;
+pub mod remove_sla_rule;
+pub mod update_holidays;
+pub mod update_sla_rule;
+
+use crate::{dtos::SlaRuleDto, traits::RepositoryGroupRepository};
+use anyhow::Result;
+use domain::errors::RepositoryGroupNotFoundError;
+
+/// Validate an SLA rule before it is saved
+///
+/// # Arguments
+///
+/// * `sla_rule` - The SLA rule to validate
+/// * `repository_group_repository` - The repository to check the repository group exists with
+///
+/// # Errors
+///
+/// If the name is empty, the threshold is zero or the repository group does not exist
+pub(crate) async fn validate_sla_rule<M>(
+    sla_rule: &SlaRuleDto,
+    repository_group_repository: &M,
+) -> Result<()>
+where
+    M: RepositoryGroupRepository,
+{
+    if sla_rule.name.trim().is_empty() {
+        return Err(anyhow::anyhow!("The SLA rule name must not be empty"));
+    }
+    if sla_rule.threshold_hours == 0 {
+        return Err(anyhow::anyhow!(
+            "The SLA rule threshold must be at least one hour"
+        ));
+    }
+    if let Some(group_id) = sla_rule.repository_group_id {
+        repository_group_repository
+            .get_repository_group_by_id(&group_id)
+            .await
+            .map_err(|err| match err.is::<RepositoryGroupNotFoundError>() {
+                true => err,
+                false => err.context(format!(
+                    "Could not get the repository group `{}` of the SLA rule",
+                    group_id
+                )),
+            })?;
+    }
+    Ok(())
+}
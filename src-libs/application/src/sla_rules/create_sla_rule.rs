@@ -0,0 +1,57 @@
+use super::validate_sla_rule;
+use crate::{
+    dtos::SlaRuleDto,
+    traits::{RepositoryGroupRepository, SlaRuleRepository},
+};
+use anyhow::Result;
+
+/// Responsible for creating a new SLA rule
+pub struct CreateSlaRuleCommand<K, M>
+where
+    K: SlaRuleRepository,
+    M: RepositoryGroupRepository,
+{
+    sla_rule_repository: K,
+    repository_group_repository: M,
+}
+
+impl<K, M> CreateSlaRuleCommand<K, M>
+where
+    K: SlaRuleRepository,
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to access SLA rules
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(sla_rule_repository: K, repository_group_repository: M) -> Self {
+        Self {
+            sla_rule_repository,
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule` - The SLA rule to create, its identifier is ignored
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u32>` - The unique identifier of the created SLA rule
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the SLA rule is invalid
+    pub async fn execute(&self, sla_rule: &SlaRuleDto) -> Result<u32> {
+        validate_sla_rule(sla_rule, &self.repository_group_repository).await?;
+        let id = self
+            .sla_rule_repository
+            .create_sla_rule(sla_rule.into())
+            .await?;
+        Ok(id)
+    }
+}
@@ -0,0 +1,416 @@
+use crate::{
+    dtos::SlaViolationDto,
+    traits::{SettingsRepository, SlaRuleRepository},
+};
+use anyhow::Result;
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+use domain::{
+    enums::{ReviewerVote, SlaRuleKind},
+    models::{PullRequest, RepositoryGroup, SlaRule},
+};
+use std::collections::{HashMap, HashSet};
+
+/// The working days and hours that count towards SLA rules limited to business hours
+struct BusinessHours {
+    /// The time of day business hours start at
+    start: NaiveTime,
+    /// The time of day business hours end at
+    end: NaiveTime,
+    /// The time zone the days and times are evaluated in
+    time_zone: Tz,
+    /// The days that do not count
+    holidays: HashSet<NaiveDate>,
+}
+
+/// Responsible for evaluating the SLA rules against open pull requests
+pub struct SlaRuleEvaluator<K, E>
+where
+    K: SlaRuleRepository,
+    E: SettingsRepository,
+{
+    sla_rule_repository: K,
+    settings_repository: E,
+}
+
+impl<K, E> SlaRuleEvaluator<K, E>
+where
+    K: SlaRuleRepository,
+    E: SettingsRepository,
+{
+    /// Create a new instance of the evaluator
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to get the SLA rules and holidays
+    /// * `settings_repository` - The repository to get the business hours and their time zone
+    pub fn new(sla_rule_repository: K, settings_repository: E) -> Self {
+        Self {
+            sla_rule_repository,
+            settings_repository,
+        }
+    }
+
+    /// Evaluate the SLA rules that apply to each of the open pull requests
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_requests` - The retrieved open pull requests
+    /// * `repository_groups` - All repository groups, to find the rules that apply
+    /// * `now` - The date to evaluate the SLA rules at
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HashMap<(u32, u32), Vec<SlaViolationDto>>>` - The violated SLA rules
+    ///   by git repository and pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub(crate) async fn evaluate(
+        &self,
+        pull_requests: &[PullRequest],
+        repository_groups: &[RepositoryGroup],
+        now: DateTime<Utc>,
+    ) -> Result<HashMap<(u32, u32), Vec<SlaViolationDto>>> {
+        let sla_rules = self.sla_rule_repository.get_sla_rules().await?;
+        let settings = self.settings_repository.get_settings().await?;
+        // The time zone is validated when saved, but might be unknown when it
+        // was taken from the host, which must not prevent showing pull requests
+        let time_zone = settings
+            .business_time_zone
+            .parse::<Tz>()
+            .unwrap_or_else(|_| {
+                log::warn!(
+                    "The time zone `{}` is unknown, business hours are evaluated in UTC",
+                    settings.business_time_zone
+                );
+                Tz::UTC
+            });
+        let business_hours = BusinessHours {
+            start: settings.business_hours_start,
+            end: settings.business_hours_end,
+            time_zone,
+            holidays: self
+                .sla_rule_repository
+                .get_holidays()
+                .await?
+                .into_iter()
+                .map(|x| x.date)
+                .collect(),
+        };
+        let result = pull_requests
+            .iter()
+            .map(|pr| {
+                let group_ids = repository_groups
+                    .iter()
+                    .filter(|x| x.git_repository_ids.contains(&pr.git_repository_id))
+                    .map(|x| x.id)
+                    .collect::<Vec<u32>>();
+                let violations =
+                    evaluate_sla_rules(pr, &sla_rules, &group_ids, &business_hours, now);
+                ((pr.git_repository_id, pr.id), violations)
+            })
+            .collect();
+        Ok(result)
+    }
+}
+
+/// Evaluate the SLA rules that apply to an open pull request
+///
+/// # Arguments
+///
+/// * `pull_request` - The open pull request
+/// * `sla_rules` - All configured SLA rules
+/// * `group_ids` - The repository groups the git repository of the pull request belongs to
+/// * `business_hours` - The working days and hours that count towards business hours
+/// * `now` - The date to evaluate the SLA rules at
+///
+/// # Returns
+///
+/// * `Vec<SlaViolationDto>` - The violated SLA rules
+fn evaluate_sla_rules(
+    pull_request: &PullRequest,
+    sla_rules: &[SlaRule],
+    group_ids: &[u32],
+    business_hours: &BusinessHours,
+    now: DateTime<Utc>,
+) -> Vec<SlaViolationDto> {
+    let mut result = vec![];
+    for sla_rule in sla_rules.iter().filter(|x| {
+        x.repository_group_id
+            .is_none_or(|group_id| group_ids.contains(&group_id))
+    }) {
+        let since = match sla_rule.kind {
+            SlaRuleKind::NoReviewerVote => waiting_for_vote_since(pull_request),
+            SlaRuleKind::NoUpdate => Some(pull_request.last_updated_date),
+            SlaRuleKind::UnresolvedComments => pull_request.oldest_unresolved_comment_date,
+        };
+        let Some(since) = since else {
+            continue;
+        };
+        let elapsed_hours = match sla_rule.is_business_hours {
+            true => business_hours_between(since, now, business_hours),
+            false => hours_between(since, now),
+        };
+        if elapsed_hours >= sla_rule.threshold_hours as f64 {
+            result.push(SlaViolationDto {
+                sla_rule_id: sla_rule.id,
+                sla_rule_name: sla_rule.name.to_string(),
+                kind: sla_rule.kind.into(),
+                threshold_hours: sla_rule.threshold_hours,
+                elapsed_hours,
+                is_business_hours: sla_rule.is_business_hours,
+            });
+        }
+    }
+    result
+}
+
+/// Helper to get the hours between two dates, never negative
+///
+/// # Arguments
+///
+/// * `start` - The start date
+/// * `end` - The end date
+fn hours_between(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    ((end - start).num_seconds().max(0) as f64) / 3600.0
+}
+
+/// Helper to get since when a pull request waits for the vote of a reviewer
+///
+/// Without reviewers there is nobody to wait for, so the pull request does not
+/// wait at all. Votes that were cast and reset since, e.g. by a push, no longer
+/// count, so the pull request waits since the latest push after the latest vote.
+///
+/// # Arguments
+///
+/// * `pull_request` - The open pull request
+///
+/// # Returns
+///
+/// * `Option<DateTime<Utc>>` - The date the pull request waits for a vote since,
+///   none if it does not wait for a vote
+fn waiting_for_vote_since(pull_request: &PullRequest) -> Option<DateTime<Utc>> {
+    let reviewers = &pull_request.reviewers;
+    if reviewers.is_empty() || reviewers.iter().any(|x| x.vote != ReviewerVote::NoVote) {
+        return None;
+    }
+    match reviewers.iter().filter_map(|x| x.voted_at).max() {
+        Some(voted_at) => Some(
+            pull_request
+                .latest_iteration_date
+                .filter(|x| *x > voted_at)
+                .unwrap_or(voted_at),
+        ),
+        None => Some(pull_request.creation_date),
+    }
+}
+
+/// Helper to get the business hours between two dates
+///
+/// Only the hours within the working day count, unless the day is on a weekend
+/// or a holiday. Days and hours are determined in the configured time zone.
+///
+/// # Arguments
+///
+/// * `start` - The start date
+/// * `end` - The end date
+/// * `business_hours` - The working days and hours that count
+fn business_hours_between(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    business_hours: &BusinessHours,
+) -> f64 {
+    let time_zone = &business_hours.time_zone;
+    let last_day = end.with_timezone(time_zone).date_naive();
+    let mut seconds = 0;
+    let mut day = start.with_timezone(time_zone).date_naive();
+    while day <= last_day {
+        let is_weekend = matches!(day.weekday(), Weekday::Sat | Weekday::Sun);
+        if !is_weekend && !business_hours.holidays.contains(&day) {
+            let day_start = to_utc(time_zone, day.and_time(business_hours.start));
+            let day_end = to_utc(time_zone, day.and_time(business_hours.end));
+            let overlap = end.min(day_end) - start.max(day_start);
+            seconds += overlap.num_seconds().max(0);
+        }
+        day += Duration::days(1);
+    }
+    seconds as f64 / 3600.0
+}
+
+/// Helper to get the point in time of a local date and time in a time zone
+///
+/// Times skipped by a daylight saving time change are interpreted with the
+/// offset after the change, times repeated by it as their first occurrence.
+///
+/// # Arguments
+///
+/// * `time_zone` - The time zone of the local date and time
+/// * `date_time` - The local date and time
+fn to_utc(time_zone: &Tz, date_time: NaiveDateTime) -> DateTime<Utc> {
+    match time_zone.from_local_datetime(&date_time).earliest() {
+        Some(x) => x.with_timezone(&Utc),
+        None => {
+            let offset = time_zone.offset_from_utc_datetime(&date_time).fix();
+            Utc.from_utc_datetime(&(date_time - offset))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::{
+        enums::GitProvider,
+        models::{PullRequestChecks, Reviewer},
+    };
+
+    /// Working days from 9:00 to 17:00 in the given time zone, without holidays
+    fn business_hours(time_zone: Tz) -> BusinessHours {
+        BusinessHours {
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            time_zone,
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// The point in time of a local date and time in a time zone
+    fn at(time_zone: Tz, year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        time_zone
+            .with_ymd_and_hms(year, month, day, hour, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// An open pull request created on Monday, 2026-10-19 at 8:00 UTC
+    fn pull_request(
+        reviewers: Vec<Reviewer>,
+        latest_iteration_date: Option<DateTime<Utc>>,
+    ) -> PullRequest {
+        let creation_date = at(Tz::UTC, 2026, 10, 19, 8);
+        PullRequest {
+            id: 1,
+            git_repository_id: 1,
+            git_provider: GitProvider::AzureDevOps,
+            repository_name: "repository".to_string(),
+            title: "title".to_string(),
+            merge_status: "succeeded".to_string(),
+            creator_id: "creator".to_string(),
+            creator_name: "Creator".to_string(),
+            creation_date,
+            last_updated_date: creation_date,
+            number_of_comments: 0,
+            number_of_closed_comments: 0,
+            first_comment_date: None,
+            oldest_unresolved_comment_date: None,
+            link: String::new(),
+            checks: PullRequestChecks::new(vec![]),
+            work_items: vec![],
+            change_statistics: None,
+            latest_iteration_id: None,
+            latest_iteration_date,
+            iterations: vec![],
+            reviewers,
+            activities: vec![],
+        }
+    }
+
+    /// A reviewer with the current vote and the date it was cast last
+    fn reviewer(vote: ReviewerVote, voted_at: Option<DateTime<Utc>>) -> Reviewer {
+        Reviewer {
+            id: "reviewer".to_string(),
+            display_name: "Reviewer".to_string(),
+            vote,
+            is_required: true,
+            voted_at,
+        }
+    }
+
+    #[test]
+    fn business_hours_between_skips_weekend() {
+        let business_hours = business_hours(Tz::UTC);
+        // Friday 16:00 until Monday 10:00
+        let start = at(Tz::UTC, 2026, 10, 16, 16);
+        let end = at(Tz::UTC, 2026, 10, 19, 10);
+        assert_eq!(business_hours_between(start, end, &business_hours), 2.0);
+    }
+
+    #[test]
+    fn business_hours_between_follows_daylight_saving_time_switch() {
+        let business_hours = business_hours(Tz::Europe__Berlin);
+        // Summer time ends on Sunday, 2026-10-25, so Monday starts at 8:00 UTC
+        let start = at(Tz::Europe__Berlin, 2026, 10, 23, 16);
+        let end = at(Tz::Europe__Berlin, 2026, 10, 26, 10);
+        assert_eq!(business_hours_between(start, end, &business_hours), 2.0);
+        // Summer time starts on Sunday, 2026-03-29, so Monday starts at 7:00 UTC
+        let start = at(Tz::Europe__Berlin, 2026, 3, 27, 16);
+        let end = at(Tz::Europe__Berlin, 2026, 3, 30, 10);
+        assert_eq!(business_hours_between(start, end, &business_hours), 2.0);
+    }
+
+    #[test]
+    fn business_hours_between_outside_working_hours_is_zero() {
+        let business_hours = business_hours(Tz::Europe__Berlin);
+        // Wednesday before and after the working day
+        let start = at(Tz::Europe__Berlin, 2026, 10, 21, 6);
+        let end = at(Tz::Europe__Berlin, 2026, 10, 21, 8);
+        assert_eq!(business_hours_between(start, end, &business_hours), 0.0);
+        let start = at(Tz::Europe__Berlin, 2026, 10, 21, 18);
+        let end = at(Tz::Europe__Berlin, 2026, 10, 21, 22);
+        assert_eq!(business_hours_between(start, end, &business_hours), 0.0);
+    }
+
+    #[test]
+    fn business_hours_between_skips_holidays() {
+        let mut business_hours = business_hours(Tz::UTC);
+        business_hours
+            .holidays
+            .insert(NaiveDate::from_ymd_opt(2026, 10, 20).unwrap());
+        // Monday 16:00 until Wednesday 10:00, with Tuesday off
+        let start = at(Tz::UTC, 2026, 10, 19, 16);
+        let end = at(Tz::UTC, 2026, 10, 21, 10);
+        assert_eq!(business_hours_between(start, end, &business_hours), 2.0);
+    }
+
+    #[test]
+    fn waiting_for_vote_since_creation_without_votes() {
+        let pull_request = pull_request(vec![reviewer(ReviewerVote::NoVote, None)], None);
+        assert_eq!(
+            waiting_for_vote_since(&pull_request),
+            Some(pull_request.creation_date)
+        );
+    }
+
+    #[test]
+    fn waiting_for_vote_since_none_when_voted_or_without_reviewers() {
+        let voted_at = at(Tz::UTC, 2026, 10, 19, 12);
+        let pull_request =
+            pull_request(vec![reviewer(ReviewerVote::Approved, Some(voted_at))], None);
+        assert_eq!(waiting_for_vote_since(&pull_request), None);
+        let pull_request = self::pull_request(vec![], None);
+        assert_eq!(waiting_for_vote_since(&pull_request), None);
+    }
+
+    #[test]
+    fn waiting_for_vote_since_restarts_after_reset_vote() {
+        let voted_at = at(Tz::UTC, 2026, 10, 19, 12);
+        let pushed_at = at(Tz::UTC, 2026, 10, 20, 9);
+        // The vote was reset by a push after it was cast
+        let pull_request = pull_request(
+            vec![reviewer(ReviewerVote::NoVote, Some(voted_at))],
+            Some(pushed_at),
+        );
+        assert_eq!(waiting_for_vote_since(&pull_request), Some(pushed_at));
+        // The vote was reset without a push after it was cast
+        let pull_request = self::pull_request(
+            vec![reviewer(ReviewerVote::NoVote, Some(voted_at))],
+            Some(at(Tz::UTC, 2026, 10, 19, 10)),
+        );
+        assert_eq!(waiting_for_vote_since(&pull_request), Some(voted_at));
+    }
+}
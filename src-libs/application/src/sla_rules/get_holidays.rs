@@ -0,0 +1,42 @@
+use crate::{dtos::HolidayDto, traits::SlaRuleRepository};
+use anyhow::Result;
+
+/// Responsible for getting the holidays, which do not count towards
+/// the business hours of SLA rules
+pub struct HolidaysQuery<K>
+where
+    K: SlaRuleRepository,
+{
+    sla_rule_repository: K,
+}
+
+impl<K> HolidaysQuery<K>
+where
+    K: SlaRuleRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to access the holidays
+    pub fn new(sla_rule_repository: K) -> Self {
+        Self {
+            sla_rule_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<HolidayDto>>` - The list of holidays, ordered by their date
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<Vec<HolidayDto>> {
+        let holidays = self.sla_rule_repository.get_holidays().await?;
+        let result = holidays.iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{dtos::SlaRuleDto, traits::SlaRuleRepository};
+use anyhow::Result;
+
+/// Responsible for getting all configured SLA rules
+pub struct SlaRulesQuery<K>
+where
+    K: SlaRuleRepository,
+{
+    sla_rule_repository: K,
+}
+
+impl<K> SlaRulesQuery<K>
+where
+    K: SlaRuleRepository,
+{
+    /// Create a new instance of the query
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to access SLA rules
+    pub fn new(sla_rule_repository: K) -> Self {
+        Self {
+            sla_rule_repository,
+        }
+    }
+
+    /// Execute the query
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<SlaRuleDto>>` - The list of SLA rules
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self) -> Result<Vec<SlaRuleDto>> {
+        let sla_rules = self.sla_rule_repository.get_sla_rules().await?;
+        let result = sla_rules.iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+}
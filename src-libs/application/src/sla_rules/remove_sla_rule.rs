@@ -0,0 +1,40 @@
+use crate::traits::SlaRuleRepository;
+use anyhow::Result;
+
+/// Responsible for removing a single SLA rule
+pub struct RemoveSlaRuleCommand<K>
+where
+    K: SlaRuleRepository,
+{
+    sla_rule_repository: K,
+}
+
+impl<K> RemoveSlaRuleCommand<K>
+where
+    K: SlaRuleRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to access SLA rules
+    pub fn new(sla_rule_repository: K) -> Self {
+        Self {
+            sla_rule_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the SLA rule to remove
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    pub async fn execute(&self, id: &u32) -> Result<()> {
+        self.sla_rule_repository.delete_sla_rule(id).await?;
+        Ok(())
+    }
+}
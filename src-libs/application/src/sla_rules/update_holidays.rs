@@ -0,0 +1,52 @@
+use crate::{dtos::HolidayDto, traits::SlaRuleRepository};
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// Responsible for replacing the holidays, which do not count towards
+/// the business hours of SLA rules
+pub struct UpdateHolidaysCommand<K>
+where
+    K: SlaRuleRepository,
+{
+    sla_rule_repository: K,
+}
+
+impl<K> UpdateHolidaysCommand<K>
+where
+    K: SlaRuleRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to access the holidays
+    pub fn new(sla_rule_repository: K) -> Self {
+        Self {
+            sla_rule_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `holidays` - The holidays replacing the current ones
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when a date is listed more than once
+    pub async fn execute(&self, holidays: &[HolidayDto]) -> Result<()> {
+        let mut dates = HashSet::<NaiveDate>::new();
+        if let Some(duplicate) = holidays.iter().find(|x| !dates.insert(x.date)) {
+            return Err(anyhow::anyhow!(
+                "The holiday on `{}` is listed more than once",
+                duplicate.date
+            ));
+        }
+        self.sla_rule_repository
+            .save_holidays(holidays.iter().map(|x| x.into()).collect())
+            .await?;
+        Ok(())
+    }
+}
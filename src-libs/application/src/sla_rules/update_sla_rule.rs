@@ -0,0 +1,52 @@
+use super::validate_sla_rule;
+use crate::{
+    dtos::SlaRuleDto,
+    traits::{RepositoryGroupRepository, SlaRuleRepository},
+};
+use anyhow::Result;
+
+/// Responsible for updating a single SLA rule
+pub struct UpdateSlaRuleCommand<K, M>
+where
+    K: SlaRuleRepository,
+    M: RepositoryGroupRepository,
+{
+    sla_rule_repository: K,
+    repository_group_repository: M,
+}
+
+impl<K, M> UpdateSlaRuleCommand<K, M>
+where
+    K: SlaRuleRepository,
+    M: RepositoryGroupRepository,
+{
+    /// Create a new instance of the command
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule_repository` - The repository to access SLA rules
+    /// * `repository_group_repository` - The repository to access repository groups
+    pub fn new(sla_rule_repository: K, repository_group_repository: M) -> Self {
+        Self {
+            sla_rule_repository,
+            repository_group_repository,
+        }
+    }
+
+    /// Execute the command
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule` - The SLA rule to update, identified by its identifier
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the SLA rule is invalid
+    pub async fn execute(&self, sla_rule: &SlaRuleDto) -> Result<()> {
+        validate_sla_rule(sla_rule, &self.repository_group_repository).await?;
+        self.sla_rule_repository
+            .update_sla_rule(sla_rule.into())
+            .await?;
+        Ok(())
+    }
+}
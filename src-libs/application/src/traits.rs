@@ -1,6 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use domain::models::{GitRepository, PullRequest};
+use chrono::{DateTime, Utc};
+use domain::{
+    enums::PullRequestStatus,
+    models::{
+        Credential, DeviceCodeChallenge, GitRepository, Holiday, ImportedRepositoryGroup,
+        OAuthTokens, PassphraseEncryptedData, PatMetadata, Profile, PullRequest,
+        PullRequestAnnotation, PullRequestLifecycle, PullRequestReview, PullRequestReviewers,
+        PullRequestState, PullRequestTriage, RepositoryGroup, Settings, SlaRule,
+    },
+};
 
 /// Must be implemented by repositories responsible
 /// for accessing git repository data
@@ -64,6 +73,261 @@ pub trait GitRepositoryRepository: Send + Sync {
     ///
     /// Any error that might occur
     async fn delete_git_repository(&self, id: &u32) -> Result<()>;
+
+    /// Creates multiple git repositories at once
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repositories` - The models of the git repositories
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, in which case no git repository is created
+    async fn create_git_repositories(&self, git_repositories: Vec<GitRepository>) -> Result<()>;
+
+    /// Updates multiple git repositories at once
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repositories` - The models of the updated git repositories
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, in which case no git repository is updated
+    async fn update_git_repositories(&self, git_repositories: Vec<GitRepository>) -> Result<()>;
+
+    /// Applies the changes of a synchronization with the remote at once
+    ///
+    /// The updated git repositories are changed before the new ones are created,
+    /// so they can swap names among each other and new git repositories can take
+    /// over the names of renamed ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `updated_git_repositories` - The models of the updated git repositories
+    /// * `created_git_repositories` - The models of the git repositories to create
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, in which case no git repository is changed
+    async fn sync_git_repositories(
+        &self,
+        updated_git_repositories: Vec<GitRepository>,
+        created_git_repositories: Vec<GitRepository>,
+    ) -> Result<()>;
+
+    /// Applies the git repositories and repository groups of an imported
+    /// configuration at once
+    ///
+    /// The git repositories are changed before the repository groups, so the
+    /// groups can be assigned the git repositories created by the same import.
+    ///
+    /// # Arguments
+    ///
+    /// * `created_git_repositories` - The models of the git repositories to create
+    /// * `updated_git_repositories` - The models of the updated git repositories
+    /// * `repository_groups` - The repository groups to create or change
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, in which case nothing is changed
+    async fn import_git_repositories(
+        &self,
+        created_git_repositories: Vec<GitRepository>,
+        updated_git_repositories: Vec<GitRepository>,
+        repository_groups: Vec<ImportedRepositoryGroup>,
+    ) -> Result<()>;
+
+    /// Sets the active state of multiple git repositories at once
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The unique identifiers of the git repositories
+    /// * `is_active` - The new active state
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, in which case no git repository is changed
+    async fn set_git_repositories_active_state(&self, ids: &[u32], is_active: bool) -> Result<()>;
+
+    /// Deletes multiple git repositories at once
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The unique identifiers of the git repositories
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, in which case no git repository is deleted
+    async fn delete_git_repositories(&self, ids: &[u32]) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for signing in with OAuth
+#[async_trait]
+pub trait OAuthRepository: Send + Sync {
+    /// Start an OAuth device code sign in
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DeviceCodeChallenge>` - The challenge the user needs to complete in the browser
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn start_device_code_sign_in(&self) -> Result<DeviceCodeChallenge>;
+
+    /// Poll the result of an OAuth device code sign in once
+    ///
+    /// # Arguments
+    ///
+    /// * `device_code` - The device code of the started sign in
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<OAuthTokens>>` - The issued tokens, none while the user did not complete the sign in yet
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the sign in was declined or expired
+    async fn poll_device_code_sign_in(&self, device_code: &str) -> Result<Option<OAuthTokens>>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the metadata of stored PATs
+#[async_trait]
+pub trait PatMetadataRepository: Send + Sync {
+    /// Get the metadata of all stored PATs
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PatMetadata>>` - The list of found PAT metadata
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_all_pat_metadata(&self) -> Result<Vec<PatMetadata>>;
+
+    /// Get the metadata of a single stored PAT
+    ///
+    /// # Arguments
+    ///
+    /// * `credential_key` - The key the PAT is stored with
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<PatMetadata>>` - The found PAT metadata, if any
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pat_metadata(&self, credential_key: &str) -> Result<Option<PatMetadata>>;
+
+    /// Creates or replaces the metadata of a single stored PAT
+    ///
+    /// # Arguments
+    ///
+    /// * `pat_metadata` - The model of the PAT metadata
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn save_pat_metadata(&self, pat_metadata: PatMetadata) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing repository groups
+#[async_trait]
+pub trait RepositoryGroupRepository: Send + Sync {
+    /// Get all repository groups including their assigned git repositories
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<RepositoryGroup>>` - The list of found repository groups
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_all_repository_groups(&self) -> Result<Vec<RepositoryGroup>>;
+
+    /// Receive a single repository group by it's unique identifier
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    ///
+    /// # Returns
+    ///
+    /// * `Result<RepositoryGroup>` - The found repository group
+    ///
+    /// # Errors
+    ///
+    /// A `RepositoryGroupNotFoundError` if the repository group does not exist,
+    /// or any other errors that might occur
+    async fn get_repository_group_by_id(&self, id: &u32) -> Result<RepositoryGroup>;
+
+    /// Creates a single repository group without assigned git repositories
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the repository group
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u32>` - The unique identifier of the created repository group
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn create_repository_group(&self, name: &str) -> Result<u32>;
+
+    /// Renames a single repository group
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    /// * `name` - The new name of the repository group
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn rename_repository_group(&self, id: &u32, name: &str) -> Result<()>;
+
+    /// Deletes a single repository group, the assigned git repositories are kept
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_repository_group(&self, id: &u32) -> Result<()>;
+
+    /// Assigns multiple git repositories to a single repository group at once,
+    /// already assigned git repositories are ignored
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    /// * `git_repository_ids` - The unique identifiers of the git repositories to assign
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn assign_git_repositories(&self, id: &u32, git_repository_ids: &[u32]) -> Result<()>;
+
+    /// Removes multiple git repositories from a single repository group at once
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the repository group
+    /// * `git_repository_ids` - The unique identifiers of the git repositories to remove
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn unassign_git_repositories(&self, id: &u32, git_repository_ids: &[u32]) -> Result<()>;
 }
 
 /// Must be implemented by repositories responsible
@@ -74,7 +338,7 @@ pub trait AzureDevOpsRepository: Send + Sync {
     ///
     /// # Arguments
     ///
-    /// * `pat` - The private access token to authenticate the REST API request
+    /// * `credential` - The credential to authenticate the REST API request
     /// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
     /// * `repository_name` - The name of the git repository to query data for
     ///
@@ -87,16 +351,39 @@ pub trait AzureDevOpsRepository: Send + Sync {
     /// Any errors that might occur
     async fn get_open_pull_requests_in_repository(
         &self,
-        pat: &str,
+        credential: &Credential,
         context: &str,
         repository_name: &str,
     ) -> Result<Vec<PullRequest>>;
 
+    /// Get the reviewers of all open pull requests of a single Azure DevOps git
+    /// repository, with a single request and without the details of each pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - The credential to authenticate the REST API request
+    /// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
+    /// * `repository_name` - The name of the git repository to query data for
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestReviewers>>` - The reviewers per open pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_open_pull_request_reviewers_in_repository(
+        &self,
+        credential: &Credential,
+        context: &str,
+        repository_name: &str,
+    ) -> Result<Vec<PullRequestReviewers>>;
+
     /// Get all git repositories inside a single Azure DevOps organization
     ///
     /// # Arguments
     ///
-    /// * `pat` - The private access token to authenticate the REST API request
+    /// * `credential` - The credential to authenticate the REST API request
     /// * `organization_name` - The name of the Azure DevOps organization
     ///
     /// # Returns
@@ -105,51 +392,750 @@ pub trait AzureDevOpsRepository: Send + Sync {
     ///
     /// # Errors
     ///
-    /// Any errors that might occur
+    /// Any errors that might occur, e.g. when any of the projects can not be listed
     async fn get_repositories_in_organization(
         &self,
-        pat: &str,
+        credential: &Credential,
         organization_name: &str,
     ) -> Result<Vec<GitRepository>>;
-}
 
-/// Must be implemented by repositories that manage secrets
-pub trait SecretRepository: Send + Sync {
-    /// Retrieve a secret by key
+    /// Get a single git repository, which also verifies that it can
+    /// be accessed with the credential
     ///
     /// # Arguments
     ///
-    /// * `key` - The key for the secret
+    /// * `credential` - The credential to authenticate the REST API request
+    /// * `context` - The context of the git repository in the form of `organization/project`
+    /// * `repository_name` - The name of the git repository
     ///
     /// # Returns
     ///
-    /// * `Result<String>` - The value of the secret
+    /// * `Result<GitRepository>` - The retrieved git repository
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the git repository can not be accessed
+    async fn get_repository(
+        &self,
+        credential: &Credential,
+        context: &str,
+        repository_name: &str,
+    ) -> Result<GitRepository>;
+
+    /// Validate that the credential is able to read the projects and pull
+    /// requests of the Azure DevOps organization
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - The credential to validate
+    /// * `organization_name` - The name of the Azure DevOps organization
+    ///
+    /// # Errors
+    ///
+    /// If the credential is invalid, expired or lacks the required scopes
+    async fn validate_credential(
+        &self,
+        credential: &Credential,
+        organization_name: &str,
+    ) -> Result<()>;
+
+    /// Get the unique identifier of the user the PAT belongs to
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - The credential to authenticate the REST API request
+    /// * `organization_name` - The name of the Azure DevOps organization
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String>` - The unique identifier of the authenticated user
     ///
     /// # Errors
     ///
     /// Any errors that might occur
-    fn get_secret(&self, key: &str) -> Result<String>;
+    async fn get_authenticated_user_id(
+        &self,
+        credential: &Credential,
+        organization_name: &str,
+    ) -> Result<String>;
 
-    /// Store a secret
+    /// Get the status of a single pull request, e.g. to find out how a pull
+    /// request that is no longer open was closed
     ///
     /// # Arguments
     ///
-    /// * `key` - The key that later can be used to access the secret
-    /// * `value` - The secret itself
+    /// * `credential` - The credential to authenticate the REST API request
+    /// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
+    /// * `repository_name` - The name of the git repository the pull request belongs to
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(PullRequestStatus, Option<DateTime<Utc>>)>` - The status and
+    ///   the date the pull request was closed, if it is no longer active
     ///
     /// # Errors
     ///
     /// Any errors that might occur
-    fn set_secret(&self, key: &str, value: &str) -> Result<()>;
+    async fn get_pull_request_status(
+        &self,
+        credential: &Credential,
+        context: &str,
+        repository_name: &str,
+        pull_request_id: &u32,
+    ) -> Result<(PullRequestStatus, Option<DateTime<Utc>>)>;
+}
 
-    /// Deletes a secret
+/// Must be implemented by repositories responsible
+/// for accessing the locally tracked reviews of pull requests
+#[async_trait]
+pub trait PullRequestReviewRepository: Send + Sync {
+    /// Get all tracked pull request reviews
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestReview>>` - The list of tracked reviews
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_reviews(&self) -> Result<Vec<PullRequestReview>>;
+
+    /// Get the tracked review of a single pull request
     ///
     /// # Arguments
     ///
-    /// * `key` - The key of the secret
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<PullRequestReview>>` - The tracked review, if any
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_review(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<Option<PullRequestReview>>;
+
+    /// Creates or replaces the tracked review of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_review` - The model of the review
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn save_pull_request_review(&self, pull_request_review: PullRequestReview) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the locally tracked read state of pull requests
+#[async_trait]
+pub trait PullRequestStateRepository: Send + Sync {
+    /// Get the read state of all pull requests that were seen before
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestState>>` - The list of tracked read states
     ///
     /// # Errors
     ///
     /// Any errors that might occur
-    fn delete_secret(&self, key: &str) -> Result<()>;
+    async fn get_pull_request_states(&self) -> Result<Vec<PullRequestState>>;
+
+    /// Creates or replaces the read state of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_state` - The model of the read state
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn save_pull_request_state(&self, pull_request_state: PullRequestState) -> Result<()>;
+
+    /// Deletes the read state of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_pull_request_state(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the pin and snooze state of pull requests
+#[async_trait]
+pub trait PullRequestTriageRepository: Send + Sync {
+    /// Get the pin and snooze state of all pull requests that have one
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestTriage>>` - The list of pin and snooze states
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_triages(&self) -> Result<Vec<PullRequestTriage>>;
+
+    /// Get the pin and snooze state of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<PullRequestTriage>>` - The pin and snooze state, if any
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_triage(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<Option<PullRequestTriage>>;
+
+    /// Creates or replaces the pin and snooze state of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_triage` - The model of the pin and snooze state
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn save_pull_request_triage(&self, pull_request_triage: PullRequestTriage) -> Result<()>;
+
+    /// Deletes the pin and snooze state of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_pull_request_triage(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the notes and local tags of pull requests
+#[async_trait]
+pub trait PullRequestAnnotationRepository: Send + Sync {
+    /// Get the notes and tags of all annotated pull requests
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestAnnotation>>` - The list of annotations
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_annotations(&self) -> Result<Vec<PullRequestAnnotation>>;
+
+    /// Creates or replaces the notes and tags of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_annotation` - The model of the annotation
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn save_pull_request_annotation(
+        &self,
+        pull_request_annotation: PullRequestAnnotation,
+    ) -> Result<()>;
+
+    /// Deletes the notes and tags of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_id` - The unique identifier of the imported git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_pull_request_annotation(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<()>;
+
+    /// Deletes the notes and tags of all pull requests that were closed
+    /// before the given date
+    ///
+    /// # Arguments
+    ///
+    /// * `closed_before` - The date pull requests must have been closed before
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u64>` - The number of deleted annotations
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_closed_pull_request_annotations(
+        &self,
+        closed_before: &DateTime<Utc>,
+    ) -> Result<u64>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the recorded lifecycles of pull requests
+#[async_trait]
+pub trait PullRequestLifecycleRepository: Send + Sync {
+    /// Get the lifecycles of the recorded pull requests of multiple git repositories
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository_ids` - The unique identifiers of the git repositories
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestLifecycle>>` - The list of lifecycles
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_lifecycles(
+        &self,
+        git_repository_ids: &[u32],
+    ) -> Result<Vec<PullRequestLifecycle>>;
+
+    /// Get the lifecycles of the pull requests created or closed within a date range,
+    /// including the ones of removed git repositories
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The inclusive start of the date range
+    /// * `to` - The exclusive end of the date range
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<PullRequestLifecycle>>` - The list of lifecycles
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_pull_request_lifecycles_in_range(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<PullRequestLifecycle>>;
+
+    /// Creates or replaces the lifecycles of multiple pull requests at once
+    ///
+    /// # Arguments
+    ///
+    /// * `pull_request_lifecycles` - The models of the lifecycles
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, in which case none of the lifecycles is saved
+    async fn save_pull_request_lifecycles(
+        &self,
+        pull_request_lifecycles: Vec<PullRequestLifecycle>,
+    ) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the SLA rules and the holidays they are evaluated with
+#[async_trait]
+pub trait SlaRuleRepository: Send + Sync {
+    /// Get all configured SLA rules
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<SlaRule>>` - The list of SLA rules
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_sla_rules(&self) -> Result<Vec<SlaRule>>;
+
+    /// Creates a single SLA rule
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule` - The model of the SLA rule, its identifier is ignored
+    ///
+    /// # Returns
+    ///
+    /// * `Result<u32>` - The unique identifier of the created SLA rule
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn create_sla_rule(&self, sla_rule: SlaRule) -> Result<u32>;
+
+    /// Updates a single SLA rule
+    ///
+    /// # Arguments
+    ///
+    /// * `sla_rule` - The model of the SLA rule
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the SLA rule does not exist
+    async fn update_sla_rule(&self, sla_rule: SlaRule) -> Result<()>;
+
+    /// Deletes a single SLA rule
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the SLA rule
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_sla_rule(&self, id: &u32) -> Result<()>;
+
+    /// Get all holidays, ordered by their date
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Holiday>>` - The list of holidays
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_holidays(&self) -> Result<Vec<Holiday>>;
+
+    /// Replaces all holidays
+    ///
+    /// # Arguments
+    ///
+    /// * `holidays` - The models of the holidays
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, in which case the holidays stay unchanged
+    async fn save_holidays(&self, holidays: Vec<Holiday>) -> Result<()>;
+}
+
+/// Must be implemented by repositories that manage secrets
+#[async_trait]
+pub trait SecretRepository: Send + Sync {
+    /// Retrieve a secret by key
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key for the secret
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String>` - The value of the secret
+    ///
+    /// # Errors
+    ///
+    /// A `SecretNotFoundError` if no secret is stored for the key, or any
+    /// other error that might occur while accessing the secrets
+    async fn get_secret(&self, key: &str) -> Result<String>;
+
+    /// Retrieve the keys of all secrets stored by the application
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<String>>` - The keys, empty for sources the application
+    ///   does not store secrets in
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_secret_keys(&self) -> Result<Vec<String>>;
+
+    /// Store a secret
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key that later can be used to access the secret
+    /// * `value` - The secret itself
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn set_secret(&self, key: &str, value: &str) -> Result<()>;
+
+    /// Deletes a secret
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the secret
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn delete_secret(&self, key: &str) -> Result<()>;
+}
+
+#[async_trait]
+impl<T> SecretRepository for std::sync::Arc<T>
+where
+    T: SecretRepository + ?Sized,
+{
+    async fn get_secret(&self, key: &str) -> Result<String> {
+        self.as_ref().get_secret(key).await
+    }
+
+    async fn get_secret_keys(&self) -> Result<Vec<String>> {
+        self.as_ref().get_secret_keys().await
+    }
+
+    async fn set_secret(&self, key: &str, value: &str) -> Result<()> {
+        self.as_ref().set_secret(key, value).await
+    }
+
+    async fn delete_secret(&self, key: &str) -> Result<()> {
+        self.as_ref().delete_secret(key).await
+    }
+}
+
+/// Must be implemented by repositories that manage secrets encrypted
+/// with a passphrase, which needs to be provided before accessing them
+#[async_trait]
+pub trait LockableSecretRepository: SecretRepository {
+    /// Unlock the secrets with a passphrase, which is kept in memory
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The passphrase the secrets are encrypted with
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the passphrase is wrong
+    async fn unlock(&self, passphrase: &str) -> Result<()>;
+}
+
+/// Must be implemented by ciphers that encrypt data with a key derived
+/// from a passphrase, independent of any secret storage
+#[async_trait]
+pub trait PassphraseCipher: Send + Sync {
+    /// Encrypt data with a key derived from the passphrase
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The passphrase to derive the key from
+    /// * `plaintext` - The data to encrypt
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PassphraseEncryptedData>` - The encrypted data with everything needed to decrypt it again
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn encrypt(&self, passphrase: &str, plaintext: &[u8]) -> Result<PassphraseEncryptedData>;
+
+    /// Decrypt data encrypted with a key derived from the passphrase
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The passphrase the data was encrypted with
+    /// * `data` - The encrypted data
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>>` - The decrypted data
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the passphrase is wrong
+    async fn decrypt(&self, passphrase: &str, data: &PassphraseEncryptedData) -> Result<Vec<u8>>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the application settings
+#[async_trait]
+pub trait SettingsRepository: Send + Sync {
+    /// Get the current settings
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Settings>` - The current settings, the defaults if none were saved yet
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_settings(&self) -> Result<Settings>;
+
+    /// Save the settings, replacing the current ones
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The settings to save
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn save_settings(&self, settings: Settings) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for accessing the profiles
+#[async_trait]
+pub trait ProfileRepository: Send + Sync {
+    /// Get all profiles
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Profile>>` - All profiles, including the default one
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_profiles(&self) -> Result<Vec<Profile>>;
+
+    /// Get the profile that is currently active
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Profile>` - The active profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_active_profile(&self) -> Result<Profile>;
+
+    /// Create a new profile
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the profile
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Profile>` - The created profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn create_profile(&self, name: &str) -> Result<Profile>;
+
+    /// Rename a profile
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the profile
+    /// * `name` - The new name of the profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the profile does not exist
+    async fn rename_profile(&self, id: &str, name: &str) -> Result<()>;
+
+    /// Delete a profile including its data and its stored secrets
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the profile does not exist
+    async fn delete_profile(&self, id: &str) -> Result<()>;
+
+    /// Remember the profile to activate on the next start
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the profile
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the profile does not exist
+    async fn set_active_profile(&self, id: &str) -> Result<()>;
+}
+
+/// Must be implemented by whatever holds the dependencies bound to a profile,
+/// such as the database connection, to switch between profiles at runtime
+#[async_trait]
+pub trait ProfileActivator: Send + Sync {
+    /// Replace the dependencies of the current profile with the ones of another one
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile to activate
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the database can not be opened,
+    /// in which case the current profile stays active
+    async fn activate_profile(&self, profile: &Profile) -> Result<()>;
+}
+
+/// Must be implemented by repositories responsible
+/// for backing up and restoring the database
+#[async_trait]
+pub trait DatabaseBackupRepository: Send + Sync {
+    /// Get the version of the schema of the database
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i64>` - The version of the latest applied migration
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn get_schema_version(&self) -> Result<i64>;
+
+    /// Validate a backup and get the version of its schema
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the backup file
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i64>` - The version of the latest migration applied to the backup
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file is no valid backup
+    async fn get_backup_schema_version(&self, file_path: &str) -> Result<i64>;
+
+    /// Back up the database to a new file, while it is in use
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the backup file, which must not exist yet
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    async fn create_backup(&self, file_path: &str) -> Result<()>;
+
+    /// Replace the content of the database with the one of a backup
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the backup file
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, in which case the database is left unchanged
+    async fn restore_backup(&self, file_path: &str) -> Result<()>;
 }
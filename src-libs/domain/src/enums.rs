@@ -3,3 +3,75 @@
 pub enum GitProvider {
     AzureDevOps,
 }
+
+/// Supported kinds of credentials to authenticate against a git provider
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CredentialType {
+    /// A personal access token (PAT)
+    Pat,
+    /// An OAuth refresh token, acquired by signing in interactively
+    OAuth,
+}
+
+/// Supported backends to store secrets in
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SecretStorageBackend {
+    /// The keyring of the operating system, e.g. the Secret Service on Linux
+    #[default]
+    Keyring,
+    /// A local file encrypted with a key derived from a passphrase
+    EncryptedFile,
+}
+
+/// Sources secrets are looked up in, in the configured order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SecretSource {
+    /// Environment variables, named after the key of the secret
+    Environment,
+    /// An external command, such as `pass` or the 1Password CLI
+    CredentialHelper,
+    /// The selected secret storage backend, the only one secrets are stored in
+    Storage,
+}
+
+/// The outcome of a single check that must be fulfilled before a pull
+/// request can be completed, e.g. a build validation or a branch policy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckStatus {
+    Passed,
+    Failed,
+    Pending,
+    NotApplicable,
+}
+
+/// The vote a reviewer cast on a pull request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReviewerVote {
+    Approved,
+    ApprovedWithSuggestions,
+    NoVote,
+    WaitingForAuthor,
+    Rejected,
+}
+
+/// The status of a pull request over its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PullRequestStatus {
+    /// The pull request is open
+    Active,
+    /// The pull request was merged
+    Completed,
+    /// The pull request was closed without being merged
+    Abandoned,
+}
+
+/// The kinds of service level agreements pull requests are checked against
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlaRuleKind {
+    /// No reviewer voted since the pull request was created or the votes were reset
+    NoReviewerVote,
+    /// No push or comment since the latest activity
+    NoUpdate,
+    /// A comment thread is unresolved since it was started
+    UnresolvedComments,
+}
@@ -0,0 +1,31 @@
+/// The error returned when no secret is stored for a key, to tell missing
+/// secrets apart from secret storages that can not be accessed
+#[derive(Debug)]
+pub struct SecretNotFoundError {
+    /// The key of the requested secret
+    pub key: String,
+}
+
+impl std::fmt::Display for SecretNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No secret stored for key `{}`", self.key)
+    }
+}
+
+impl std::error::Error for SecretNotFoundError {}
+
+/// The error returned when a repository group does not exist, to tell it
+/// apart from failing database accesses
+#[derive(Debug)]
+pub struct RepositoryGroupNotFoundError {
+    /// The unique identifier of the requested repository group
+    pub id: u32,
+}
+
+impl std::fmt::Display for RepositoryGroupNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The repository group `{}` does not exist", self.id)
+    }
+}
+
+impl std::error::Error for RepositoryGroupNotFoundError {}
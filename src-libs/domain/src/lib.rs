@@ -1,2 +1,3 @@
 pub mod enums;
+pub mod errors;
 pub mod models;
@@ -1,8 +1,11 @@
-use super::enums::GitProvider;
-use chrono::{DateTime, Utc};
+use super::enums::{
+    CheckStatus, CredentialType, GitProvider, PullRequestStatus, ReviewerVote, SecretSource,
+    SecretStorageBackend, SlaRuleKind,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 
 /// Represents a single git repository
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GitRepository {
     /// The unique identifier
     pub id: u32,
@@ -17,30 +20,564 @@ pub struct GitRepository {
     /// When marked as active, the repository will be included when
     /// querying data to fulfill the applications purpose
     pub is_active: bool,
-    /// The key to retrieve the PAT to use for this git repository
+    /// The kind of credential to authenticate with, shared by all git
+    /// repositories of the same organization
+    pub credential_type: CredentialType,
+    /// The key to retrieve the credential to use for this git repository
     /// from the secret storage
-    pub pat_secret_key: String,
+    pub credential_key: String,
+    /// The stable identifier of the repository at the git provider, that
+    /// is kept when the repository is renamed or moved
+    pub remote_id: Option<String>,
+    /// When marked as orphaned, the repository no longer exists on the
+    /// remote, but is kept to not lose its local state
+    pub is_orphaned: bool,
+}
+
+impl GitRepository {
+    /// Check whether this and another git repository refer to the same
+    /// repository on the remote
+    ///
+    /// The stable remote identifier is compared if both are known, as name
+    /// and context change when the repository is renamed or moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The git repository to compare with
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether both refer to the same repository
+    pub fn is_same_remote(&self, other: &GitRepository) -> bool {
+        match (&self.remote_id, &other.remote_id) {
+            (Some(remote_id), Some(other_remote_id)) => remote_id == other_remote_id,
+            _ => self.name == other.name && self.context == other.context,
+        }
+    }
+}
+
+/// A credential to authenticate against a git provider
+#[derive(Debug, Clone)]
+pub enum Credential {
+    /// A personal access token (PAT)
+    Pat(String),
+    /// The key of an OAuth refresh token in the secret storage, which is
+    /// exchanged for short-lived access tokens when needed
+    OAuth(String),
+}
+
+impl Credential {
+    /// Create the credential of a git repository
+    ///
+    /// # Arguments
+    ///
+    /// * `git_repository` - The git repository to create the credential for
+    /// * `pat` - Provides the PAT from the secret storage, only called for PAT credentials
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur while getting the PAT
+    pub fn for_git_repository<F, E>(git_repository: &GitRepository, pat: F) -> Result<Self, E>
+    where
+        F: FnOnce(&str) -> Result<String, E>,
+    {
+        match git_repository.credential_type {
+            CredentialType::Pat => Ok(Credential::Pat(pat(&git_repository.credential_key)?)),
+            CredentialType::OAuth => {
+                Ok(Credential::OAuth(git_repository.credential_key.to_string()))
+            }
+        }
+    }
+}
+
+/// The challenge of an OAuth device code sign in, the user needs to
+/// complete in the browser
+#[derive(Debug, Clone)]
+pub struct DeviceCodeChallenge {
+    /// The code to poll the sign in result with
+    pub device_code: String,
+    /// The code the user needs to enter in the browser
+    pub user_code: String,
+    /// The URL the user needs to open
+    pub verification_uri: String,
+    /// The instructions to show to the user
+    pub message: String,
+    /// When the challenge expires
+    pub expires_at: DateTime<Utc>,
+    /// The minimum number of seconds to wait between polls
+    pub interval_seconds: u64,
+}
+
+/// The tokens issued by an OAuth token endpoint
+#[derive(Debug, Clone)]
+pub struct OAuthTokens {
+    /// The short-lived token to authorize requests with
+    pub access_token: String,
+    /// The long-lived token to get new access tokens with, if issued
+    pub refresh_token: Option<String>,
+    /// When the access token expires
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Metadata of a personal access token (PAT) stored in the secret storage
+#[derive(Debug, Clone)]
+pub struct PatMetadata {
+    /// The key the PAT is stored with in the secret storage
+    pub credential_key: String,
+    /// When the PAT expires, if known
+    pub expires_at: Option<DateTime<Utc>>,
+    /// When the PAT was successfully validated the last time
+    pub validated_at: Option<DateTime<Utc>>,
+}
+
+/// Data encrypted with a key derived from a passphrase, e.g. the secrets
+/// included in an exported configuration
+#[derive(Debug, Clone)]
+pub struct PassphraseEncryptedData {
+    /// The random salt to derive the key with, base64 encoded
+    pub salt: String,
+    /// The memory cost of the key derivation in KiB
+    pub memory_kib: u32,
+    /// The number of iterations of the key derivation
+    pub iterations: u32,
+    /// The degree of parallelism of the key derivation
+    pub parallelism: u32,
+    /// The nonce the data was encrypted with, base64 encoded
+    pub nonce: String,
+    /// The encrypted data, base64 encoded
+    pub ciphertext: String,
+}
+
+/// Represents a profile, e.g. for work or personal use, with its own
+/// database and secrets
+#[derive(Debug, Clone)]
+pub struct Profile {
+    /// The unique identifier, used to name the data of the profile
+    pub id: String,
+    /// The display name
+    pub name: String,
+}
+
+impl Profile {
+    /// The identifier of the profile that always exists and uses the data
+    /// created before profiles were introduced
+    pub const DEFAULT_ID: &str = "default";
+
+    /// Check whether this is the default profile
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether this is the default profile, which can not be removed
+    pub fn is_default(&self) -> bool {
+        self.id == Self::DEFAULT_ID
+    }
+}
+
+/// The settings of the application, that apply regardless of the imported
+/// git repositories
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The backend to store secrets, such as PATs, in
+    pub secret_storage_backend: SecretStorageBackend,
+    /// The sources to look up secrets in, the first one providing a secret wins.
+    /// The storage comes first by default, so entered secrets are not shadowed
+    pub secret_sources: Vec<SecretSource>,
+    /// The command to run to get a secret from an external credential helper,
+    /// the key of the secret is passed in the `PR_HUB_SECRET_KEY` environment variable
+    pub credential_helper_command: Option<String>,
+    /// How many days before their expiry stored PATs are warned about
+    pub pat_expiry_warning_days: u32,
+    /// The time of day business hours start at, in the business time zone
+    pub business_hours_start: NaiveTime,
+    /// The time of day business hours end at, in the business time zone
+    pub business_hours_end: NaiveTime,
+    /// The IANA name of the time zone business hours are evaluated in, e.g. `Europe/Berlin`
+    pub business_time_zone: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            secret_storage_backend: SecretStorageBackend::default(),
+            secret_sources: vec![SecretSource::Storage, SecretSource::Environment],
+            credential_helper_command: None,
+            pat_expiry_warning_days: 14,
+            business_hours_start: NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default(),
+            business_hours_end: NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default(),
+            business_time_zone: "UTC".to_string(),
+        }
+    }
+}
+
+/// Represents a named group of imported git repositories, e.g. the
+/// repositories a single team is responsible for
+#[derive(Debug, Clone)]
+pub struct RepositoryGroup {
+    /// The unique identifier
+    pub id: u32,
+    /// The group name
+    pub name: String,
+    /// The unique identifiers of the git repositories assigned to the group
+    pub git_repository_ids: Vec<u32>,
+}
+
+/// Represents the changes of a repository group imported from a configuration
+#[derive(Debug, Clone)]
+pub struct ImportedRepositoryGroup {
+    /// The unique identifier of the local group, none if it is created
+    pub id: Option<u32>,
+    /// The group name
+    pub name: String,
+    /// The git repositories to assign, identified by their remote as they
+    /// might be created by the same import
+    pub assigned_git_repositories: Vec<GitRepository>,
+    /// The unique identifiers of the local git repositories to unassign
+    pub unassigned_git_repository_ids: Vec<u32>,
 }
 
 /// Represents a single pull request within a git repository
 pub struct PullRequest {
     /// The unique identifier
     pub id: u32,
+    /// The unique identifier of the imported git repository the pull request belongs to
+    pub git_repository_id: u32,
+    /// The git provider where the pull request is stored
+    pub git_provider: GitProvider,
     /// The name of the associated git repository
     pub repository_name: String,
     /// The pull request title
     pub title: String,
     /// The label that describes the merge status
     pub merge_status: String,
+    /// The unique identifier of the user that created the pull request
+    pub creator_id: String,
     /// The display name of the user that created the pull request
     pub creator_name: String,
     /// The date of the pull request creation
     pub creation_date: DateTime<Utc>,
+    /// The date of the latest activity, such as a push or a comment
+    pub last_updated_date: DateTime<Utc>,
     /// The total number of reviewers comments
     pub number_of_comments: usize,
     /// The total number of closed comments
     /// (closed, resolved, etc.)
     pub number_of_closed_comments: usize,
+    /// The date of the first comment by someone other than the creator
+    pub first_comment_date: Option<DateTime<Utc>>,
+    /// The date the oldest comment thread that is still unresolved was started
+    pub oldest_unresolved_comment_date: Option<DateTime<Utc>>,
+    /// The web link to the pr
+    pub link: String,
+    /// The build, status and policy checks of the pull request
+    pub checks: PullRequestChecks,
+    /// The work items linked to the pull request
+    pub work_items: Vec<WorkItem>,
+    /// The size of the changes of the latest iteration, if it could be determined
+    pub change_statistics: Option<ChangeStatistics>,
+    /// The identifier of the latest iteration (push) of the pull request
+    pub latest_iteration_id: Option<u32>,
+    /// The date the latest iteration (push) of the pull request was created
+    pub latest_iteration_date: Option<DateTime<Utc>>,
+    /// All iterations (pushes) of the pull request
+    pub iterations: Vec<PullRequestIteration>,
+    /// The reviewers assigned to the pull request
+    pub reviewers: Vec<Reviewer>,
+    /// The activities on the pull request, such as pushes, comments and votes
+    pub activities: Vec<PullRequestActivity>,
+}
+
+/// Represents a single activity on a pull request
+#[derive(Debug, Clone)]
+pub struct PullRequestActivity {
+    /// The provider specific unique identifier of the user that caused the activity,
+    /// empty if unknown
+    pub author_id: String,
+    /// The date of the activity
+    pub date: DateTime<Utc>,
+    /// Whether the activity started a comment thread that counts as comment
+    pub is_new_comment: bool,
+}
+
+/// Represents a single iteration (push) of a pull request
+#[derive(Debug, Clone)]
+pub struct PullRequestIteration {
+    /// The identifier of the iteration, increasing with every push
+    pub id: u32,
+    /// The date the iteration was created
+    pub created_date: DateTime<Utc>,
+}
+
+/// Represents a single reviewer of a pull request
+#[derive(Debug, Clone)]
+pub struct Reviewer {
+    /// The provider specific unique identifier of the reviewer
+    pub id: String,
+    /// The display name of the reviewer
+    pub display_name: String,
+    /// The vote the reviewer cast
+    pub vote: ReviewerVote,
+    /// Whether the review of this reviewer is required to complete the pull request
+    pub is_required: bool,
+    /// The date the reviewer cast the vote last, if known
+    pub voted_at: Option<DateTime<Utc>>,
+}
+
+/// Represents the reviewers of a single open pull request, as available from
+/// listing the pull requests without retrieving the details of each one
+#[derive(Debug, Clone)]
+pub struct PullRequestReviewers {
+    /// The unique identifier of the pull request
+    pub id: u32,
+    /// The unique identifier of the imported git repository the pull request belongs to
+    pub git_repository_id: u32,
+    /// The pull request title
+    pub title: String,
     /// The web link to the pr
     pub link: String,
+    /// The date of the pull request creation
+    pub creation_date: DateTime<Utc>,
+    /// The reviewers assigned to the pull request, without the date of their vote
+    pub reviewers: Vec<Reviewer>,
+}
+
+/// Remembers the iteration of a pull request the current user reviewed last
+#[derive(Debug, Clone)]
+pub struct PullRequestReview {
+    /// The unique identifier of the imported git repository the pull request belongs to
+    pub git_repository_id: u32,
+    /// The unique identifier of the pull request
+    pub pull_request_id: u32,
+    /// The identifier of the reviewed iteration
+    pub iteration_id: u32,
+    /// The vote the current user cast when the iteration was reviewed
+    pub vote: ReviewerVote,
+    /// The date the iteration was reviewed
+    pub reviewed_at: DateTime<Utc>,
+}
+
+/// Describes the size of the changes a pull request introduces
+#[derive(Debug, Clone)]
+pub struct ChangeStatistics {
+    /// The identifier of the pull request iteration the statistics were computed for
+    pub iteration_id: u32,
+    /// The number of changed files
+    pub files_changed: usize,
+    /// The number of added lines, if the provider exposes it
+    pub lines_added: Option<usize>,
+    /// The number of deleted lines, if the provider exposes it
+    pub lines_deleted: Option<usize>,
+}
+
+/// Represents a single work item, such as a user story or bug, that
+/// can be linked to a pull request
+#[derive(Debug, Clone)]
+pub struct WorkItem {
+    /// The unique identifier
+    pub id: u32,
+    /// The name of the work item type, e.g. `Bug`
+    pub work_item_type: String,
+    /// The work item title
+    pub title: String,
+    /// The name of the current state, e.g. `Active`
+    pub state: String,
+}
+
+/// Represents a single check of a pull request, such as a build
+/// validation or a branch policy
+#[derive(Debug, Clone)]
+pub struct PullRequestCheck {
+    /// The display name of the check
+    pub name: String,
+    /// The outcome of the check
+    pub status: CheckStatus,
+    /// The web link to details of the check, if available
+    pub link: Option<String>,
+}
+
+/// The summary of all checks of a single pull request
+#[derive(Debug, Clone)]
+pub struct PullRequestChecks {
+    /// The combined outcome of all checks
+    pub status: CheckStatus,
+    /// The individual checks
+    pub items: Vec<PullRequestCheck>,
+}
+
+impl PullRequestChecks {
+    /// Create the summary for the given checks
+    ///
+    /// A single failed check fails the whole summary, otherwise a single pending
+    /// check keeps it pending. Only when neither applies and at least one check
+    /// passed, the summary is considered passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The individual checks of the pull request
+    pub fn new(items: Vec<PullRequestCheck>) -> Self {
+        let has_status = |status: CheckStatus| items.iter().any(|x| x.status == status);
+        let status = if has_status(CheckStatus::Failed) {
+            CheckStatus::Failed
+        } else if has_status(CheckStatus::Pending) {
+            CheckStatus::Pending
+        } else if has_status(CheckStatus::Passed) {
+            CheckStatus::Passed
+        } else {
+            CheckStatus::NotApplicable
+        };
+        Self { status, items }
+    }
+}
+
+/// The locally tracked read state of a single pull request
+#[derive(Debug, Clone)]
+pub struct PullRequestState {
+    /// The git provider where the pull request is stored
+    pub git_provider: GitProvider,
+    /// The unique identifier of the imported git repository the pull request belongs to
+    pub git_repository_id: u32,
+    /// The unique identifier of the pull request
+    pub pull_request_id: u32,
+    /// The date the pull request was last seen by the user
+    pub last_seen_at: DateTime<Utc>,
+    /// The total number of comments when the pull request was last seen
+    pub last_seen_comment_count: usize,
+}
+
+/// The locally stored pin and snooze state of a single pull request
+#[derive(Debug, Clone)]
+pub struct PullRequestTriage {
+    /// The git provider where the pull request is stored
+    pub git_provider: GitProvider,
+    /// The unique identifier of the imported git repository the pull request belongs to
+    pub git_repository_id: u32,
+    /// The unique identifier of the pull request
+    pub pull_request_id: u32,
+    /// When pinned, the pull request is always listed first
+    pub is_pinned: bool,
+    /// The date the pull request was snoozed, if it is snoozed
+    pub snoozed_at: Option<DateTime<Utc>>,
+    /// The date until the pull request is snoozed
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// Whether the pull request is snoozed until its next update
+    pub snoozed_until_next_update: bool,
+}
+
+impl PullRequestTriage {
+    /// Check whether the pull request is still snoozed
+    ///
+    /// A snoozed pull request wakes up as soon as any of its wake conditions is met,
+    /// that is the snooze date passed or the pull request was updated after snoozing.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current date
+    /// * `last_updated_date` - The date of the latest activity on the pull request
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the pull request should be hidden
+    pub fn is_snoozed(&self, now: DateTime<Utc>, last_updated_date: DateTime<Utc>) -> bool {
+        let Some(snoozed_at) = self.snoozed_at else {
+            return false;
+        };
+        let woke_by_date = self.snoozed_until.is_some_and(|x| x <= now);
+        let woke_by_update = self.snoozed_until_next_update && last_updated_date > snoozed_at;
+        !woke_by_date && !woke_by_update
+    }
+}
+
+/// Private notes and local tags the user attached to a single pull request
+#[derive(Debug, Clone)]
+pub struct PullRequestAnnotation {
+    /// The git provider where the pull request is stored
+    pub git_provider: GitProvider,
+    /// The unique identifier of the imported git repository the pull request belongs to
+    pub git_repository_id: u32,
+    /// The unique identifier of the pull request
+    pub pull_request_id: u32,
+    /// The free text note
+    pub note: Option<String>,
+    /// The free-form local tags
+    pub tags: Vec<String>,
+    /// The date the annotation was last changed
+    pub updated_at: DateTime<Utc>,
+    /// The date the pull request was first observed as no longer open
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// The milestones of a single pull request, recorded as the refreshes
+/// observe them to measure the review process
+#[derive(Debug, Clone)]
+pub struct PullRequestLifecycle {
+    /// The git provider where the pull request is stored
+    pub git_provider: GitProvider,
+    /// The unique identifier of the imported git repository the pull request
+    /// belongs to, none if the git repository was removed since
+    pub git_repository_id: Option<u32>,
+    /// The name of the git repository, kept when the git repository is removed
+    pub git_repository_name: String,
+    /// The unique identifier of the pull request
+    pub pull_request_id: u32,
+    /// The unique identifier of the user that created the pull request
+    pub creator_id: String,
+    /// The display name of the user that created the pull request
+    pub creator_name: String,
+    /// The latest known status
+    pub status: PullRequestStatus,
+    /// The date of the pull request creation
+    pub created_at: DateTime<Utc>,
+    /// The date a reviewer vote was first observed, as the provider does not
+    /// expose when a vote was cast
+    pub first_vote_at: Option<DateTime<Utc>>,
+    /// The date of the first comment by someone other than the creator
+    pub first_comment_at: Option<DateTime<Utc>>,
+    /// The date the pull request was completed or abandoned
+    pub closed_at: Option<DateTime<Utc>>,
+    /// The number of failed lookups of the status since the pull request
+    /// is no longer open
+    pub status_lookup_failure_count: u32,
+    /// The date of the latest failed lookup of the status
+    pub status_lookup_failed_at: Option<DateTime<Utc>>,
+}
+
+impl PullRequestLifecycle {
+    /// Get the date the pull request was first reviewed, either by a vote or a comment
+    ///
+    /// # Returns
+    ///
+    /// * `Option<DateTime<Utc>>` - The date of the first review, none if not reviewed yet
+    pub fn first_review_at(&self) -> Option<DateTime<Utc>> {
+        match (self.first_vote_at, self.first_comment_at) {
+            (Some(vote), Some(comment)) => Some(vote.min(comment)),
+            (vote, comment) => vote.or(comment),
+        }
+    }
+}
+
+/// A service level agreement open pull requests are expected to meet,
+/// e.g. a reviewer vote within 24 business hours
+#[derive(Debug, Clone)]
+pub struct SlaRule {
+    /// The unique identifier
+    pub id: u32,
+    /// The name describing the rule
+    pub name: String,
+    /// What is checked
+    pub kind: SlaRuleKind,
+    /// The number of hours after which the rule is violated
+    pub threshold_hours: u32,
+    /// Whether only business hours count towards the threshold, excluding the
+    /// time outside of the working day, weekends and holidays
+    pub is_business_hours: bool,
+    /// The repository group the rule is limited to, none if it applies globally
+    pub repository_group_id: Option<u32>,
+}
+
+/// A day that does not count towards business hours
+#[derive(Debug, Clone)]
+pub struct Holiday {
+    /// The date of the holiday
+    pub date: NaiveDate,
+    /// The name of the holiday, e.g. `New Year`
+    pub name: String,
 }
@@ -0,0 +1,163 @@
+use super::models::GitPullRequestIteration;
+use domain::models::ChangeStatistics;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// How long cached entries are kept without being used, so the ones of pull
+/// requests that were closed or abandoned are evicted
+const CACHE_ENTRY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A cached value and when it was used the last time
+struct CacheEntry<T> {
+    value: T,
+    last_used: Instant,
+}
+
+impl<T> CacheEntry<T> {
+    /// Create a new entry, that counts as used right now
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Check whether the entry was not used for longer than its time to live
+    fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.last_used) > CACHE_ENTRY_TTL
+    }
+}
+
+/// In-memory cache for the change statistics of pull request iterations
+///
+/// The changes of a pushed iteration never change, therefore the statistics
+/// only need to be computed once per iteration and can be shared across queries.
+/// Entries not used for a day are evicted, once new statistics are stored.
+#[derive(Default)]
+pub struct ChangeStatisticsCache {
+    entries: Mutex<HashMap<(String, u32, u32), CacheEntry<ChangeStatistics>>>,
+}
+
+impl ChangeStatisticsCache {
+    /// Get the cached statistics of a single pull request iteration
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The provider specific identifier of the git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `iteration_id` - The identifier of the pull request iteration
+    ///
+    /// # Returns
+    ///
+    /// * `Option<ChangeStatistics>` - The cached statistics, if any
+    pub fn get(
+        &self,
+        repository_id: &str,
+        pull_request_id: u32,
+        iteration_id: u32,
+    ) -> Option<ChangeStatistics> {
+        let mut entries = self.entries.lock().ok()?;
+        let entry = entries.get_mut(&(repository_id.to_string(), pull_request_id, iteration_id))?;
+        entry.last_used = Instant::now();
+        Some(entry.value.clone())
+    }
+
+    /// Store the statistics of a single pull request iteration
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The provider specific identifier of the git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `statistics` - The statistics to cache, including the iteration identifier
+    pub fn insert(&self, repository_id: &str, pull_request_id: u32, statistics: ChangeStatistics) {
+        if let Ok(mut entries) = self.entries.lock() {
+            // Older iterations of the same pull request are never requested again
+            let now = Instant::now();
+            entries.retain(|(r, p, _), x| {
+                (r != repository_id || *p != pull_request_id) && !x.is_expired(now)
+            });
+            entries.insert(
+                (
+                    repository_id.to_string(),
+                    pull_request_id,
+                    statistics.iteration_id,
+                ),
+                CacheEntry::new(statistics),
+            );
+        }
+    }
+}
+
+/// In-memory cache for the iterations of pull requests
+///
+/// A new iteration is only created by a push to the source branch, therefore the
+/// iterations are reused as long as the last merge source commit stays the same.
+/// Entries not used for a day are evicted, once new iterations are stored.
+#[derive(Default)]
+pub struct PullRequestIterationsCache {
+    entries: Mutex<HashMap<(String, u32), CacheEntry<CachedIterations>>>,
+}
+
+/// The iterations of a pull request at a certain source commit
+struct CachedIterations {
+    source_commit_id: String,
+    iterations: Vec<GitPullRequestIteration>,
+}
+
+impl PullRequestIterationsCache {
+    /// Get the cached iterations of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The provider specific identifier of the git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `source_commit_id` - The identifier of the last merge source commit of the pull request
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Vec<GitPullRequestIteration>>` - The cached iterations, if any
+    pub fn get(
+        &self,
+        repository_id: &str,
+        pull_request_id: u32,
+        source_commit_id: &str,
+    ) -> Option<Vec<GitPullRequestIteration>> {
+        let mut entries = self.entries.lock().ok()?;
+        let entry = entries
+            .get_mut(&(repository_id.to_string(), pull_request_id))
+            .filter(|x| x.value.source_commit_id == source_commit_id)?;
+        entry.last_used = Instant::now();
+        Some(entry.value.iterations.clone())
+    }
+
+    /// Store the iterations of a single pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `repository_id` - The provider specific identifier of the git repository
+    /// * `pull_request_id` - The unique identifier of the pull request
+    /// * `source_commit_id` - The identifier of the last merge source commit of the pull request
+    /// * `iterations` - The iterations to cache
+    pub fn insert(
+        &self,
+        repository_id: &str,
+        pull_request_id: u32,
+        source_commit_id: &str,
+        iterations: Vec<GitPullRequestIteration>,
+    ) {
+        if let Ok(mut entries) = self.entries.lock() {
+            let now = Instant::now();
+            entries.retain(|_, x| !x.is_expired(now));
+            entries.insert(
+                (repository_id.to_string(), pull_request_id),
+                CacheEntry::new(CachedIterations {
+                    source_commit_id: source_commit_id.to_string(),
+                    iterations,
+                }),
+            );
+        }
+    }
+}
@@ -33,3 +33,53 @@ pub enum PullRequestCommentThreadStatus {
     #[serde(rename = "wontFix")]
     WontFix,
 }
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-statuses/list?view=azure-devops-rest-6.0&tabs=HTTP#gitstatusstate
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default)]
+pub enum GitStatusState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(rename = "notApplicable")]
+    NotApplicable,
+    #[serde(rename = "notSet")]
+    #[default]
+    NotSet,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "succeeded")]
+    Succeeded,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/policy/evaluations/list?view=azure-devops-rest-6.0&tabs=HTTP#policyevaluationstatus
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default)]
+pub enum PolicyEvaluationStatus {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "broken")]
+    Broken,
+    #[serde(rename = "notApplicable")]
+    #[default]
+    NotApplicable,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[serde(rename = "running")]
+    Running,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/file-diffs/get-file-diffs?view=azure-devops-rest-6.0&tabs=HTTP#linediffblockchangetype
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default)]
+pub enum LineDiffBlockChangeType {
+    #[serde(rename = "add")]
+    Add,
+    #[serde(rename = "delete")]
+    Delete,
+    #[serde(rename = "edit")]
+    Edit,
+    #[serde(rename = "none")]
+    #[default]
+    None,
+}
@@ -1,4 +1,7 @@
-use super::enums::{CommentType, PullRequestCommentThreadStatus};
+use super::enums::{
+    CommentType, GitStatusState, LineDiffBlockChangeType, PolicyEvaluationStatus,
+    PullRequestCommentThreadStatus,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -6,12 +9,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(default)]
 pub struct Comment {
+    pub author: IdentityRef,
     #[serde(rename = "commentType")]
     pub comment_type: CommentType,
+    #[serde(rename = "lastUpdatedDate")]
+    pub last_updated_date: Option<DateTime<Utc>>,
+    #[serde(rename = "publishedDate")]
+    pub published_date: Option<DateTime<Utc>>,
 }
 
 /// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-requests/get-pull-requests?view=azure-devops-rest-6.0&tabs=HTTP#identityref
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(default)]
 pub struct IdentityRef {
     #[serde(rename = "displayName")]
@@ -19,12 +27,33 @@ pub struct IdentityRef {
     pub id: String,
 }
 
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-requests/get-pull-requests?view=azure-devops-rest-6.0&tabs=HTTP#identityrefwithvote
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct IdentityRefWithVote {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub id: String,
+    #[serde(rename = "isRequired")]
+    pub is_required: bool,
+    pub vote: i32,
+}
+
+/// The subset of the connection data that identifies the authenticated user
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ConnectionData {
+    #[serde(rename = "authenticatedUser")]
+    pub authenticated_user: IdentityRef,
+}
+
 /// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-requests/get-pull-requests?view=azure-devops-rest-6.0&tabs=HTTP#gitrepository
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(default)]
 pub struct GitRepository {
     pub id: String,
     pub name: String,
+    pub project: TeamProjectReference,
 }
 
 /// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-requests/get-pull-requests?view=azure-devops-rest-6.0&tabs=HTTP#webapitagdefinition
@@ -39,6 +68,7 @@ pub struct WebApiTagDefinition {
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(default)]
 pub struct TeamProjectReference {
+    pub id: String,
     pub name: String,
 }
 
@@ -46,17 +76,21 @@ pub struct TeamProjectReference {
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(default)]
 pub struct PullRequest {
+    #[serde(rename = "closedDate")]
+    pub closed_date: Option<DateTime<Utc>>,
     #[serde(rename = "createdBy")]
     pub created_by: IdentityRef,
     #[serde(rename = "creationDate")]
     pub creation_date: DateTime<Utc>,
     pub labels: Vec<WebApiTagDefinition>,
+    #[serde(rename = "lastMergeSourceCommit")]
+    pub last_merge_source_commit: Option<GitCommitRef>,
     #[serde(rename = "mergeStatus")]
     pub merge_status: String,
     #[serde(rename = "pullRequestId")]
     pub pull_request_id: u32,
     pub repository: GitRepository,
-    pub reviewers: Vec<IdentityRef>,
+    pub reviewers: Vec<IdentityRefWithVote>,
     pub status: String,
     pub title: String,
 }
@@ -66,9 +100,228 @@ pub struct PullRequest {
 #[serde(default)]
 pub struct PullRequestCommentThread {
     pub comments: Vec<Comment>,
+    #[serde(rename = "lastUpdatedDate")]
+    pub last_updated_date: Option<DateTime<Utc>>,
+    pub properties: PullRequestCommentThreadProperties,
+    #[serde(rename = "publishedDate")]
+    pub published_date: Option<DateTime<Utc>>,
     pub status: PullRequestCommentThreadStatus,
 }
 
+/// The subset of the properties of a comment thread that identifies system threads,
+/// see https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-threads/list?view=azure-devops-rest-6.0&tabs=HTTP#propertiescollection
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PullRequestCommentThreadProperties {
+    #[serde(rename = "CodeReviewThreadType")]
+    pub code_review_thread_type: Option<PropertyValue>,
+}
+
+/// A single value of a properties collection
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PropertyValue {
+    #[serde(rename = "$value")]
+    pub value: String,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-statuses/list?view=azure-devops-rest-6.0&tabs=HTTP#gitstatuscontext
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct GitStatusContext {
+    pub genre: String,
+    pub name: String,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-statuses/list?view=azure-devops-rest-6.0&tabs=HTTP#gitpullrequeststatus
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct GitPullRequestStatus {
+    pub context: GitStatusContext,
+    pub description: String,
+    pub id: u32,
+    pub state: GitStatusState,
+    #[serde(rename = "targetUrl")]
+    pub target_url: Option<String>,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/policy/evaluations/list?view=azure-devops-rest-6.0&tabs=HTTP#policytyperef
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PolicyTypeRef {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub id: String,
+}
+
+/// The subset of the type specific policy settings that is relevant to
+/// describe a policy
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PolicySettings {
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/policy/evaluations/list?view=azure-devops-rest-6.0&tabs=HTTP#policyconfiguration
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PolicyConfiguration {
+    #[serde(rename = "isBlocking")]
+    pub is_blocking: bool,
+    #[serde(rename = "isEnabled")]
+    pub is_enabled: bool,
+    pub settings: PolicySettings,
+    #[serde(rename = "type")]
+    pub policy_type: PolicyTypeRef,
+}
+
+/// The subset of the type specific evaluation context that is relevant to
+/// link a policy evaluation
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PolicyEvaluationContext {
+    #[serde(rename = "buildId")]
+    pub build_id: Option<u32>,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/policy/evaluations/list?view=azure-devops-rest-6.0&tabs=HTTP#policyevaluationrecord
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct PolicyEvaluationRecord {
+    pub configuration: PolicyConfiguration,
+    pub context: Option<PolicyEvaluationContext>,
+    pub status: PolicyEvaluationStatus,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-work-items/list?view=azure-devops-rest-6.0&tabs=HTTP#resourceref
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ResourceRef {
+    pub id: String,
+    pub url: String,
+}
+
+/// The subset of the work item fields that describe a work item
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct WorkItemFields {
+    #[serde(rename = "System.WorkItemType")]
+    pub work_item_type: String,
+    #[serde(rename = "System.Title")]
+    pub title: String,
+    #[serde(rename = "System.State")]
+    pub state: String,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/wit/work-items/list?view=azure-devops-rest-6.0&tabs=HTTP#workitem
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct WorkItem {
+    pub fields: WorkItemFields,
+    pub id: u32,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-iterations/list?view=azure-devops-rest-6.0&tabs=HTTP#gitcommitref
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct GitCommitRef {
+    #[serde(rename = "commitId")]
+    pub commit_id: String,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-iterations/list?view=azure-devops-rest-6.0&tabs=HTTP#gitpullrequestiteration
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct GitPullRequestIteration {
+    pub author: IdentityRef,
+    #[serde(rename = "commonRefCommit")]
+    pub common_ref_commit: Option<GitCommitRef>,
+    #[serde(rename = "createdDate")]
+    pub created_date: DateTime<Utc>,
+    pub id: u32,
+    #[serde(rename = "sourceRefCommit")]
+    pub source_ref_commit: Option<GitCommitRef>,
+    #[serde(rename = "targetRefCommit")]
+    pub target_ref_commit: Option<GitCommitRef>,
+    #[serde(rename = "updatedDate")]
+    pub updated_date: DateTime<Utc>,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-iteration-changes/get?view=azure-devops-rest-6.0&tabs=HTTP#gititem
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct GitItem {
+    #[serde(rename = "gitObjectType")]
+    pub git_object_type: String,
+    pub path: String,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-iteration-changes/get?view=azure-devops-rest-6.0&tabs=HTTP#gitpullrequestchange
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct GitPullRequestChange {
+    #[serde(rename = "changeType")]
+    pub change_type: String,
+    pub item: GitItem,
+    #[serde(rename = "originalPath")]
+    pub original_path: Option<String>,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/pull-request-iteration-changes/get?view=azure-devops-rest-6.0&tabs=HTTP#gitpullrequestiterationchanges
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct GitPullRequestIterationChanges {
+    #[serde(rename = "changeEntries")]
+    pub change_entries: Vec<GitPullRequestChange>,
+    #[serde(rename = "nextSkip")]
+    pub next_skip: u32,
+    #[serde(rename = "nextTop")]
+    pub next_top: u32,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/file-diffs/get-file-diffs?view=azure-devops-rest-6.0&tabs=HTTP#filediffparams
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct FileDiffParams {
+    #[serde(rename = "originalPath")]
+    pub original_path: Option<String>,
+    pub path: Option<String>,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/file-diffs/get-file-diffs?view=azure-devops-rest-6.0&tabs=HTTP#filediffscriteria
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct FileDiffsCriteria {
+    #[serde(rename = "baseVersionCommit")]
+    pub base_version_commit: String,
+    #[serde(rename = "fileDiffParams")]
+    pub file_diff_params: Vec<FileDiffParams>,
+    #[serde(rename = "targetVersionCommit")]
+    pub target_version_commit: String,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/file-diffs/get-file-diffs?view=azure-devops-rest-6.0&tabs=HTTP#linediffblock
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct LineDiffBlock {
+    #[serde(rename = "changeType")]
+    pub change_type: LineDiffBlockChangeType,
+    #[serde(rename = "modifiedLinesCount")]
+    pub modified_lines_count: usize,
+    #[serde(rename = "originalLinesCount")]
+    pub original_lines_count: usize,
+}
+
+/// https://learn.microsoft.com/en-us/rest/api/azure/devops/git/file-diffs/get-file-diffs?view=azure-devops-rest-6.0&tabs=HTTP#filediff
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct FileDiff {
+    #[serde(rename = "lineDiffBlocks")]
+    pub line_diff_blocks: Vec<LineDiffBlock>,
+    pub path: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(default)]
 pub struct Response<T> {
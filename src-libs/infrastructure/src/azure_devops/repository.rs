@@ -1,37 +1,66 @@
 use super::{
-    enums::{CommentType, PullRequestCommentThreadStatus},
+    cache::{ChangeStatisticsCache, PullRequestIterationsCache},
+    enums::{
+        CommentType, GitStatusState, LineDiffBlockChangeType, PolicyEvaluationStatus,
+        PullRequestCommentThreadStatus,
+    },
     models::{
-        GitRepository as AzureDevopsGitRepository, PullRequest as AzureDevopsPullRequest,
-        PullRequestCommentThread, Response, TeamProjectReference,
+        ConnectionData, FileDiff, FileDiffParams, FileDiffsCriteria, GitPullRequestIteration,
+        GitPullRequestIterationChanges, GitPullRequestStatus,
+        GitRepository as AzureDevopsGitRepository, PolicyEvaluationRecord,
+        PullRequest as AzureDevopsPullRequest, PullRequestCommentThread, ResourceRef, Response,
+        TeamProjectReference, WorkItem as AzureDevOpsWorkItem,
     },
 };
-use anyhow::Result;
+use crate::entra_id::token_provider::AccessTokenProvider;
+use anyhow::{Context, Result};
 use application::traits::AzureDevOpsRepository;
 use async_trait::async_trait;
 use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
 use domain::{
-    enums::GitProvider,
-    models::{GitRepository, PullRequest},
+    enums::{CheckStatus, CredentialType, GitProvider, PullRequestStatus, ReviewerVote},
+    models::{
+        ChangeStatistics, Credential, GitRepository, PullRequest, PullRequestActivity,
+        PullRequestCheck, PullRequestChecks, PullRequestIteration, PullRequestReviewers, Reviewer,
+        WorkItem,
+    },
 };
 use reqwest::{
-    Client,
+    Client, StatusCode,
     header::{AUTHORIZATION, HeaderMap, HeaderValue},
 };
 use std::sync::Arc;
 use tokio::task::JoinSet;
 
 const DEVOPS_API_VERSION: &str = "6.0";
+const DEVOPS_API_PREVIEW_VERSION: &str = "6.0-preview.1";
 const DEVOPS_API_BASE_URL: &str = "https://dev.azure.com";
+const DEVOPS_FILE_DIFFS_BATCH_SIZE: usize = 10;
+const THREAD_STATUS_CHANGE_DELAY_SECONDS: i64 = 1;
 
 /// Repository to access Azure DevOps using it's REST API
 pub struct AzureDevOpsRestRepository {
     http_client: Client,
+    change_statistics_cache: Arc<ChangeStatisticsCache>,
+    pull_request_iterations_cache: Arc<PullRequestIterationsCache>,
+    access_token_provider: Arc<AccessTokenProvider>,
 }
 
-impl Default for AzureDevOpsRestRepository {
-    /// Create a default instance with a configured http client that can
-    /// access the REST API using a PAT
-    fn default() -> Self {
+impl AzureDevOpsRestRepository {
+    /// Create a new instance with a configured http client, that shares the
+    /// caches for change statistics and iterations and the OAuth access tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `change_statistics_cache` - The cache to reuse already computed change statistics
+    /// * `pull_request_iterations_cache` - The cache to reuse already retrieved iterations
+    /// * `access_token_provider` - Provides the access tokens for OAuth credentials
+    pub fn new(
+        change_statistics_cache: Arc<ChangeStatisticsCache>,
+        pull_request_iterations_cache: Arc<PullRequestIterationsCache>,
+        access_token_provider: Arc<AccessTokenProvider>,
+    ) -> Self {
         let mut default_http_client_headers = HeaderMap::new();
         let api_version_header =
             HeaderValue::from_str(DEVOPS_API_VERSION).expect("Could not create api version header");
@@ -42,6 +71,32 @@ impl Default for AzureDevOpsRestRepository {
                 .default_headers(default_http_client_headers)
                 .build()
                 .expect("Could not create HTTP client for the Azure DevOps REST API"),
+            change_statistics_cache,
+            pull_request_iterations_cache,
+            access_token_provider,
+        }
+    }
+
+    /// Create the value of the authorization header for a credential, OAuth
+    /// access tokens are refreshed when they are about to expire
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - The credential to authorize the requests with
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur, e.g. when the OAuth sign in is no longer valid
+    async fn authorize(&self, credential: &Credential) -> Result<String> {
+        match credential {
+            Credential::Pat(pat) => Ok(basic_authorization(pat)),
+            Credential::OAuth(credential_key) => {
+                let access_token = self
+                    .access_token_provider
+                    .get_access_token(credential_key)
+                    .await?;
+                Ok(format!("Bearer {}", access_token))
+            }
         }
     }
 }
@@ -50,10 +105,11 @@ impl Default for AzureDevOpsRestRepository {
 impl AzureDevOpsRepository for AzureDevOpsRestRepository {
     async fn get_open_pull_requests_in_repository(
         &self,
-        pat: &str,
+        credential: &Credential,
         context: &str,
         repository_name: &str,
     ) -> Result<Vec<PullRequest>> {
+        let authorization = self.authorize(credential).await?;
         // Get all pull requests
         let relative_url = format!(
             "{}/_apis/git/repositories/{}/pullrequests",
@@ -61,7 +117,7 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
         );
         let response = perform_get_request::<Response<AzureDevopsPullRequest>>(
             &self.http_client,
-            pat,
+            &authorization,
             &relative_url,
         )
         .await?;
@@ -71,10 +127,12 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
         // comments were made for the PR and how many of those are marked as done
         let mut join_set = JoinSet::<Result<PullRequest>>::new();
         for x in response.value {
-            let pat = pat.to_string();
+            let authorization = authorization.to_string();
             let http_client_arc = Arc::clone(&http_client);
             let context = context.to_string();
             let repository = repository_name.to_string();
+            let change_statistics_cache = Arc::clone(&self.change_statistics_cache);
+            let pull_request_iterations_cache = Arc::clone(&self.pull_request_iterations_cache);
             join_set.spawn(async move {
                 let relative_url = format!(
                     "{}/_apis/git/repositories/{}/pullRequests/{}/threads",
@@ -82,7 +140,7 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
                 );
                 let response = perform_get_request::<Response<PullRequestCommentThread>>(
                     &http_client_arc,
-                    &pat,
+                    &authorization,
                     &relative_url,
                 )
                 .await?;
@@ -91,25 +149,214 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
                         .iter()
                         .any(|y| y.comment_type == CommentType::Text)
                 });
-                let solved_comments = comments.clone().filter(|x| {
+                // Replies of the creator are no review activity
+                let first_comment_date = comments
+                    .clone()
+                    .flat_map(|t| t.comments.iter())
+                    .filter(|c| {
+                        c.comment_type == CommentType::Text && c.author.id != x.created_by.id
+                    })
+                    .filter_map(|c| c.published_date)
+                    .min();
+                let is_solved = |x: &&PullRequestCommentThread| {
                     x.status == PullRequestCommentThreadStatus::Closed
                         || x.status == PullRequestCommentThreadStatus::Fixed
                         || x.status == PullRequestCommentThreadStatus::WontFix
                         || x.status == PullRequestCommentThreadStatus::ByDesign
+                };
+                let solved_comments = comments.clone().filter(is_solved);
+                let oldest_unresolved_comment_date = comments
+                    .clone()
+                    .filter(|x| !is_solved(x))
+                    .filter_map(|x| x.published_date)
+                    .min();
+                // A pull request is still worth showing when its checks can not be
+                // retrieved, e.g. due to missing permissions to read policies
+                let checks = get_pull_request_checks(&http_client_arc, &authorization, &context, &x)
+                    .await
+                    .unwrap_or_else(|err| {
+                        log::warn!(
+                            "Error getting checks of Azure DevOps pull request `{}`: {:?}",
+                            x.pull_request_id,
+                            err.to_string()
+                        );
+                        PullRequestChecks::new(vec![])
+                    });
+                // Linked work items are optional as well, so the pull request is
+                // shown without them when they can not be retrieved
+                let work_items =
+                    get_pull_request_work_items(&http_client_arc, &authorization, &context, &x)
+                        .await
+                        .unwrap_or_else(|err| {
+                            log::warn!(
+                                "Error getting work items of Azure DevOps pull request `{}`: {:?}",
+                                x.pull_request_id,
+                                err.to_string()
+                            );
+                            vec![]
+                        });
+                // Without iterations the pull request is still shown, only the
+                // change statistics and the new changes since a review are unknown
+                let iterations = get_pull_request_iterations(
+                    &http_client_arc,
+                    &authorization,
+                    &context,
+                    &x,
+                    &pull_request_iterations_cache,
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    log::warn!(
+                        "Error getting iterations of Azure DevOps pull request `{}`: {:?}",
+                        x.pull_request_id,
+                        err.to_string()
+                    );
+                    vec![]
                 });
+                let change_statistics = match iterations.iter().max_by_key(|x| x.id) {
+                    Some(latest_iteration) => get_change_statistics(
+                        &http_client_arc,
+                        &authorization,
+                        &context,
+                        &x,
+                        latest_iteration,
+                        &change_statistics_cache,
+                    )
+                    .await
+                    .map_err(|err| {
+                        log::warn!(
+                            "Error getting change statistics of Azure DevOps pull request `{}`: {:?}",
+                            x.pull_request_id,
+                            err.to_string()
+                        );
+                    })
+                    .ok(),
+                    None => None,
+                };
+                // Every vote is recorded as a system thread commented by the voter,
+                // so the latest of those dates when the current vote was cast
+                let voted_at = |reviewer_id: &str| {
+                    response
+                        .value
+                        .iter()
+                        .filter(|t| {
+                            t.properties
+                                .code_review_thread_type
+                                .as_ref()
+                                .is_some_and(|x| x.value == "VoteUpdate")
+                                && t.comments.iter().any(|c| c.author.id == reviewer_id)
+                        })
+                        .filter_map(|t| t.published_date)
+                        .max()
+                };
+                let reviewers = x
+                    .reviewers
+                    .iter()
+                    .map(|r| Reviewer {
+                        id: r.id.to_string(),
+                        display_name: r.display_name.to_string(),
+                        vote: reviewer_vote_of(r.vote),
+                        is_required: r.is_required,
+                        voted_at: voted_at(&r.id),
+                    })
+                    .collect();
+                // Pushes and comments are considered as activity on the pull request
+                let last_updated_date = iterations
+                    .iter()
+                    .map(|x| x.updated_date)
+                    .chain(response.value.iter().filter_map(|x| x.last_updated_date))
+                    .fold(x.creation_date, |acc, x| acc.max(x));
+                // Votes are recorded as system comments, so they are covered by the comments
+                let mut activities = iterations
+                    .iter()
+                    .map(|i| PullRequestActivity {
+                        author_id: i.author.id.to_string(),
+                        date: i.updated_date,
+                        is_new_comment: false,
+                    })
+                    .collect::<Vec<PullRequestActivity>>();
+                for thread in &response.value {
+                    let is_comment = thread
+                        .comments
+                        .iter()
+                        .any(|c| c.comment_type == CommentType::Text);
+                    let first_comment = thread.comments.iter().min_by_key(|c| c.published_date);
+                    for comment in &thread.comments {
+                        let Some(date) = comment.last_updated_date.or(comment.published_date)
+                        else {
+                            continue;
+                        };
+                        activities.push(PullRequestActivity {
+                            author_id: comment.author.id.to_string(),
+                            date,
+                            is_new_comment: false,
+                        });
+                    }
+                    if let (true, Some(first_comment), Some(date)) =
+                        (is_comment, first_comment, thread.published_date)
+                    {
+                        activities.push(PullRequestActivity {
+                            author_id: first_comment.author.id.to_string(),
+                            date,
+                            is_new_comment: true,
+                        });
+                    }
+                    let last_comment_date = thread
+                        .comments
+                        .iter()
+                        .filter_map(|c| c.last_updated_date.or(c.published_date))
+                        .max();
+                    // A thread updated notably after its latest comment had its status
+                    // changed, which can not be attributed to a user
+                    let status_change_delay = Duration::seconds(THREAD_STATUS_CHANGE_DELAY_SECONDS);
+                    if let Some(date) = thread
+                        .last_updated_date
+                        .filter(|x| last_comment_date.is_none_or(|y| *x - y > status_change_delay))
+                    {
+                        activities.push(PullRequestActivity {
+                            author_id: String::new(),
+                            date,
+                            is_new_comment: false,
+                        });
+                    }
+                }
                 let pr = PullRequest {
                     id: x.pull_request_id,
+                    // The imported git repository is not known to the Azure DevOps API
+                    git_repository_id: 0,
+                    git_provider: GitProvider::AzureDevOps,
                     repository_name: repository.to_string(),
                     title: x.title.to_string(),
                     merge_status: x.merge_status.to_string(),
+                    creator_id: x.created_by.id.to_string(),
                     creator_name: x.created_by.display_name.to_string(),
                     creation_date: x.creation_date,
+                    last_updated_date,
                     number_of_comments: comments.count(),
                     number_of_closed_comments: solved_comments.count(),
+                    first_comment_date,
+                    oldest_unresolved_comment_date,
                     link: format!(
                         "https://dev.azure.com/{}/_git/{}/pullrequest/{}",
                         context, repository, x.pull_request_id
                     ),
+                    checks,
+                    work_items,
+                    change_statistics,
+                    latest_iteration_id: iterations.iter().map(|x| x.id).max(),
+                    latest_iteration_date: iterations
+                        .iter()
+                        .max_by_key(|x| x.id)
+                        .map(|x| x.created_date),
+                    iterations: iterations
+                        .iter()
+                        .map(|x| PullRequestIteration {
+                            id: x.id,
+                            created_date: x.created_date,
+                        })
+                        .collect(),
+                    reviewers,
+                    activities,
                 };
                 Ok(pr)
             });
@@ -130,11 +377,59 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
         Ok(result)
     }
 
+    async fn get_open_pull_request_reviewers_in_repository(
+        &self,
+        credential: &Credential,
+        context: &str,
+        repository_name: &str,
+    ) -> Result<Vec<PullRequestReviewers>> {
+        let authorization = self.authorize(credential).await?;
+        let relative_url = format!(
+            "{}/_apis/git/repositories/{}/pullrequests",
+            context, repository_name
+        );
+        let response = perform_get_request::<Response<AzureDevopsPullRequest>>(
+            &self.http_client,
+            &authorization,
+            &relative_url,
+        )
+        .await?;
+        Ok(response
+            .value
+            .iter()
+            .map(|x| PullRequestReviewers {
+                id: x.pull_request_id,
+                // The imported git repository is not known to the Azure DevOps API
+                git_repository_id: 0,
+                title: x.title.to_string(),
+                link: format!(
+                    "https://dev.azure.com/{}/_git/{}/pullrequest/{}",
+                    context, repository_name, x.pull_request_id
+                ),
+                creation_date: x.creation_date,
+                // When a vote was cast is only known from the comment threads
+                reviewers: x
+                    .reviewers
+                    .iter()
+                    .map(|r| Reviewer {
+                        id: r.id.to_string(),
+                        display_name: r.display_name.to_string(),
+                        vote: reviewer_vote_of(r.vote),
+                        is_required: r.is_required,
+                        voted_at: None,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
     async fn get_repositories_in_organization(
         &self,
-        pat: &str,
+        credential: &Credential,
         organization_name: &str,
     ) -> Result<Vec<GitRepository>> {
+        let authorization = self.authorize(credential).await?;
+        let (credential_type, credential_key) = credential_of(credential, organization_name);
         // Create a copy of the http client that is safe to be shared between threads
         let http_client = Arc::new(self.http_client.clone());
         // Get all the projects first, cause the relation in Azure DevOps between
@@ -142,7 +437,7 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
         let projects_path = format!("{}/_apis/projects", organization_name);
         let projects = perform_get_request::<Response<TeamProjectReference>>(
             &Arc::clone(&http_client),
-            pat,
+            &authorization,
             &projects_path,
         )
         .await?
@@ -152,7 +447,8 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
         for project in projects {
             let organization_name = organization_name.to_string();
             let project_name = project.name.to_string();
-            let pat = pat.to_string();
+            let authorization = authorization.to_string();
+            let credential_key = credential_key.to_string();
             let http_client_arc = Arc::clone(&http_client);
             join_set.spawn(async move {
                 let git_repos_path = format!(
@@ -161,7 +457,7 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
                 );
                 let response = perform_get_request::<Response<AzureDevopsGitRepository>>(
                     &http_client_arc,
-                    &pat,
+                    &authorization,
                     &git_repos_path,
                 )
                 .await?;
@@ -174,34 +470,621 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
                         context: format!("{}/{}", organization_name, project_name),
                         git_provider: GitProvider::AzureDevOps,
                         is_active: true,
-                        pat_secret_key: format!("azuredevops-{}", organization_name),
+                        credential_type,
+                        credential_key: credential_key.to_string(),
+                        remote_id: Some(x.id.to_string()),
+                        is_orphaned: false,
                     })
                     .collect::<Vec<GitRepository>>();
                 Ok(result)
             });
         }
-        // Collect the results from all tasks and return all found domain models
+        // Collect the results from all tasks and return all found domain models. A
+        // single project that can not be listed fails the whole listing, as its
+        // git repositories would otherwise be considered as removed
         let mut result = vec![];
         while let Some(res) = join_set.join_next().await {
-            match res {
-                Ok(x) => result.extend(x?),
-                Err(err) => {
-                    log::info!(
-                        "Error getting Azure DevOps git repository: {:?}",
-                        err.to_string()
-                    );
+            result.extend(res??);
+        }
+        Ok(result)
+    }
+
+    async fn get_repository(
+        &self,
+        credential: &Credential,
+        context: &str,
+        repository_name: &str,
+    ) -> Result<GitRepository> {
+        let organization_name = context.split('/').next().unwrap_or_default();
+        let authorization = self.authorize(credential).await?;
+        let (credential_type, credential_key) = credential_of(credential, organization_name);
+        let git_repo_path = format!(
+            "{}/_apis/git/repositories/{}?api-version={}",
+            context, repository_name, DEVOPS_API_VERSION
+        );
+        let git_repo = perform_get_request::<AzureDevopsGitRepository>(
+            &self.http_client,
+            &authorization,
+            &git_repo_path,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "The git repository `{}` in `{}` could not be accessed with the given credential",
+                repository_name, context
+            )
+        })?;
+        Ok(GitRepository {
+            id: 0,
+            name: git_repo.name,
+            context: format!("{}/{}", organization_name, git_repo.project.name),
+            git_provider: GitProvider::AzureDevOps,
+            is_active: true,
+            credential_type,
+            credential_key,
+            remote_id: Some(git_repo.id),
+            is_orphaned: false,
+        })
+    }
+
+    async fn validate_credential(
+        &self,
+        credential: &Credential,
+        organization_name: &str,
+    ) -> Result<()> {
+        let authorization = self.authorize(credential).await?;
+        // Only rejected requests prove the credential invalid, other failures
+        // like an unreachable service are propagated as they are
+        let map_rejection = |err: anyhow::Error| match err.downcast_ref::<UnexpectedStatusError>() {
+            Some(x) if x.is_rejection() => anyhow::anyhow!(
+                "The credential is invalid, expired or not allowed to read the projects and pull requests of `{}`",
+                organization_name
+            ),
+            _ => err,
+        };
+        let projects_path = format!(
+            "{}/_apis/projects?$top=1&api-version={}",
+            organization_name, DEVOPS_API_VERSION
+        );
+        let projects = perform_validated_get_request::<Response<TeamProjectReference>>(
+            &self.http_client,
+            &authorization,
+            &projects_path,
+        )
+        .await
+        .map_err(map_rejection)?;
+        if let Some(project) = projects.value.first() {
+            let pull_requests_path = format!(
+                "{}/{}/_apis/git/pullrequests?$top=1&api-version={}",
+                organization_name, project.name, DEVOPS_API_VERSION
+            );
+            perform_validated_get_request::<Response<AzureDevopsPullRequest>>(
+                &self.http_client,
+                &authorization,
+                &pull_requests_path,
+            )
+            .await
+            .map_err(map_rejection)?;
+        }
+        Ok(())
+    }
+
+    async fn get_authenticated_user_id(
+        &self,
+        credential: &Credential,
+        organization_name: &str,
+    ) -> Result<String> {
+        let authorization = self.authorize(credential).await?;
+        let connection_data_path = format!(
+            "{}/_apis/connectionData?api-version={}",
+            organization_name, DEVOPS_API_PREVIEW_VERSION
+        );
+        let connection_data = perform_get_request::<ConnectionData>(
+            &self.http_client,
+            &authorization,
+            &connection_data_path,
+        )
+        .await?;
+        Ok(connection_data.authenticated_user.id)
+    }
+
+    async fn get_pull_request_status(
+        &self,
+        credential: &Credential,
+        context: &str,
+        repository_name: &str,
+        pull_request_id: &u32,
+    ) -> Result<(PullRequestStatus, Option<DateTime<Utc>>)> {
+        let authorization = self.authorize(credential).await?;
+        let pull_request_path = format!(
+            "{}/_apis/git/repositories/{}/pullrequests/{}?api-version={}",
+            context, repository_name, pull_request_id, DEVOPS_API_VERSION
+        );
+        let pull_request = perform_validated_get_request::<AzureDevopsPullRequest>(
+            &self.http_client,
+            &authorization,
+            &pull_request_path,
+        )
+        .await?;
+        let status = match pull_request.status.as_str() {
+            "completed" => PullRequestStatus::Completed,
+            "abandoned" => PullRequestStatus::Abandoned,
+            _ => PullRequestStatus::Active,
+        };
+        let closed_date = match status {
+            PullRequestStatus::Active => None,
+            _ => pull_request.closed_date,
+        };
+        Ok((status, closed_date))
+    }
+}
+
+/// Helper to map the numeric vote of a reviewer to the domain enum
+///
+/// # Arguments
+///
+/// * `vote` - The vote as returned by the REST API
+fn reviewer_vote_of(vote: i32) -> ReviewerVote {
+    match vote {
+        10 => ReviewerVote::Approved,
+        5 => ReviewerVote::ApprovedWithSuggestions,
+        -5 => ReviewerVote::WaitingForAuthor,
+        -10 => ReviewerVote::Rejected,
+        _ => ReviewerVote::NoVote,
+    }
+}
+
+/// Helper to get the type and key of the credential, imported git
+/// repositories of an organization are accessed with
+///
+/// # Arguments
+///
+/// * `credential` - The credential the git repositories were queried with
+/// * `organization_name` - The name of the Azure DevOps organization
+fn credential_of(credential: &Credential, organization_name: &str) -> (CredentialType, String) {
+    match credential {
+        Credential::Pat(_) => (
+            CredentialType::Pat,
+            format!("azuredevops-{}", organization_name),
+        ),
+        Credential::OAuth(credential_key) => (CredentialType::OAuth, credential_key.to_string()),
+    }
+}
+
+/// Helper to get the statuses and policy evaluations of a single pull request
+/// and summarize them as checks
+///
+/// # Arguments
+///
+/// * `http_client` - The http client to perform the requests with
+/// * `authorization` - The value of the authorization header of the requests
+/// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
+/// * `pull_request` - The pull request to get the checks for
+///
+/// # Returns
+///
+/// * `Result<PullRequestChecks>` - The summarized checks of the pull request
+///
+/// # Errors
+///
+/// Any error that might occur
+async fn get_pull_request_checks(
+    http_client: &Client,
+    authorization: &str,
+    context: &str,
+    pull_request: &AzureDevopsPullRequest,
+) -> Result<PullRequestChecks> {
+    let pull_request_link = format!(
+        "{}/{}/_git/{}/pullrequest/{}",
+        DEVOPS_API_BASE_URL, context, pull_request.repository.name, pull_request.pull_request_id
+    );
+    // Statuses are posted by external services (e.g. pipelines) for every
+    // iteration, therefore only the latest status per context is relevant
+    let statuses_path = format!(
+        "{}/_apis/git/repositories/{}/pullRequests/{}/statuses",
+        context, pull_request.repository.id, pull_request.pull_request_id
+    );
+    let mut statuses = perform_get_request::<Response<GitPullRequestStatus>>(
+        http_client,
+        authorization,
+        &statuses_path,
+    )
+    .await?
+    .value;
+    statuses.sort_by_key(|x| std::cmp::Reverse(x.id));
+    let mut items: Vec<PullRequestCheck> = vec![];
+    let mut seen_contexts: Vec<(String, String)> = vec![];
+    for status in statuses {
+        let status_context = (status.context.genre.clone(), status.context.name.clone());
+        if seen_contexts.contains(&status_context) {
+            continue;
+        }
+        seen_contexts.push(status_context);
+        let name = if status.description.is_empty() {
+            match status.context.genre.is_empty() {
+                true => status.context.name,
+                false => format!("{}/{}", status.context.genre, status.context.name),
+            }
+        } else {
+            status.description
+        };
+        items.push(PullRequestCheck {
+            name,
+            status: match status.state {
+                GitStatusState::Succeeded => CheckStatus::Passed,
+                GitStatusState::Failed | GitStatusState::Error => CheckStatus::Failed,
+                GitStatusState::Pending => CheckStatus::Pending,
+                GitStatusState::NotApplicable | GitStatusState::NotSet => {
+                    CheckStatus::NotApplicable
+                }
+            },
+            link: status.target_url,
+        });
+    }
+    // Branch policies, such as build validation, minimum number of reviewers, comment
+    // resolution and work item linking, are evaluated against the code review artifact
+    let evaluations_path = format!(
+        "{}/_apis/policy/evaluations?artifactId=vstfs:///CodeReview/CodeReviewId/{}/{}&api-version={}",
+        context,
+        pull_request.repository.project.id,
+        pull_request.pull_request_id,
+        DEVOPS_API_PREVIEW_VERSION
+    );
+    let evaluations = perform_get_request::<Response<PolicyEvaluationRecord>>(
+        http_client,
+        authorization,
+        &evaluations_path,
+    )
+    .await?
+    .value;
+    for evaluation in evaluations
+        .into_iter()
+        .filter(|x| x.configuration.is_enabled)
+    {
+        let link = match evaluation.context.and_then(|x| x.build_id) {
+            Some(build_id) => format!(
+                "{}/{}/_build/results?buildId={}",
+                DEVOPS_API_BASE_URL, context, build_id
+            ),
+            None => pull_request_link.to_string(),
+        };
+        items.push(PullRequestCheck {
+            name: evaluation
+                .configuration
+                .settings
+                .display_name
+                .unwrap_or(evaluation.configuration.policy_type.display_name),
+            status: match evaluation.status {
+                PolicyEvaluationStatus::Approved => CheckStatus::Passed,
+                PolicyEvaluationStatus::Rejected | PolicyEvaluationStatus::Broken => {
+                    match evaluation.configuration.is_blocking {
+                        true => CheckStatus::Failed,
+                        // Optional policies never block the completion
+                        false => CheckStatus::NotApplicable,
+                    }
+                }
+                PolicyEvaluationStatus::Queued | PolicyEvaluationStatus::Running => {
+                    CheckStatus::Pending
+                }
+                PolicyEvaluationStatus::NotApplicable => CheckStatus::NotApplicable,
+            },
+            link: Some(link),
+        });
+    }
+    Ok(PullRequestChecks::new(items))
+}
+
+/// Helper to get the work items linked to a single pull request
+///
+/// # Arguments
+///
+/// * `http_client` - The http client to perform the requests with
+/// * `authorization` - The value of the authorization header of the requests
+/// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
+/// * `pull_request` - The pull request to get the linked work items for
+///
+/// # Returns
+///
+/// * `Result<Vec<WorkItem>>` - The linked work items
+///
+/// # Errors
+///
+/// Any error that might occur
+async fn get_pull_request_work_items(
+    http_client: &Client,
+    authorization: &str,
+    context: &str,
+    pull_request: &AzureDevopsPullRequest,
+) -> Result<Vec<WorkItem>> {
+    let work_item_refs_path = format!(
+        "{}/_apis/git/repositories/{}/pullRequests/{}/workitems",
+        context, pull_request.repository.id, pull_request.pull_request_id
+    );
+    let work_item_ids = perform_get_request::<Response<ResourceRef>>(
+        http_client,
+        authorization,
+        &work_item_refs_path,
+    )
+    .await?
+    .value
+    .iter()
+    .filter_map(|x| x.id.parse::<u32>().ok())
+    .collect::<Vec<u32>>();
+    if work_item_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    // The links alone are already sufficient to know whether work items are linked,
+    // therefore missing details (e.g. due to a PAT without work item scope) are tolerated
+    let work_items_path = format!(
+        "{}/_apis/wit/workitems?ids={}&fields=System.WorkItemType,System.Title,System.State",
+        context,
+        work_item_ids
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    );
+    let details = perform_get_request::<Response<AzureDevOpsWorkItem>>(
+        http_client,
+        authorization,
+        &work_items_path,
+    )
+    .await
+    .map(|x| x.value)
+    .unwrap_or_else(|err| {
+        log::warn!(
+            "Error getting Azure DevOps work items of pull request `{}`: {:?}",
+            pull_request.pull_request_id,
+            err.to_string()
+        );
+        vec![]
+    });
+    let result = work_item_ids
+        .iter()
+        .map(|id| match details.iter().find(|x| x.id == *id) {
+            Some(x) => WorkItem {
+                id: *id,
+                work_item_type: x.fields.work_item_type.to_string(),
+                title: x.fields.title.to_string(),
+                state: x.fields.state.to_string(),
+            },
+            None => WorkItem {
+                id: *id,
+                work_item_type: String::new(),
+                title: String::new(),
+                state: String::new(),
+            },
+        })
+        .collect();
+    Ok(result)
+}
+
+/// Helper to get all iterations (pushes) of a single pull request
+///
+/// The iterations are only requested again after a push to the source branch.
+///
+/// # Arguments
+///
+/// * `http_client` - The http client to perform the requests with
+/// * `authorization` - The value of the authorization header of the requests
+/// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
+/// * `pull_request` - The pull request to get the iterations for
+/// * `cache` - The cache of already retrieved iterations
+///
+/// # Returns
+///
+/// * `Result<Vec<GitPullRequestIteration>>` - The iterations of the pull request
+///
+/// # Errors
+///
+/// Any error that might occur
+async fn get_pull_request_iterations(
+    http_client: &Client,
+    authorization: &str,
+    context: &str,
+    pull_request: &AzureDevopsPullRequest,
+    cache: &PullRequestIterationsCache,
+) -> Result<Vec<GitPullRequestIteration>> {
+    let repository_id = &pull_request.repository.id;
+    let source_commit_id = pull_request
+        .last_merge_source_commit
+        .as_ref()
+        .map(|x| x.commit_id.as_str());
+    if let Some(cached) =
+        source_commit_id.and_then(|x| cache.get(repository_id, pull_request.pull_request_id, x))
+    {
+        return Ok(cached);
+    }
+    let iterations_path = format!(
+        "{}/_apis/git/repositories/{}/pullRequests/{}/iterations",
+        context, repository_id, pull_request.pull_request_id
+    );
+    let result = perform_get_request::<Response<GitPullRequestIteration>>(
+        http_client,
+        authorization,
+        &iterations_path,
+    )
+    .await?
+    .value;
+    if let Some(source_commit_id) = source_commit_id {
+        cache.insert(
+            repository_id,
+            pull_request.pull_request_id,
+            source_commit_id,
+            result.clone(),
+        );
+    }
+    Ok(result)
+}
+
+/// Helper to compute the size of the changes of a single pull request iteration,
+/// compared to the common commit with the target branch
+///
+/// The number of changed files is always computed, the number of added and deleted
+/// lines only when the file diffs of the iteration can be retrieved. Complete results
+/// are cached per iteration, since the changes of an iteration never change.
+///
+/// # Arguments
+///
+/// * `http_client` - The http client to perform the requests with
+/// * `authorization` - The value of the authorization header of the requests
+/// * `context` - The request context in the format ORGANIZATION_NAME/PROJECT_NAME
+/// * `pull_request` - The pull request the iteration belongs to
+/// * `iteration` - The iteration to compute the statistics for
+/// * `cache` - The cache of already computed statistics
+///
+/// # Returns
+///
+/// * `Result<ChangeStatistics>` - The statistics of the iteration
+///
+/// # Errors
+///
+/// Any error that might occur
+async fn get_change_statistics(
+    http_client: &Client,
+    authorization: &str,
+    context: &str,
+    pull_request: &AzureDevopsPullRequest,
+    iteration: &GitPullRequestIteration,
+    cache: &ChangeStatisticsCache,
+) -> Result<ChangeStatistics> {
+    let repository_id = &pull_request.repository.id;
+    if let Some(cached) = cache.get(repository_id, pull_request.pull_request_id, iteration.id) {
+        return Ok(cached);
+    }
+    // Get all changed files of the iteration, the API returns them in pages
+    let mut file_changes = vec![];
+    let mut skip = 0;
+    loop {
+        let changes_path = format!(
+            "{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/changes?$compareTo=0&$top=1000&$skip={}",
+            context, repository_id, pull_request.pull_request_id, iteration.id, skip
+        );
+        let response = perform_get_request::<GitPullRequestIterationChanges>(
+            http_client,
+            authorization,
+            &changes_path,
+        )
+        .await?;
+        file_changes.extend(
+            response
+                .change_entries
+                .into_iter()
+                .filter(|x| x.item.git_object_type != "tree"),
+        );
+        if response.next_skip == 0 {
+            break;
+        }
+        skip = response.next_skip;
+    }
+    let mut statistics = ChangeStatistics {
+        iteration_id: iteration.id,
+        files_changed: file_changes.len(),
+        lines_added: None,
+        lines_deleted: None,
+    };
+    // The line based statistics require the commits to compare
+    let (Some(base), Some(target)) = (&iteration.common_ref_commit, &iteration.source_ref_commit)
+    else {
+        return Ok(statistics);
+    };
+    let file_diffs_path = format!(
+        "{}/_apis/git/repositories/{}/fileDiffs?api-version={}",
+        context, repository_id, DEVOPS_API_PREVIEW_VERSION
+    );
+    let mut lines_added = 0;
+    let mut lines_deleted = 0;
+    for batch in file_changes.chunks(DEVOPS_FILE_DIFFS_BATCH_SIZE) {
+        let criteria = FileDiffsCriteria {
+            base_version_commit: base.commit_id.to_string(),
+            target_version_commit: target.commit_id.to_string(),
+            file_diff_params: batch
+                .iter()
+                .map(|x| FileDiffParams {
+                    original_path: Some(
+                        x.original_path
+                            .clone()
+                            .unwrap_or_else(|| x.item.path.to_string()),
+                    ),
+                    path: Some(x.item.path.to_string()),
+                })
+                .collect(),
+        };
+        let file_diffs = match perform_post_request::<FileDiffsCriteria, Response<FileDiff>>(
+            http_client,
+            authorization,
+            &file_diffs_path,
+            &criteria,
+        )
+        .await
+        {
+            Ok(x) => x.value,
+            Err(err) => {
+                log::warn!(
+                    "Error getting file diffs of Azure DevOps pull request `{}`: {:?}",
+                    pull_request.pull_request_id,
+                    err.to_string()
+                );
+                return Ok(statistics);
+            }
+        };
+        for block in file_diffs.iter().flat_map(|x| &x.line_diff_blocks) {
+            match block.change_type {
+                LineDiffBlockChangeType::Add => lines_added += block.modified_lines_count,
+                LineDiffBlockChangeType::Delete => lines_deleted += block.original_lines_count,
+                LineDiffBlockChangeType::Edit => {
+                    lines_added += block.modified_lines_count;
+                    lines_deleted += block.original_lines_count;
                 }
+                LineDiffBlockChangeType::None => {}
             }
         }
-        Ok(result)
     }
+    statistics.lines_added = Some(lines_added);
+    statistics.lines_deleted = Some(lines_deleted);
+    cache.insert(
+        repository_id,
+        pull_request.pull_request_id,
+        statistics.clone(),
+    );
+    Ok(statistics)
 }
 
-/// Helper to perform a GET HTTP request to the Azure DevOps API
+/// Helper to create the value of the authorization header for a PAT
 ///
 /// # Arguments
 ///
 /// * `pat` - The personal access token to authorize the request
+fn basic_authorization(pat: &str) -> String {
+    let encoded_pat = base64::engine::general_purpose::STANDARD.encode(format!(":{}", pat));
+    format!("Basic {}", &encoded_pat)
+}
+
+/// Helper to create the headers to authorize a request against the Azure DevOps API
+///
+/// # Arguments
+///
+/// * `authorization` - The value of the authorization header of the request
+///
+/// # Returns
+///
+/// * `Result<HeaderMap>` - The headers to attach to the request
+///
+/// # Errors
+///
+/// Any error that might occur
+fn create_auth_headers(authorization: &str) -> Result<HeaderMap> {
+    let auth_header_value = HeaderValue::from_str(authorization)?;
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, auth_header_value);
+    Ok(headers)
+}
+
+/// Helper to perform a GET HTTP request to the Azure DevOps API
+///
+/// # Arguments
+///
+/// * `authorization` - The value of the authorization header of the request
 /// * `path` - The relative path to the API resource
 ///
 /// # Returns
@@ -211,20 +1094,124 @@ impl AzureDevOpsRepository for AzureDevOpsRestRepository {
 /// # Errors
 ///
 /// Any error that might occur
-async fn perform_get_request<T>(http_client: &Client, pat: &str, path: &str) -> Result<T>
+async fn perform_get_request<T>(http_client: &Client, authorization: &str, path: &str) -> Result<T>
 where
     T: serde::de::DeserializeOwned,
 {
     let url = format!("{}/{}", DEVOPS_API_BASE_URL, path);
-    let encoded_pat = base64::engine::general_purpose::STANDARD.encode(format!(":{}", pat));
-    let auth_value = format!("Basic {}", &encoded_pat);
-    let auth_header_value = HeaderValue::from_str(&auth_value)?;
-    let headers = {
-        let mut headers = HeaderMap::new();
-        headers.insert(AUTHORIZATION, auth_header_value);
-        headers
-    };
+    let headers = create_auth_headers(authorization)?;
+    let response = http_client.get(url).headers(headers).send().await?;
+    let json = response.json::<T>().await?;
+    Ok(json)
+}
+
+/// Helper to perform a GET HTTP request to the Azure DevOps API, that fails
+/// unless the request was fully authorized
+///
+/// Azure DevOps answers requests with invalid or expired credentials with a sign in
+/// page and a non-authoritative status, so only `200 OK` is accepted.
+///
+/// # Arguments
+///
+/// * `authorization` - The value of the authorization header of the request
+/// * `path` - The relative path to the API resource
+///
+/// # Returns
+///
+/// * `Result<T>` - The result of the request parsed as T
+///
+/// # Errors
+///
+/// If the request was not authorized or any other error occurs
+async fn perform_validated_get_request<T>(
+    http_client: &Client,
+    authorization: &str,
+    path: &str,
+) -> Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let url = format!("{}/{}", DEVOPS_API_BASE_URL, path);
+    let headers = create_auth_headers(authorization)?;
     let response = http_client.get(url).headers(headers).send().await?;
+    if response.status() != StatusCode::OK {
+        return Err(UnexpectedStatusError {
+            path: path.to_string(),
+            status: response.status(),
+        }
+        .into());
+    }
+    let json = response.json::<T>().await?;
+    Ok(json)
+}
+
+/// The error of a request to the Azure DevOps API that was answered with
+/// another status than `200 OK`
+#[derive(Debug)]
+struct UnexpectedStatusError {
+    path: String,
+    status: StatusCode,
+}
+
+impl UnexpectedStatusError {
+    /// Whether the request was rejected because of its credential, which is
+    /// answered with a sign in page by Azure DevOps as well
+    fn is_rejection(&self) -> bool {
+        matches!(
+            self.status,
+            StatusCode::UNAUTHORIZED
+                | StatusCode::FORBIDDEN
+                | StatusCode::NON_AUTHORITATIVE_INFORMATION
+        )
+    }
+}
+
+impl std::fmt::Display for UnexpectedStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Request to `{}` failed with status `{}`",
+            self.path, self.status
+        )
+    }
+}
+
+impl std::error::Error for UnexpectedStatusError {}
+
+/// Helper to perform a POST HTTP request to the Azure DevOps API
+///
+/// # Arguments
+///
+/// * `authorization` - The value of the authorization header of the request
+/// * `path` - The relative path to the API resource
+/// * `body` - The payload to send as JSON
+///
+/// # Returns
+///
+/// * `Result<T>` - The result of the request parsed as T
+///
+/// # Errors
+///
+/// Any error that might occur
+async fn perform_post_request<B, T>(
+    http_client: &Client,
+    authorization: &str,
+    path: &str,
+    body: &B,
+) -> Result<T>
+where
+    B: serde::Serialize,
+    T: serde::de::DeserializeOwned,
+{
+    let url = format!("{}/{}", DEVOPS_API_BASE_URL, path);
+    let headers = create_auth_headers(authorization)?;
+    let response = http_client
+        .post(url)
+        .headers(headers)
+        .json(body)
+        .send()
+        .await?
+        .error_for_status()?;
     let json = response.json::<T>().await?;
     Ok(json)
 }
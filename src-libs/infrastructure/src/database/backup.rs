@@ -0,0 +1,228 @@
+use super::connection::MIGRATOR;
+use anyhow::Result;
+use application::traits::DatabaseBackupRepository;
+use async_trait::async_trait;
+use sqlx::{
+    Connection, SqliteConnection, SqlitePool,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+use std::{path::Path, sync::Arc};
+
+/// Repository to back up and restore the sqlite database
+pub struct DatabaseBackupSqliteRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl DatabaseBackupSqliteRepository {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - The connection pool to the database to back up and restore
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl DatabaseBackupRepository for DatabaseBackupSqliteRepository {
+    async fn get_schema_version(&self) -> Result<i64> {
+        let version = sqlx::query_scalar::<_, i64>(
+            r#"
+                SELECT COALESCE(MAX(version), 0)
+                FROM _sqlx_migrations
+                WHERE success = 1
+            "#,
+        )
+        .fetch_one(self.database_access.as_ref())
+        .await?;
+        Ok(version)
+    }
+
+    async fn get_backup_schema_version(&self, file_path: &str) -> Result<i64> {
+        if !Path::new(file_path).is_file() {
+            return Err(anyhow::anyhow!("The backup `{}` does not exist", file_path));
+        }
+        let mut conn = SqliteConnection::connect_with(
+            &SqliteConnectOptions::new()
+                .filename(file_path)
+                .read_only(true),
+        )
+        .await
+        .map_err(|err| anyhow::anyhow!("The backup could not be opened: {}", err))?;
+        let result = get_validated_schema_version(&mut conn).await;
+        conn.close().await?;
+        result
+    }
+
+    async fn create_backup(&self, file_path: &str) -> Result<()> {
+        if Path::new(file_path).exists() {
+            return Err(anyhow::anyhow!("The file `{}` already exists", file_path));
+        }
+        // Unlike copying the file, this includes the changes still in the
+        // WAL and does not block other connections while running
+        sqlx::query("VACUUM INTO ?1")
+            .bind(file_path)
+            .execute(self.database_access.as_ref())
+            .await?;
+        Ok(())
+    }
+
+    async fn restore_backup(&self, file_path: &str) -> Result<()> {
+        // Bring a copy of the backup to the current schema first, so the
+        // tables of both databases match
+        let temp_file_path = std::env::temp_dir().join(format!(
+            "pr-hub-restore-{}-{}.sqlite",
+            std::process::id(),
+            chrono::Utc::now().timestamp_millis()
+        ));
+        std::fs::copy(file_path, &temp_file_path)?;
+        let result = self.restore_from_migrated_copy(&temp_file_path).await;
+        if let Err(err) = std::fs::remove_file(&temp_file_path) {
+            log::warn!(
+                "Error removing the temporary copy of the backup `{}`: {}",
+                temp_file_path.display(),
+                err
+            );
+        }
+        result
+    }
+}
+
+impl DatabaseBackupSqliteRepository {
+    /// Migrate a copy of a backup and replace the content of all tables with it
+    ///
+    /// # Arguments
+    ///
+    /// * `temp_file_path` - The path to the copy of the backup, which is changed
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, in which case the database is left unchanged
+    async fn restore_from_migrated_copy(&self, temp_file_path: &Path) -> Result<()> {
+        let backup_pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(
+                SqliteConnectOptions::new()
+                    .filename(temp_file_path)
+                    .foreign_keys(true),
+            )
+            .await?;
+        MIGRATOR.run(&backup_pool).await?;
+        backup_pool.close().await;
+
+        let mut conn = self.database_access.acquire().await?;
+        sqlx::query("ATTACH DATABASE ?1 AS backup")
+            .bind(temp_file_path.to_string_lossy().to_string())
+            .execute(&mut *conn)
+            .await?;
+        let result = replace_tables_from_backup(&mut conn).await;
+        let detach_result = sqlx::query("DETACH DATABASE backup")
+            .execute(&mut *conn)
+            .await;
+        result?;
+        detach_result?;
+        Ok(())
+    }
+}
+
+/// Helper to check the integrity of a backup and to get its schema version
+///
+/// # Arguments
+///
+/// * `conn` - The connection to the backup
+///
+/// # Errors
+///
+/// If the backup is corrupt, not created by the application or contains
+/// migrations unknown to this version of the application
+async fn get_validated_schema_version(conn: &mut SqliteConnection) -> Result<i64> {
+    let integrity = sqlx::query_scalar::<_, String>("PRAGMA quick_check")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|err| anyhow::anyhow!("The backup is no valid database: {}", err))?;
+    if integrity != "ok" {
+        return Err(anyhow::anyhow!("The backup is corrupt: {}", integrity));
+    }
+    let has_migrations = sqlx::query_scalar::<_, i64>(
+        r#"
+            SELECT COUNT(*)
+            FROM sqlite_master
+            WHERE type = 'table' AND name = '_sqlx_migrations'
+        "#,
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    if has_migrations == 0 {
+        return Err(anyhow::anyhow!("The file is no backup of the application"));
+    }
+    let versions = sqlx::query_scalar::<_, i64>(
+        r#"
+            SELECT version
+            FROM _sqlx_migrations
+            WHERE success = 1
+        "#,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    if versions
+        .iter()
+        .any(|x| !MIGRATOR.iter().any(|m| m.version == *x))
+    {
+        return Err(anyhow::anyhow!(
+            "The backup was created by a newer version of the application, please update it first"
+        ));
+    }
+    Ok(versions.into_iter().max().unwrap_or_default())
+}
+
+/// Helper to replace the content of all tables with the one of the attached
+/// backup within a single transaction
+///
+/// # Arguments
+///
+/// * `conn` - The connection to the database, with the backup attached as `backup`
+///
+/// # Errors
+///
+/// Any errors that might occur, in which case the transaction is rolled back
+async fn replace_tables_from_backup(conn: &mut SqliteConnection) -> Result<()> {
+    let tables = sqlx::query_scalar::<_, String>(
+        r#"
+            SELECT name
+            FROM main.sqlite_master
+            WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name <> '_sqlx_migrations'
+        "#,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    let mut tx = conn.begin().await?;
+    // The tables are filled in any order, so the references can only be
+    // checked once all of them are complete
+    sqlx::query("PRAGMA defer_foreign_keys = ON")
+        .execute(&mut *tx)
+        .await?;
+    for table in &tables {
+        sqlx::query(&format!("DELETE FROM main.\"{}\"", table))
+            .execute(&mut *tx)
+            .await?;
+    }
+    for table in &tables {
+        let columns =
+            sqlx::query_scalar::<_, String>("SELECT name FROM pragma_table_info(?1, 'main')")
+                .bind(table)
+                .fetch_all(&mut *tx)
+                .await?
+                .iter()
+                .map(|x| format!("\"{}\"", x))
+                .collect::<Vec<String>>()
+                .join(", ");
+        sqlx::query(&format!(
+            "INSERT INTO main.\"{table}\" ({columns}) SELECT {columns} FROM backup.\"{table}\""
+        ))
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
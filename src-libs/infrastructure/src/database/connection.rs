@@ -1,10 +1,28 @@
 use anyhow::Result;
-use sqlx::{SqlitePool, migrate::MigrateDatabase};
+use sqlx::{
+    SqlitePool,
+    migrate::Migrator,
+    sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous},
+};
+use std::{path::Path, time::Duration};
+
+/// The migrations to bring a database to the schema the application expects
+pub(crate) static MIGRATOR: Migrator = sqlx::migrate!("src/database/migrations");
+
+/// The name of the database file within the app data dir
+const DATABASE_FILE_NAME: &str = "pr-hub-db.sqlite";
+/// The maximum number of connections kept open to the database
+const MAX_CONNECTIONS: u32 = 4;
+/// How long to wait for a lock held by another connection, before failing
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Initialize the connection to the sqlite database,
 /// apply pending migrations and
 /// return a new connection pool as result
 ///
+/// The database is opened in WAL mode, so reading does not block writing,
+/// and with foreign keys enforced.
+///
 /// # Arguments
 ///
 /// * `app_data_dir_path` - The path to the app data dir
@@ -17,11 +35,25 @@ use sqlx::{SqlitePool, migrate::MigrateDatabase};
 ///
 /// Any errors that might occur
 pub async fn init_db_connection(app_data_dir_path: &str) -> Result<SqlitePool> {
-    let db_path = &format!("{}/pr-hub-db.sqlite", app_data_dir_path);
-    if !sqlx::Sqlite::database_exists(db_path).await? {
-        sqlx::Sqlite::create_database(db_path).await?;
-    }
-    let pool = SqlitePool::connect(db_path).await?;
-    sqlx::migrate!("src/database/migrations").run(&pool).await?;
+    let db_path = Path::new(app_data_dir_path).join(DATABASE_FILE_NAME);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(MAX_CONNECTIONS)
+        .connect_with(connect_options(&db_path).create_if_missing(true))
+        .await?;
+    MIGRATOR.run(&pool).await?;
     Ok(pool)
 }
+
+/// Get the options to connect to a database file with
+///
+/// # Arguments
+///
+/// * `db_path` - The path to the database file
+pub(crate) fn connect_options(db_path: &Path) -> SqliteConnectOptions {
+    SqliteConnectOptions::new()
+        .filename(db_path)
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(SqliteSynchronous::Normal)
+        .busy_timeout(BUSY_TIMEOUT)
+        .foreign_keys(true)
+}
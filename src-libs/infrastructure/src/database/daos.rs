@@ -1,4 +1,15 @@
-use domain::{enums::GitProvider as DomainGitProvider, models::GitRepository};
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::{
+    enums::{
+        CredentialType as DomainCredentialType, GitProvider as DomainGitProvider,
+        PullRequestStatus as DomainPullRequestStatus, ReviewerVote as DomainReviewerVote,
+        SlaRuleKind as DomainSlaRuleKind,
+    },
+    models::{
+        GitRepository, Holiday, PatMetadata, PullRequestAnnotation, PullRequestLifecycle,
+        PullRequestReview, PullRequestState, PullRequestTriage, RepositoryGroup, SlaRule,
+    },
+};
 use sqlx::{FromRow, Type};
 
 #[derive(Type, Clone)]
@@ -23,6 +34,31 @@ impl From<DomainGitProvider> for GitProvider {
     }
 }
 
+#[derive(Type, Clone)]
+#[sqlx(type_name = "credential_type", rename_all = "lowercase")]
+pub enum CredentialType {
+    Pat,
+    OAuth,
+}
+
+impl From<CredentialType> for DomainCredentialType {
+    fn from(value: CredentialType) -> Self {
+        match value {
+            CredentialType::Pat => DomainCredentialType::Pat,
+            CredentialType::OAuth => DomainCredentialType::OAuth,
+        }
+    }
+}
+
+impl From<DomainCredentialType> for CredentialType {
+    fn from(value: DomainCredentialType) -> Self {
+        match value {
+            DomainCredentialType::Pat => CredentialType::Pat,
+            DomainCredentialType::OAuth => CredentialType::OAuth,
+        }
+    }
+}
+
 #[derive(FromRow, Clone)]
 pub struct GitRepositoryDao {
     pub id: u32,
@@ -30,7 +66,10 @@ pub struct GitRepositoryDao {
     pub context: String,
     pub is_active: bool,
     pub git_provider: GitProvider,
-    pub pat_secret_key: String,
+    pub credential_type: CredentialType,
+    pub credential_key: String,
+    pub remote_id: Option<String>,
+    pub is_orphaned: bool,
 }
 
 impl From<&GitRepositoryDao> for GitRepository {
@@ -41,7 +80,10 @@ impl From<&GitRepositoryDao> for GitRepository {
             context: value.context.to_string(),
             git_provider: value.git_provider.clone().into(),
             is_active: value.is_active,
-            pat_secret_key: value.pat_secret_key.to_string(),
+            credential_type: value.credential_type.clone().into(),
+            credential_key: value.credential_key.to_string(),
+            remote_id: value.remote_id.clone(),
+            is_orphaned: value.is_orphaned,
         }
     }
 }
@@ -54,7 +96,10 @@ impl From<GitRepositoryDao> for GitRepository {
             context: value.context,
             git_provider: value.git_provider.into(),
             is_active: value.is_active,
-            pat_secret_key: value.pat_secret_key,
+            credential_type: value.credential_type.into(),
+            credential_key: value.credential_key,
+            remote_id: value.remote_id,
+            is_orphaned: value.is_orphaned,
         }
     }
 }
@@ -67,7 +112,436 @@ impl From<GitRepository> for GitRepositoryDao {
             context: value.context,
             git_provider: value.git_provider.into(),
             is_active: value.is_active,
-            pat_secret_key: value.pat_secret_key,
+            credential_type: value.credential_type.into(),
+            credential_key: value.credential_key,
+            remote_id: value.remote_id,
+            is_orphaned: value.is_orphaned,
+        }
+    }
+}
+
+#[derive(Type, Clone)]
+#[sqlx(type_name = "reviewer_vote", rename_all = "snake_case")]
+pub enum ReviewerVote {
+    Approved,
+    ApprovedWithSuggestions,
+    NoVote,
+    WaitingForAuthor,
+    Rejected,
+}
+
+impl From<ReviewerVote> for DomainReviewerVote {
+    fn from(value: ReviewerVote) -> Self {
+        match value {
+            ReviewerVote::Approved => DomainReviewerVote::Approved,
+            ReviewerVote::ApprovedWithSuggestions => DomainReviewerVote::ApprovedWithSuggestions,
+            ReviewerVote::NoVote => DomainReviewerVote::NoVote,
+            ReviewerVote::WaitingForAuthor => DomainReviewerVote::WaitingForAuthor,
+            ReviewerVote::Rejected => DomainReviewerVote::Rejected,
+        }
+    }
+}
+
+impl From<DomainReviewerVote> for ReviewerVote {
+    fn from(value: DomainReviewerVote) -> Self {
+        match value {
+            DomainReviewerVote::Approved => ReviewerVote::Approved,
+            DomainReviewerVote::ApprovedWithSuggestions => ReviewerVote::ApprovedWithSuggestions,
+            DomainReviewerVote::NoVote => ReviewerVote::NoVote,
+            DomainReviewerVote::WaitingForAuthor => ReviewerVote::WaitingForAuthor,
+            DomainReviewerVote::Rejected => ReviewerVote::Rejected,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct PullRequestReviewDao {
+    pub git_repository_id: u32,
+    pub pull_request_id: u32,
+    pub iteration_id: u32,
+    pub vote: ReviewerVote,
+    pub reviewed_at: DateTime<Utc>,
+}
+
+impl From<PullRequestReviewDao> for PullRequestReview {
+    fn from(value: PullRequestReviewDao) -> Self {
+        Self {
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            iteration_id: value.iteration_id,
+            vote: value.vote.into(),
+            reviewed_at: value.reviewed_at,
+        }
+    }
+}
+
+impl From<PullRequestReview> for PullRequestReviewDao {
+    fn from(value: PullRequestReview) -> Self {
+        Self {
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            iteration_id: value.iteration_id,
+            vote: value.vote.into(),
+            reviewed_at: value.reviewed_at,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct PullRequestStateDao {
+    pub git_provider: GitProvider,
+    pub git_repository_id: u32,
+    pub pull_request_id: u32,
+    pub last_seen_at: DateTime<Utc>,
+    pub last_seen_comment_count: u32,
+}
+
+impl From<PullRequestStateDao> for PullRequestState {
+    fn from(value: PullRequestStateDao) -> Self {
+        Self {
+            git_provider: value.git_provider.into(),
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            last_seen_at: value.last_seen_at,
+            last_seen_comment_count: value.last_seen_comment_count as usize,
+        }
+    }
+}
+
+impl From<PullRequestState> for PullRequestStateDao {
+    fn from(value: PullRequestState) -> Self {
+        Self {
+            git_provider: value.git_provider.into(),
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            last_seen_at: value.last_seen_at,
+            last_seen_comment_count: value.last_seen_comment_count as u32,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct PullRequestTriageDao {
+    pub git_provider: GitProvider,
+    pub git_repository_id: u32,
+    pub pull_request_id: u32,
+    pub is_pinned: bool,
+    pub snoozed_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub snoozed_until_next_update: bool,
+}
+
+impl From<PullRequestTriageDao> for PullRequestTriage {
+    fn from(value: PullRequestTriageDao) -> Self {
+        Self {
+            git_provider: value.git_provider.into(),
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            is_pinned: value.is_pinned,
+            snoozed_at: value.snoozed_at,
+            snoozed_until: value.snoozed_until,
+            snoozed_until_next_update: value.snoozed_until_next_update,
+        }
+    }
+}
+
+impl From<PullRequestTriage> for PullRequestTriageDao {
+    fn from(value: PullRequestTriage) -> Self {
+        Self {
+            git_provider: value.git_provider.into(),
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            is_pinned: value.is_pinned,
+            snoozed_at: value.snoozed_at,
+            snoozed_until: value.snoozed_until,
+            snoozed_until_next_update: value.snoozed_until_next_update,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct PullRequestAnnotationDao {
+    pub git_provider: GitProvider,
+    pub git_repository_id: u32,
+    pub pull_request_id: u32,
+    pub note: Option<String>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(FromRow, Clone)]
+pub struct PullRequestAnnotationTagDao {
+    pub git_repository_id: u32,
+    pub pull_request_id: u32,
+    pub tag: String,
+}
+
+impl PullRequestAnnotationDao {
+    /// Map the annotation to the domain model
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The tags of all annotations, only the ones of this annotation are used
+    pub fn into_domain(self, tags: &[PullRequestAnnotationTagDao]) -> PullRequestAnnotation {
+        PullRequestAnnotation {
+            git_provider: self.git_provider.into(),
+            git_repository_id: self.git_repository_id,
+            pull_request_id: self.pull_request_id,
+            note: self.note,
+            tags: tags
+                .iter()
+                .filter(|x| {
+                    x.git_repository_id == self.git_repository_id
+                        && x.pull_request_id == self.pull_request_id
+                })
+                .map(|x| x.tag.to_string())
+                .collect(),
+            updated_at: self.updated_at,
+            closed_at: self.closed_at,
+        }
+    }
+}
+
+impl From<&PullRequestAnnotation> for PullRequestAnnotationDao {
+    fn from(value: &PullRequestAnnotation) -> Self {
+        Self {
+            git_provider: value.git_provider.clone().into(),
+            git_repository_id: value.git_repository_id,
+            pull_request_id: value.pull_request_id,
+            note: value.note.clone(),
+            updated_at: value.updated_at,
+            closed_at: value.closed_at,
+        }
+    }
+}
+
+#[derive(Type, Clone)]
+#[sqlx(type_name = "pull_request_status", rename_all = "lowercase")]
+pub enum PullRequestStatus {
+    Active,
+    Completed,
+    Abandoned,
+}
+
+impl From<PullRequestStatus> for DomainPullRequestStatus {
+    fn from(value: PullRequestStatus) -> Self {
+        match value {
+            PullRequestStatus::Active => DomainPullRequestStatus::Active,
+            PullRequestStatus::Completed => DomainPullRequestStatus::Completed,
+            PullRequestStatus::Abandoned => DomainPullRequestStatus::Abandoned,
+        }
+    }
+}
+
+impl From<DomainPullRequestStatus> for PullRequestStatus {
+    fn from(value: DomainPullRequestStatus) -> Self {
+        match value {
+            DomainPullRequestStatus::Active => PullRequestStatus::Active,
+            DomainPullRequestStatus::Completed => PullRequestStatus::Completed,
+            DomainPullRequestStatus::Abandoned => PullRequestStatus::Abandoned,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct PullRequestLifecycleDao {
+    pub git_provider: GitProvider,
+    pub git_repository_id: Option<u32>,
+    pub git_repository_name: String,
+    pub pull_request_id: u32,
+    pub creator_id: String,
+    pub creator_name: String,
+    pub status: PullRequestStatus,
+    pub created_at: DateTime<Utc>,
+    pub first_vote_at: Option<DateTime<Utc>>,
+    pub first_comment_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub status_lookup_failure_count: u32,
+    pub status_lookup_failed_at: Option<DateTime<Utc>>,
+}
+
+impl From<PullRequestLifecycleDao> for PullRequestLifecycle {
+    fn from(value: PullRequestLifecycleDao) -> Self {
+        Self {
+            git_provider: value.git_provider.into(),
+            git_repository_id: value.git_repository_id,
+            git_repository_name: value.git_repository_name,
+            pull_request_id: value.pull_request_id,
+            creator_id: value.creator_id,
+            creator_name: value.creator_name,
+            status: value.status.into(),
+            created_at: value.created_at,
+            first_vote_at: value.first_vote_at,
+            first_comment_at: value.first_comment_at,
+            closed_at: value.closed_at,
+            status_lookup_failure_count: value.status_lookup_failure_count,
+            status_lookup_failed_at: value.status_lookup_failed_at,
+        }
+    }
+}
+
+impl From<PullRequestLifecycle> for PullRequestLifecycleDao {
+    fn from(value: PullRequestLifecycle) -> Self {
+        Self {
+            git_provider: value.git_provider.into(),
+            git_repository_id: value.git_repository_id,
+            git_repository_name: value.git_repository_name,
+            pull_request_id: value.pull_request_id,
+            creator_id: value.creator_id,
+            creator_name: value.creator_name,
+            status: value.status.into(),
+            created_at: value.created_at,
+            first_vote_at: value.first_vote_at,
+            first_comment_at: value.first_comment_at,
+            closed_at: value.closed_at,
+            status_lookup_failure_count: value.status_lookup_failure_count,
+            status_lookup_failed_at: value.status_lookup_failed_at,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct RepositoryGroupDao {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(FromRow, Clone)]
+pub struct RepositoryGroupMemberDao {
+    pub repository_group_id: u32,
+    pub git_repository_id: u32,
+}
+
+impl RepositoryGroupDao {
+    /// Map the repository group to the domain model
+    ///
+    /// # Arguments
+    ///
+    /// * `members` - The members of all groups, only the ones of this group are used
+    pub fn into_domain(self, members: &[RepositoryGroupMemberDao]) -> RepositoryGroup {
+        RepositoryGroup {
+            id: self.id,
+            name: self.name,
+            git_repository_ids: members
+                .iter()
+                .filter(|x| x.repository_group_id == self.id)
+                .map(|x| x.git_repository_id)
+                .collect(),
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct PatMetadataDao {
+    pub credential_key: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub validated_at: Option<DateTime<Utc>>,
+}
+
+impl From<PatMetadataDao> for PatMetadata {
+    fn from(value: PatMetadataDao) -> Self {
+        Self {
+            credential_key: value.credential_key,
+            expires_at: value.expires_at,
+            validated_at: value.validated_at,
+        }
+    }
+}
+
+impl From<PatMetadata> for PatMetadataDao {
+    fn from(value: PatMetadata) -> Self {
+        Self {
+            credential_key: value.credential_key,
+            expires_at: value.expires_at,
+            validated_at: value.validated_at,
+        }
+    }
+}
+
+#[derive(Type, Clone)]
+#[sqlx(type_name = "sla_rule_kind", rename_all = "snake_case")]
+pub enum SlaRuleKind {
+    NoReviewerVote,
+    NoUpdate,
+    UnresolvedComments,
+}
+
+impl From<SlaRuleKind> for DomainSlaRuleKind {
+    fn from(value: SlaRuleKind) -> Self {
+        match value {
+            SlaRuleKind::NoReviewerVote => DomainSlaRuleKind::NoReviewerVote,
+            SlaRuleKind::NoUpdate => DomainSlaRuleKind::NoUpdate,
+            SlaRuleKind::UnresolvedComments => DomainSlaRuleKind::UnresolvedComments,
+        }
+    }
+}
+
+impl From<DomainSlaRuleKind> for SlaRuleKind {
+    fn from(value: DomainSlaRuleKind) -> Self {
+        match value {
+            DomainSlaRuleKind::NoReviewerVote => SlaRuleKind::NoReviewerVote,
+            DomainSlaRuleKind::NoUpdate => SlaRuleKind::NoUpdate,
+            DomainSlaRuleKind::UnresolvedComments => SlaRuleKind::UnresolvedComments,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct SlaRuleDao {
+    pub id: u32,
+    pub name: String,
+    pub kind: SlaRuleKind,
+    pub threshold_hours: u32,
+    pub is_business_hours: bool,
+    pub repository_group_id: Option<u32>,
+}
+
+impl From<SlaRuleDao> for SlaRule {
+    fn from(value: SlaRuleDao) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            kind: value.kind.into(),
+            threshold_hours: value.threshold_hours,
+            is_business_hours: value.is_business_hours,
+            repository_group_id: value.repository_group_id,
+        }
+    }
+}
+
+impl From<SlaRule> for SlaRuleDao {
+    fn from(value: SlaRule) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            kind: value.kind.into(),
+            threshold_hours: value.threshold_hours,
+            is_business_hours: value.is_business_hours,
+            repository_group_id: value.repository_group_id,
+        }
+    }
+}
+
+#[derive(FromRow, Clone)]
+pub struct HolidayDao {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+impl From<HolidayDao> for Holiday {
+    fn from(value: HolidayDao) -> Self {
+        Self {
+            date: value.date,
+            name: value.name,
+        }
+    }
+}
+
+impl From<Holiday> for HolidayDao {
+    fn from(value: Holiday) -> Self {
+        Self {
+            date: value.date,
+            name: value.name,
         }
     }
 }
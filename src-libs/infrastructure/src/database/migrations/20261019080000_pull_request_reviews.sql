@@ -0,0 +1,9 @@
+CREATE TABLE pull_request_reviews
+(
+		git_repository_id INTEGER NOT NULL REFERENCES git_repositories(id) ON DELETE CASCADE,
+		pull_request_id INTEGER NOT NULL,
+		iteration_id INTEGER NOT NULL,
+		vote TEXT NOT NULL CHECK(vote IN ('approved', 'approved_with_suggestions', 'no_vote', 'waiting_for_author', 'rejected')),
+		reviewed_at TEXT NOT NULL,
+		PRIMARY KEY(git_repository_id, pull_request_id)
+);
@@ -0,0 +1,9 @@
+CREATE TABLE pull_request_state
+(
+		git_provider TEXT NOT NULL CHECK(git_provider IN ('azuredevops')),
+		git_repository_id INTEGER NOT NULL REFERENCES git_repositories(id) ON DELETE CASCADE,
+		pull_request_id INTEGER NOT NULL,
+		last_seen_at TEXT NOT NULL,
+		last_seen_comment_count INTEGER NOT NULL DEFAULT 0,
+		PRIMARY KEY(git_repository_id, pull_request_id)
+);
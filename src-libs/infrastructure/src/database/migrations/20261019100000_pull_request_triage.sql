@@ -0,0 +1,11 @@
+CREATE TABLE pull_request_triage
+(
+		git_provider TEXT NOT NULL CHECK(git_provider IN ('azuredevops')),
+		git_repository_id INTEGER NOT NULL REFERENCES git_repositories(id) ON DELETE CASCADE,
+		pull_request_id INTEGER NOT NULL,
+		is_pinned BOOLEAN NOT NULL DEFAULT 0,
+		snoozed_at TEXT,
+		snoozed_until TEXT,
+		snoozed_until_next_update BOOLEAN NOT NULL DEFAULT 0,
+		PRIMARY KEY(git_repository_id, pull_request_id)
+);
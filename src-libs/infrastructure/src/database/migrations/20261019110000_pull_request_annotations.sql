@@ -0,0 +1,20 @@
+CREATE TABLE pull_request_annotations
+(
+		git_provider TEXT NOT NULL CHECK(git_provider IN ('azuredevops')),
+		git_repository_id INTEGER NOT NULL REFERENCES git_repositories(id) ON DELETE CASCADE,
+		pull_request_id INTEGER NOT NULL,
+		note TEXT,
+		updated_at TEXT NOT NULL,
+		closed_at TEXT,
+		PRIMARY KEY(git_repository_id, pull_request_id)
+);
+
+CREATE TABLE pull_request_annotation_tags
+(
+		git_repository_id INTEGER NOT NULL,
+		pull_request_id INTEGER NOT NULL,
+		tag TEXT NOT NULL,
+		PRIMARY KEY(git_repository_id, pull_request_id, tag),
+		FOREIGN KEY(git_repository_id, pull_request_id)
+			REFERENCES pull_request_annotations(git_repository_id, pull_request_id) ON DELETE CASCADE
+);
@@ -0,0 +1,12 @@
+CREATE TABLE repository_groups
+(
+		id INTEGER PRIMARY KEY,
+		name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE repository_group_members
+(
+		repository_group_id INTEGER NOT NULL REFERENCES repository_groups(id) ON DELETE CASCADE,
+		git_repository_id INTEGER NOT NULL REFERENCES git_repositories(id) ON DELETE CASCADE,
+		PRIMARY KEY(repository_group_id, git_repository_id)
+);
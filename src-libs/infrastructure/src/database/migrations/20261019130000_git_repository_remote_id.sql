@@ -0,0 +1,5 @@
+ALTER TABLE git_repositories ADD COLUMN remote_id TEXT;
+
+ALTER TABLE git_repositories ADD COLUMN is_orphaned BOOLEAN NOT NULL DEFAULT 0;
+
+CREATE UNIQUE INDEX git_repositories_remote_id ON git_repositories(git_provider, remote_id);
@@ -0,0 +1,6 @@
+CREATE TABLE pat_metadata
+(
+		pat_secret_key TEXT PRIMARY KEY,
+		expires_at TEXT,
+		validated_at TEXT
+);
@@ -0,0 +1,5 @@
+ALTER TABLE git_repositories RENAME COLUMN pat_secret_key TO credential_key;
+
+ALTER TABLE git_repositories ADD COLUMN credential_type TEXT NOT NULL DEFAULT 'pat' CHECK(credential_type IN ('pat', 'oauth'));
+
+ALTER TABLE pat_metadata RENAME COLUMN pat_secret_key TO credential_key;
@@ -0,0 +1,18 @@
+CREATE TABLE pull_request_lifecycles
+(
+		id INTEGER PRIMARY KEY,
+		git_provider TEXT NOT NULL CHECK(git_provider IN ('azuredevops')),
+		git_repository_id INTEGER REFERENCES git_repositories(id) ON DELETE SET NULL,
+		git_repository_name TEXT NOT NULL,
+		pull_request_id INTEGER NOT NULL,
+		creator_id TEXT NOT NULL,
+		creator_name TEXT NOT NULL,
+		status TEXT NOT NULL CHECK(status IN ('active', 'completed', 'abandoned')),
+		created_at TEXT NOT NULL,
+		first_vote_at TEXT,
+		first_comment_at TEXT,
+		closed_at TEXT,
+		status_lookup_failure_count INTEGER NOT NULL DEFAULT 0,
+		status_lookup_failed_at TEXT,
+		UNIQUE(git_repository_id, pull_request_id)
+);
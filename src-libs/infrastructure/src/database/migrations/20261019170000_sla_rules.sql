@@ -0,0 +1,15 @@
+CREATE TABLE sla_rules
+(
+		id INTEGER PRIMARY KEY,
+		name TEXT NOT NULL,
+		kind TEXT NOT NULL CHECK(kind IN ('no_reviewer_vote', 'no_update', 'unresolved_comments')),
+		threshold_hours INTEGER NOT NULL CHECK(threshold_hours > 0),
+		is_business_hours BOOLEAN NOT NULL DEFAULT 0,
+		repository_group_id INTEGER REFERENCES repository_groups(id) ON DELETE CASCADE
+);
+
+CREATE TABLE holidays
+(
+		date TEXT PRIMARY KEY,
+		name TEXT NOT NULL DEFAULT ''
+);
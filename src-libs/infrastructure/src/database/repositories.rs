@@ -1,9 +1,26 @@
-use super::daos::GitRepositoryDao;
+use super::daos::{
+    GitRepositoryDao, HolidayDao, PatMetadataDao, PullRequestAnnotationDao,
+    PullRequestAnnotationTagDao, PullRequestLifecycleDao, PullRequestReviewDao,
+    PullRequestStateDao, PullRequestTriageDao, RepositoryGroupDao, RepositoryGroupMemberDao,
+    SlaRuleDao,
+};
 use anyhow::Result;
-use application::traits::GitRepositoryRepository;
+use application::traits::{
+    GitRepositoryRepository, PatMetadataRepository, PullRequestAnnotationRepository,
+    PullRequestLifecycleRepository, PullRequestReviewRepository, PullRequestStateRepository,
+    PullRequestTriageRepository, RepositoryGroupRepository, SlaRuleRepository,
+};
 use async_trait::async_trait;
-use domain::models::GitRepository;
-use sqlx::SqlitePool;
+use chrono::{DateTime, Utc};
+use domain::{
+    errors::RepositoryGroupNotFoundError,
+    models::{
+        GitRepository, Holiday, ImportedRepositoryGroup, PatMetadata, PullRequestAnnotation,
+        PullRequestLifecycle, PullRequestReview, PullRequestState, PullRequestTriage,
+        RepositoryGroup, SlaRule,
+    },
+};
+use sqlx::{Executor, Sqlite, SqlitePool};
 use std::sync::Arc;
 
 /// Repository to access the git repositories, stored in the database
@@ -53,46 +70,1005 @@ impl GitRepositoryRepository for GitRepositoryDatabaseRepository {
     }
 
     async fn update_git_repository(&self, git_repository: GitRepository) -> Result<()> {
-        let dao: GitRepositoryDao = git_repository.into();
+        update_git_repository(&*self.database_access, git_repository.into()).await
+    }
+
+    async fn create_git_repository(&self, git_repository: GitRepository) -> Result<()> {
+        insert_git_repository(&*self.database_access, git_repository.into()).await
+    }
+
+    async fn delete_git_repository(&self, id: &u32) -> Result<()> {
         sqlx::query(
             r#"
-                UPDATE git_repositories
-                SET name = ?1, context = ?2, is_active = ?3, git_provider = ?4
-                WHERE id = ?5;
+                DELETE FROM git_repositories
+                WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+
+    async fn create_git_repositories(&self, git_repositories: Vec<GitRepository>) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for git_repository in git_repositories {
+            insert_git_repository(&mut *transaction, git_repository.into()).await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn update_git_repositories(&self, git_repositories: Vec<GitRepository>) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for git_repository in git_repositories {
+            update_git_repository(&mut *transaction, git_repository.into()).await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn sync_git_repositories(
+        &self,
+        updated_git_repositories: Vec<GitRepository>,
+        created_git_repositories: Vec<GitRepository>,
+    ) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        // Move the updated git repositories to temporary names first, so renames
+        // never collide with the previous names of each other. Azure DevOps does
+        // not allow colons in names, so the temporary names are always unique.
+        for git_repository in &updated_git_repositories {
+            sqlx::query(
+                r#"
+                    UPDATE git_repositories
+                    SET name = ?1
+                    WHERE id = ?2;
+                "#,
+            )
+            .bind(format!(":sync:{}", git_repository.id))
+            .bind(git_repository.id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        for git_repository in updated_git_repositories {
+            update_git_repository(&mut *transaction, git_repository.into()).await?;
+        }
+        for git_repository in created_git_repositories {
+            insert_git_repository(&mut *transaction, git_repository.into()).await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn import_git_repositories(
+        &self,
+        created_git_repositories: Vec<GitRepository>,
+        updated_git_repositories: Vec<GitRepository>,
+        repository_groups: Vec<ImportedRepositoryGroup>,
+    ) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for git_repository in created_git_repositories {
+            insert_git_repository(&mut *transaction, git_repository.into()).await?;
+        }
+        for git_repository in updated_git_repositories {
+            update_git_repository(&mut *transaction, git_repository.into()).await?;
+        }
+        let git_repositories = sqlx::query_as::<_, GitRepositoryDao>(
+            r#"
+                    SELECT *
+                    FROM git_repositories
+            "#,
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .iter()
+        .map(|x| x.into())
+        .collect::<Vec<GitRepository>>();
+        for repository_group in repository_groups {
+            let id = match repository_group.id {
+                Some(id) => id,
+                None => {
+                    sqlx::query_scalar::<_, u32>(
+                        r#"
+                            INSERT INTO repository_groups (name)
+                            VALUES (?1)
+                            RETURNING id
+                        "#,
+                    )
+                    .bind(&repository_group.name)
+                    .fetch_one(&mut *transaction)
+                    .await?
+                }
+            };
+            for git_repository_id in &repository_group.unassigned_git_repository_ids {
+                sqlx::query(
+                    r#"
+                        DELETE FROM repository_group_members
+                        WHERE repository_group_id = ?1 AND git_repository_id = ?2
+                    "#,
+                )
+                .bind(id)
+                .bind(git_repository_id)
+                .execute(&mut *transaction)
+                .await?;
+            }
+            for assigned in &repository_group.assigned_git_repositories {
+                let git_repository = git_repositories
+                    .iter()
+                    .find(|x| x.is_same_remote(assigned))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The git repository `{}` of the repository group `{}` does not exist",
+                            assigned.name,
+                            repository_group.name
+                        )
+                    })?;
+                sqlx::query(
+                    r#"
+                        INSERT OR IGNORE INTO repository_group_members (repository_group_id, git_repository_id)
+                        VALUES (?1, ?2)
+                    "#,
+                )
+                .bind(id)
+                .bind(git_repository.id)
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn set_git_repositories_active_state(&self, ids: &[u32], is_active: bool) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for id in ids {
+            sqlx::query(
+                r#"
+                    UPDATE git_repositories
+                    SET is_active = ?1
+                    WHERE id = ?2
+                "#,
+            )
+            .bind(is_active)
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_git_repositories(&self, ids: &[u32]) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for id in ids {
+            sqlx::query(
+                r#"
+                    DELETE FROM git_repositories
+                    WHERE id = ?1
+                "#,
+            )
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+/// Helper to insert a single git repository, with a connection or within a transaction
+///
+/// # Arguments
+///
+/// * `executor` - The connection or transaction to run the statement with
+/// * `dao` - The git repository to insert
+///
+/// # Errors
+///
+/// Any errors that might occur
+async fn insert_git_repository<'e, E>(executor: E, dao: GitRepositoryDao) -> Result<()>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    sqlx::query(
+        r#"
+            INSERT INTO git_repositories (name, context, is_active, git_provider, credential_key, remote_id, is_orphaned, credential_type)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        "#,
+    )
+    .bind(dao.name)
+    .bind(dao.context)
+    .bind(dao.is_active)
+    .bind(dao.git_provider)
+    .bind(dao.credential_key)
+    .bind(dao.remote_id)
+    .bind(dao.is_orphaned)
+    .bind(dao.credential_type)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Helper to update a single git repository, with a connection or within a transaction
+///
+/// # Arguments
+///
+/// * `executor` - The connection or transaction to run the statement with
+/// * `dao` - The git repository to update, identified by its id
+///
+/// # Errors
+///
+/// Any errors that might occur
+async fn update_git_repository<'e, E>(executor: E, dao: GitRepositoryDao) -> Result<()>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    sqlx::query(
+        r#"
+            UPDATE git_repositories
+            SET name = ?1, context = ?2, is_active = ?3, git_provider = ?4, remote_id = ?5, is_orphaned = ?6, credential_type = ?7, credential_key = ?8
+            WHERE id = ?9;
+        "#,
+    )
+    .bind(dao.name)
+    .bind(dao.context)
+    .bind(dao.is_active)
+    .bind(dao.git_provider)
+    .bind(dao.remote_id)
+    .bind(dao.is_orphaned)
+    .bind(dao.credential_type)
+    .bind(dao.credential_key)
+    .bind(dao.id)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Repository to access the tracked pull request reviews, stored in the database
+pub struct PullRequestReviewDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl PullRequestReviewDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl PullRequestReviewRepository for PullRequestReviewDatabaseRepository {
+    async fn get_pull_request_reviews(&self) -> Result<Vec<PullRequestReview>> {
+        let reviews = sqlx::query_as::<_, PullRequestReviewDao>(
+            r#"
+                SELECT *
+                FROM pull_request_reviews
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = reviews.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn get_pull_request_review(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<Option<PullRequestReview>> {
+        let review = sqlx::query_as::<_, PullRequestReviewDao>(
+            r#"
+                SELECT *
+                FROM pull_request_reviews
+                WHERE git_repository_id = ?1 AND pull_request_id = ?2
+            "#,
+        )
+        .bind(git_repository_id)
+        .bind(pull_request_id)
+        .fetch_optional(&*self.database_access)
+        .await?;
+        Ok(review.map(|x| x.into()))
+    }
+
+    async fn save_pull_request_review(&self, pull_request_review: PullRequestReview) -> Result<()> {
+        let dao: PullRequestReviewDao = pull_request_review.into();
+        sqlx::query(
+            r#"
+                INSERT INTO pull_request_reviews (git_repository_id, pull_request_id, iteration_id, vote, reviewed_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(git_repository_id, pull_request_id)
+                DO UPDATE SET iteration_id = ?3, vote = ?4, reviewed_at = ?5
+            "#,
+        )
+        .bind(dao.git_repository_id)
+        .bind(dao.pull_request_id)
+        .bind(dao.iteration_id)
+        .bind(dao.vote)
+        .bind(dao.reviewed_at)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Repository to access the read state of pull requests, stored in the database
+pub struct PullRequestStateDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl PullRequestStateDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl PullRequestStateRepository for PullRequestStateDatabaseRepository {
+    async fn get_pull_request_states(&self) -> Result<Vec<PullRequestState>> {
+        let states = sqlx::query_as::<_, PullRequestStateDao>(
+            r#"
+                SELECT *
+                FROM pull_request_state
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = states.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn save_pull_request_state(&self, pull_request_state: PullRequestState) -> Result<()> {
+        let dao: PullRequestStateDao = pull_request_state.into();
+        sqlx::query(
+            r#"
+                INSERT INTO pull_request_state (git_provider, git_repository_id, pull_request_id, last_seen_at, last_seen_comment_count)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(git_repository_id, pull_request_id)
+                DO UPDATE SET git_provider = ?1, last_seen_at = ?4, last_seen_comment_count = ?5
             "#,
         )
-        .bind(dao.name)
-        .bind(dao.context)
-        .bind(dao.is_active)
         .bind(dao.git_provider)
-        .bind(dao.id)
+        .bind(dao.git_repository_id)
+        .bind(dao.pull_request_id)
+        .bind(dao.last_seen_at)
+        .bind(dao.last_seen_comment_count)
         .execute(&*self.database_access)
         .await?;
         Ok(())
     }
 
-    async fn create_git_repository(&self, git_repository: GitRepository) -> Result<()> {
-        let dao: GitRepositoryDao = git_repository.into();
+    async fn delete_pull_request_state(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM pull_request_state
+                WHERE git_repository_id = ?1 AND pull_request_id = ?2
+            "#,
+        )
+        .bind(git_repository_id)
+        .bind(pull_request_id)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Repository to access the pin and snooze state of pull requests, stored in the database
+pub struct PullRequestTriageDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl PullRequestTriageDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl PullRequestTriageRepository for PullRequestTriageDatabaseRepository {
+    async fn get_pull_request_triages(&self) -> Result<Vec<PullRequestTriage>> {
+        let triages = sqlx::query_as::<_, PullRequestTriageDao>(
+            r#"
+                SELECT *
+                FROM pull_request_triage
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = triages.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn get_pull_request_triage(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<Option<PullRequestTriage>> {
+        let triage = sqlx::query_as::<_, PullRequestTriageDao>(
+            r#"
+                SELECT *
+                FROM pull_request_triage
+                WHERE git_repository_id = ?1 AND pull_request_id = ?2
+            "#,
+        )
+        .bind(git_repository_id)
+        .bind(pull_request_id)
+        .fetch_optional(&*self.database_access)
+        .await?;
+        let result = triage.map(|x| x.into());
+        Ok(result)
+    }
+
+    async fn save_pull_request_triage(&self, pull_request_triage: PullRequestTriage) -> Result<()> {
+        let dao: PullRequestTriageDao = pull_request_triage.into();
+        sqlx::query(
+            r#"
+                INSERT INTO pull_request_triage (git_provider, git_repository_id, pull_request_id, is_pinned, snoozed_at, snoozed_until, snoozed_until_next_update)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                ON CONFLICT(git_repository_id, pull_request_id)
+                DO UPDATE SET git_provider = ?1, is_pinned = ?4, snoozed_at = ?5, snoozed_until = ?6, snoozed_until_next_update = ?7
+            "#,
+        )
+        .bind(dao.git_provider)
+        .bind(dao.git_repository_id)
+        .bind(dao.pull_request_id)
+        .bind(dao.is_pinned)
+        .bind(dao.snoozed_at)
+        .bind(dao.snoozed_until)
+        .bind(dao.snoozed_until_next_update)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_pull_request_triage(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<()> {
         sqlx::query(
             r#"
-                INSERT INTO git_repositories (name, context, is_active, git_provider, pat_secret_key)
+                DELETE FROM pull_request_triage
+                WHERE git_repository_id = ?1 AND pull_request_id = ?2
+            "#,
+        )
+        .bind(git_repository_id)
+        .bind(pull_request_id)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Repository to access the notes and local tags of pull requests, stored in the database
+pub struct PullRequestAnnotationDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl PullRequestAnnotationDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl PullRequestAnnotationRepository for PullRequestAnnotationDatabaseRepository {
+    async fn get_pull_request_annotations(&self) -> Result<Vec<PullRequestAnnotation>> {
+        let annotations = sqlx::query_as::<_, PullRequestAnnotationDao>(
+            r#"
+                SELECT *
+                FROM pull_request_annotations
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let tags = sqlx::query_as::<_, PullRequestAnnotationTagDao>(
+            r#"
+                SELECT *
+                FROM pull_request_annotation_tags
+                ORDER BY tag
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = annotations
+            .into_iter()
+            .map(|x| x.into_domain(&tags))
+            .collect();
+        Ok(result)
+    }
+
+    async fn save_pull_request_annotation(
+        &self,
+        pull_request_annotation: PullRequestAnnotation,
+    ) -> Result<()> {
+        let dao: PullRequestAnnotationDao = (&pull_request_annotation).into();
+        // The annotation and its tags are replaced as a whole
+        let mut transaction = self.database_access.begin().await?;
+        sqlx::query(
+            r#"
+                INSERT INTO pull_request_annotations (git_provider, git_repository_id, pull_request_id, note, updated_at, closed_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                ON CONFLICT(git_repository_id, pull_request_id)
+                DO UPDATE SET git_provider = ?1, note = ?4, updated_at = ?5, closed_at = ?6
+            "#,
+        )
+        .bind(dao.git_provider)
+        .bind(dao.git_repository_id)
+        .bind(dao.pull_request_id)
+        .bind(dao.note)
+        .bind(dao.updated_at)
+        .bind(dao.closed_at)
+        .execute(&mut *transaction)
+        .await?;
+        sqlx::query(
+            r#"
+                DELETE FROM pull_request_annotation_tags
+                WHERE git_repository_id = ?1 AND pull_request_id = ?2
+            "#,
+        )
+        .bind(dao.git_repository_id)
+        .bind(dao.pull_request_id)
+        .execute(&mut *transaction)
+        .await?;
+        for tag in pull_request_annotation.tags {
+            sqlx::query(
+                r#"
+                    INSERT INTO pull_request_annotation_tags (git_repository_id, pull_request_id, tag)
+                    VALUES (?1, ?2, ?3)
+                "#,
+            )
+            .bind(dao.git_repository_id)
+            .bind(dao.pull_request_id)
+            .bind(tag)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_pull_request_annotation(
+        &self,
+        git_repository_id: &u32,
+        pull_request_id: &u32,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM pull_request_annotations
+                WHERE git_repository_id = ?1 AND pull_request_id = ?2
+            "#,
+        )
+        .bind(git_repository_id)
+        .bind(pull_request_id)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_closed_pull_request_annotations(
+        &self,
+        closed_before: &DateTime<Utc>,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+                DELETE FROM pull_request_annotations
+                WHERE closed_at IS NOT NULL AND closed_at < ?1
+            "#,
+        )
+        .bind(closed_before)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// Repository to access the recorded lifecycles of pull requests, stored in the database
+pub struct PullRequestLifecycleDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl PullRequestLifecycleDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl PullRequestLifecycleRepository for PullRequestLifecycleDatabaseRepository {
+    async fn get_pull_request_lifecycles(
+        &self,
+        git_repository_ids: &[u32],
+    ) -> Result<Vec<PullRequestLifecycle>> {
+        let lifecycles = sqlx::query_as::<_, PullRequestLifecycleDao>(
+            r#"
+                SELECT *
+                FROM pull_request_lifecycles
+                WHERE git_repository_id IN (SELECT value FROM json_each(?1))
+            "#,
+        )
+        .bind(serde_json::to_string(git_repository_ids)?)
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = lifecycles.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn get_pull_request_lifecycles_in_range(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> Result<Vec<PullRequestLifecycle>> {
+        let lifecycles = sqlx::query_as::<_, PullRequestLifecycleDao>(
+            r#"
+                SELECT *
+                FROM pull_request_lifecycles
+                WHERE (created_at >= ?1 AND created_at < ?2)
+                    OR (closed_at IS NOT NULL AND closed_at >= ?1 AND closed_at < ?2)
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = lifecycles.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn save_pull_request_lifecycles(
+        &self,
+        pull_request_lifecycles: Vec<PullRequestLifecycle>,
+    ) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for pull_request_lifecycle in pull_request_lifecycles {
+            let dao: PullRequestLifecycleDao = pull_request_lifecycle.into();
+            sqlx::query(
+                r#"
+                    INSERT INTO pull_request_lifecycles (git_provider, git_repository_id, git_repository_name, pull_request_id, creator_id, creator_name, status, created_at, first_vote_at, first_comment_at, closed_at, status_lookup_failure_count, status_lookup_failed_at)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+                    ON CONFLICT(git_repository_id, pull_request_id)
+                    DO UPDATE SET git_provider = ?1, git_repository_name = ?3, creator_id = ?5, creator_name = ?6, status = ?7, created_at = ?8, first_vote_at = ?9, first_comment_at = ?10, closed_at = ?11, status_lookup_failure_count = ?12, status_lookup_failed_at = ?13
+                "#,
+            )
+            .bind(dao.git_provider)
+            .bind(dao.git_repository_id)
+            .bind(dao.git_repository_name)
+            .bind(dao.pull_request_id)
+            .bind(dao.creator_id)
+            .bind(dao.creator_name)
+            .bind(dao.status)
+            .bind(dao.created_at)
+            .bind(dao.first_vote_at)
+            .bind(dao.first_comment_at)
+            .bind(dao.closed_at)
+            .bind(dao.status_lookup_failure_count)
+            .bind(dao.status_lookup_failed_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+/// Repository to access the repository groups, stored in the database
+pub struct RepositoryGroupDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl RepositoryGroupDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl RepositoryGroupRepository for RepositoryGroupDatabaseRepository {
+    async fn get_all_repository_groups(&self) -> Result<Vec<RepositoryGroup>> {
+        let groups = sqlx::query_as::<_, RepositoryGroupDao>(
+            r#"
+                SELECT *
+                FROM repository_groups
+                ORDER BY name
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let members = sqlx::query_as::<_, RepositoryGroupMemberDao>(
+            r#"
+                SELECT *
+                FROM repository_group_members
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = groups
+            .into_iter()
+            .map(|x| x.into_domain(&members))
+            .collect();
+        Ok(result)
+    }
+
+    async fn get_repository_group_by_id(&self, id: &u32) -> Result<RepositoryGroup> {
+        let group = sqlx::query_as::<_, RepositoryGroupDao>(
+            r#"
+                SELECT *
+                FROM repository_groups
+                WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&*self.database_access)
+        .await?
+        .ok_or(RepositoryGroupNotFoundError { id: *id })?;
+        let members = sqlx::query_as::<_, RepositoryGroupMemberDao>(
+            r#"
+                SELECT *
+                FROM repository_group_members
+                WHERE repository_group_id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = group.into_domain(&members);
+        Ok(result)
+    }
+
+    async fn create_repository_group(&self, name: &str) -> Result<u32> {
+        let id = sqlx::query_scalar::<_, u32>(
+            r#"
+                INSERT INTO repository_groups (name)
+                VALUES (?1)
+                RETURNING id
+            "#,
+        )
+        .bind(name)
+        .fetch_one(&*self.database_access)
+        .await?;
+        Ok(id)
+    }
+
+    async fn rename_repository_group(&self, id: &u32, name: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+                UPDATE repository_groups
+                SET name = ?1
+                WHERE id = ?2
+            "#,
+        )
+        .bind(name)
+        .bind(id)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_repository_group(&self, id: &u32) -> Result<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM repository_groups
+                WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+
+    async fn assign_git_repositories(&self, id: &u32, git_repository_ids: &[u32]) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for git_repository_id in git_repository_ids {
+            sqlx::query(
+                r#"
+                    INSERT OR IGNORE INTO repository_group_members (repository_group_id, git_repository_id)
+                    VALUES (?1, ?2)
+                "#,
+            )
+            .bind(id)
+            .bind(git_repository_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn unassign_git_repositories(&self, id: &u32, git_repository_ids: &[u32]) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        for git_repository_id in git_repository_ids {
+            sqlx::query(
+                r#"
+                    DELETE FROM repository_group_members
+                    WHERE repository_group_id = ?1 AND git_repository_id = ?2
+                "#,
+            )
+            .bind(id)
+            .bind(git_repository_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+}
+
+/// Repository to access the metadata of stored PATs, stored in the database
+pub struct PatMetadataDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl PatMetadataDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl PatMetadataRepository for PatMetadataDatabaseRepository {
+    async fn get_all_pat_metadata(&self) -> Result<Vec<PatMetadata>> {
+        let pat_metadata = sqlx::query_as::<_, PatMetadataDao>(
+            r#"
+                SELECT *
+                FROM pat_metadata
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = pat_metadata.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn get_pat_metadata(&self, credential_key: &str) -> Result<Option<PatMetadata>> {
+        let pat_metadata = sqlx::query_as::<_, PatMetadataDao>(
+            r#"
+                SELECT *
+                FROM pat_metadata
+                WHERE credential_key = ?1
+            "#,
+        )
+        .bind(credential_key)
+        .fetch_optional(&*self.database_access)
+        .await?;
+        let result = pat_metadata.map(|x| x.into());
+        Ok(result)
+    }
+
+    async fn save_pat_metadata(&self, pat_metadata: PatMetadata) -> Result<()> {
+        let dao: PatMetadataDao = pat_metadata.into();
+        sqlx::query(
+            r#"
+                INSERT INTO pat_metadata (credential_key, expires_at, validated_at)
+                VALUES (?1, ?2, ?3)
+                ON CONFLICT(credential_key)
+                DO UPDATE SET expires_at = ?2, validated_at = ?3
+            "#,
+        )
+        .bind(dao.credential_key)
+        .bind(dao.expires_at)
+        .bind(dao.validated_at)
+        .execute(&*self.database_access)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Repository to access the SLA rules and holidays, stored in the database
+pub struct SlaRuleDatabaseRepository {
+    database_access: Arc<SqlitePool>,
+}
+
+impl SlaRuleDatabaseRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `database_access` - Access to the target database
+    pub fn new(database_access: Arc<SqlitePool>) -> Self {
+        Self { database_access }
+    }
+}
+
+#[async_trait]
+impl SlaRuleRepository for SlaRuleDatabaseRepository {
+    async fn get_sla_rules(&self) -> Result<Vec<SlaRule>> {
+        let sla_rules = sqlx::query_as::<_, SlaRuleDao>(
+            r#"
+                SELECT *
+                FROM sla_rules
+                ORDER BY name
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = sla_rules.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn create_sla_rule(&self, sla_rule: SlaRule) -> Result<u32> {
+        let dao: SlaRuleDao = sla_rule.into();
+        let id = sqlx::query_scalar::<_, u32>(
+            r#"
+                INSERT INTO sla_rules (name, kind, threshold_hours, is_business_hours, repository_group_id)
                 VALUES (?1, ?2, ?3, ?4, ?5)
+                RETURNING id
             "#,
         )
         .bind(dao.name)
-        .bind(dao.context)
-        .bind(dao.is_active)
-        .bind(dao.git_provider)
-        .bind(dao.pat_secret_key)
+        .bind(dao.kind)
+        .bind(dao.threshold_hours)
+        .bind(dao.is_business_hours)
+        .bind(dao.repository_group_id)
+        .fetch_one(&*self.database_access)
+        .await?;
+        Ok(id)
+    }
+
+    async fn update_sla_rule(&self, sla_rule: SlaRule) -> Result<()> {
+        let dao: SlaRuleDao = sla_rule.into();
+        let result = sqlx::query(
+            r#"
+                UPDATE sla_rules
+                SET name = ?1, kind = ?2, threshold_hours = ?3, is_business_hours = ?4, repository_group_id = ?5
+                WHERE id = ?6
+            "#,
+        )
+        .bind(dao.name)
+        .bind(dao.kind)
+        .bind(dao.threshold_hours)
+        .bind(dao.is_business_hours)
+        .bind(dao.repository_group_id)
+        .bind(dao.id)
         .execute(&*self.database_access)
         .await?;
+        if result.rows_affected() == 0 {
+            return Err(anyhow::anyhow!("The SLA rule `{}` does not exist", dao.id));
+        }
         Ok(())
     }
 
-    async fn delete_git_repository(&self, id: &u32) -> Result<()> {
+    async fn delete_sla_rule(&self, id: &u32) -> Result<()> {
         sqlx::query(
             r#"
-                DELETE FROM git_repositories
+                DELETE FROM sla_rules
                 WHERE id = ?1
             "#,
         )
@@ -101,4 +1077,40 @@ impl GitRepositoryRepository for GitRepositoryDatabaseRepository {
         .await?;
         Ok(())
     }
+
+    async fn get_holidays(&self) -> Result<Vec<Holiday>> {
+        let holidays = sqlx::query_as::<_, HolidayDao>(
+            r#"
+                SELECT *
+                FROM holidays
+                ORDER BY date
+            "#,
+        )
+        .fetch_all(&*self.database_access)
+        .await?;
+        let result = holidays.into_iter().map(|x| x.into()).collect();
+        Ok(result)
+    }
+
+    async fn save_holidays(&self, holidays: Vec<Holiday>) -> Result<()> {
+        let mut transaction = self.database_access.begin().await?;
+        sqlx::query("DELETE FROM holidays")
+            .execute(&mut *transaction)
+            .await?;
+        for holiday in holidays {
+            let dao: HolidayDao = holiday.into();
+            sqlx::query(
+                r#"
+                    INSERT INTO holidays (date, name)
+                    VALUES (?1, ?2)
+                "#,
+            )
+            .bind(dao.date)
+            .bind(dao.name)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
 }
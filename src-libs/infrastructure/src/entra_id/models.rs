@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-device-code#device-authorization-response
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct DeviceCodeResponse {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub expires_in: i64,
+    pub interval: u64,
+    pub message: String,
+}
+
+/// https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-device-code#successful-authentication-response
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_in: i64,
+}
+
+/// https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-device-code#expected-errors
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct TokenErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
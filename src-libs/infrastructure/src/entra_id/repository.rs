@@ -0,0 +1,175 @@
+use super::models::{DeviceCodeResponse, TokenErrorResponse, TokenResponse};
+use anyhow::Result;
+use application::traits::OAuthRepository;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::models::{DeviceCodeChallenge, OAuthTokens};
+use reqwest::Client;
+
+/// The authority that accepts work and school accounts of any tenant
+const ENTRA_ID_DEFAULT_AUTHORITY_URL: &str = "https://login.microsoftonline.com/organizations";
+/// The public client registered by Microsoft for the Azure CLI, which is
+/// pre-authorized to access Azure DevOps
+const ENTRA_ID_DEFAULT_CLIENT_ID: &str = "872cd9fa-d31f-45e0-9eab-6e460a02d1f1";
+/// The Azure DevOps resource, including a refresh token in the response
+const ENTRA_ID_AZURE_DEVOPS_SCOPE: &str =
+    "499b84ac-1321-427f-aa17-267ca6975798/.default offline_access";
+/// Overrides the authority, e.g. to sign in against a local mock token endpoint
+const ENTRA_ID_AUTHORITY_URL_ENV: &str = "PR_HUB_OAUTH_AUTHORITY_URL";
+/// Overrides the client to sign in with, e.g. an app registration of the own tenant
+const ENTRA_ID_CLIENT_ID_ENV: &str = "PR_HUB_OAUTH_CLIENT_ID";
+
+/// Repository to sign in with Microsoft Entra ID using its OAuth 2.0 endpoints
+pub struct EntraIdRestRepository {
+    http_client: Client,
+    authority_url: String,
+    client_id: String,
+}
+
+impl EntraIdRestRepository {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `authority_url` - The base URL of the OAuth endpoints, including the tenant
+    /// * `client_id` - The identifier of the public client to sign in with
+    pub fn new(authority_url: String, client_id: String) -> Self {
+        Self {
+            http_client: Client::new(),
+            authority_url: authority_url.trim_end_matches('/').to_string(),
+            client_id,
+        }
+    }
+
+    /// Get new tokens using a refresh token
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_token` - The refresh token issued by a previous sign in or refresh
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OAuthTokens>` - The issued tokens
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the refresh token expired or was revoked
+    pub async fn refresh_tokens(&self, refresh_token: &str) -> Result<OAuthTokens> {
+        let params = [
+            ("grant_type", "refresh_token"),
+            ("client_id", &self.client_id),
+            ("refresh_token", refresh_token),
+            ("scope", ENTRA_ID_AZURE_DEVOPS_SCOPE),
+        ];
+        match self.request_tokens(&params).await? {
+            Ok(tokens) => Ok(tokens),
+            Err(err) => Err(token_error(err)),
+        }
+    }
+
+    /// Helper to request tokens from the token endpoint
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The form parameters of the grant to request the tokens with
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Result<OAuthTokens, TokenErrorResponse>>` - The issued tokens or the
+    ///   error returned by the token endpoint
+    ///
+    /// # Errors
+    ///
+    /// Any error that might occur while performing the request
+    async fn request_tokens(
+        &self,
+        params: &[(&str, &str)],
+    ) -> Result<Result<OAuthTokens, TokenErrorResponse>> {
+        let url = format!("{}/oauth2/v2.0/token", self.authority_url);
+        let response = self.http_client.post(url).form(params).send().await?;
+        if !response.status().is_success() {
+            return Ok(Err(response.json::<TokenErrorResponse>().await?));
+        }
+        let tokens = response.json::<TokenResponse>().await?;
+        Ok(Ok(OAuthTokens {
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            expires_at: Utc::now() + Duration::seconds(tokens.expires_in),
+        }))
+    }
+}
+
+impl Default for EntraIdRestRepository {
+    /// Create a default instance that signs in for Azure DevOps, unless the
+    /// authority or client are overridden by environment variables
+    fn default() -> Self {
+        Self::new(
+            std::env::var(ENTRA_ID_AUTHORITY_URL_ENV)
+                .unwrap_or_else(|_| ENTRA_ID_DEFAULT_AUTHORITY_URL.to_string()),
+            std::env::var(ENTRA_ID_CLIENT_ID_ENV)
+                .unwrap_or_else(|_| ENTRA_ID_DEFAULT_CLIENT_ID.to_string()),
+        )
+    }
+}
+
+#[async_trait]
+impl OAuthRepository for EntraIdRestRepository {
+    async fn start_device_code_sign_in(&self) -> Result<DeviceCodeChallenge> {
+        let url = format!("{}/oauth2/v2.0/devicecode", self.authority_url);
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("scope", ENTRA_ID_AZURE_DEVOPS_SCOPE),
+        ];
+        let response = self
+            .http_client
+            .post(url)
+            .form(&params)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<DeviceCodeResponse>()
+            .await?;
+        Ok(DeviceCodeChallenge {
+            device_code: response.device_code,
+            user_code: response.user_code,
+            verification_uri: response.verification_uri,
+            message: response.message,
+            expires_at: Utc::now() + Duration::seconds(response.expires_in),
+            interval_seconds: response.interval,
+        })
+    }
+
+    async fn poll_device_code_sign_in(&self, device_code: &str) -> Result<Option<OAuthTokens>> {
+        let params = [
+            ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+            ("client_id", &self.client_id),
+            ("device_code", device_code),
+        ];
+        match self.request_tokens(&params).await? {
+            Ok(tokens) => Ok(Some(tokens)),
+            // The user did not complete the sign in in the browser yet
+            Err(err) if err.error == "authorization_pending" || err.error == "slow_down" => {
+                Ok(None)
+            }
+            Err(err) => Err(token_error(err)),
+        }
+    }
+}
+
+/// Helper to convert an error returned by the token endpoint
+///
+/// # Arguments
+///
+/// * `err` - The error returned by the token endpoint
+fn token_error(err: TokenErrorResponse) -> anyhow::Error {
+    match err.error.as_str() {
+        "authorization_declined" => anyhow::anyhow!("The sign in was declined"),
+        "expired_token" => anyhow::anyhow!("The sign in expired, please start it again"),
+        "invalid_grant" => anyhow::anyhow!("The sign in is no longer valid, please sign in again"),
+        _ => anyhow::anyhow!(
+            "The sign in failed with `{}`: {}",
+            err.error,
+            err.error_description.unwrap_or_default()
+        ),
+    }
+}
@@ -0,0 +1,223 @@
+use super::repository::EntraIdRestRepository;
+use anyhow::Result;
+use application::traits::SecretRepository;
+use chrono::{Duration, Utc};
+use domain::models::OAuthTokens;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::Mutex;
+
+/// Access tokens expiring within this number of minutes are refreshed
+/// in advance, so they do not expire while requests are in flight
+const ACCESS_TOKEN_REFRESH_MARGIN_MINUTES: i64 = 5;
+
+/// Provides short-lived access tokens for refresh tokens stored in the
+/// secret storage, refreshing them transparently when needed
+///
+/// Access tokens are only kept in memory and shared across queries. Each
+/// refresh token has its own lock, which is held while refreshing, so
+/// concurrent requests for the same organization do not redeem the refresh
+/// token multiple times, while other organizations are not blocked.
+pub struct AccessTokenProvider {
+    entra_id_repository: EntraIdRestRepository,
+    secret_repository: Arc<dyn SecretRepository>,
+    tokens: std::sync::Mutex<HashMap<String, Arc<Mutex<Option<OAuthTokens>>>>>,
+}
+
+impl AccessTokenProvider {
+    /// Create a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `entra_id_repository` - The repository to refresh the tokens with
+    /// * `secret_repository` - The repository to access the stored refresh tokens
+    pub fn new(
+        entra_id_repository: EntraIdRestRepository,
+        secret_repository: Arc<dyn SecretRepository>,
+    ) -> Self {
+        Self {
+            entra_id_repository,
+            secret_repository,
+            tokens: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get a valid access token for a stored refresh token
+    ///
+    /// # Arguments
+    ///
+    /// * `credential_key` - The key of the refresh token in the secret storage
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String>` - The access token
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the refresh token expired or was revoked
+    pub async fn get_access_token(&self, credential_key: &str) -> Result<String> {
+        let token = Arc::clone(
+            self.tokens
+                .lock()
+                .map_err(|_| anyhow::anyhow!("The access tokens could not be accessed"))?
+                .entry(credential_key.to_string())
+                .or_default(),
+        );
+        let mut token = token.lock().await;
+        let refresh_after = Utc::now() + Duration::minutes(ACCESS_TOKEN_REFRESH_MARGIN_MINUTES);
+        if let Some(cached) = token.as_ref()
+            && cached.expires_at > refresh_after
+        {
+            return Ok(cached.access_token.to_string());
+        }
+        let refresh_token = self.secret_repository.get_secret(credential_key).await?;
+        let refreshed = self
+            .entra_id_repository
+            .refresh_tokens(&refresh_token)
+            .await?;
+        // Refresh tokens are rotated, so the latest one must be kept to
+        // remain signed in beyond the lifetime of the previous one
+        if let Some(new_refresh_token) = &refreshed.refresh_token
+            && *new_refresh_token != refresh_token
+        {
+            self.secret_repository
+                .set_secret(credential_key, new_refresh_token)
+                .await?;
+        }
+        let access_token = refreshed.access_token.to_string();
+        *token = Some(refreshed);
+        Ok(access_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use mockito::{Matcher, Server};
+
+    /// Secret repository keeping the secrets in memory
+    #[derive(Default)]
+    struct InMemorySecretRepository {
+        secrets: std::sync::Mutex<HashMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl SecretRepository for InMemorySecretRepository {
+        async fn get_secret(&self, key: &str) -> Result<String> {
+            self.secrets
+                .lock()
+                .unwrap()
+                .get(key)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("The secret `{}` does not exist", key))
+        }
+
+        async fn get_secret_keys(&self) -> Result<Vec<String>> {
+            Ok(self.secrets.lock().unwrap().keys().cloned().collect())
+        }
+
+        async fn set_secret(&self, key: &str, value: &str) -> Result<()> {
+            self.secrets
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        async fn delete_secret(&self, key: &str) -> Result<()> {
+            self.secrets.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_redeem_the_refresh_token_once_per_key() {
+        let mut server = Server::new_async().await;
+        let mut mocks = vec![];
+        for key in ["first", "second"] {
+            let mock = server
+                .mock("POST", "/oauth2/v2.0/token")
+                .match_body(Matcher::UrlEncoded(
+                    "refresh_token".to_string(),
+                    format!("{}-refresh-token", key),
+                ))
+                .with_header("content-type", "application/json")
+                .with_body(format!(
+                    r#"{{"access_token":"{0}-access-token","refresh_token":"{0}-refresh-token-rotated","expires_in":3600}}"#,
+                    key
+                ))
+                .expect(1)
+                .create_async()
+                .await;
+            mocks.push(mock);
+        }
+        let secret_repository = Arc::new(InMemorySecretRepository::default());
+        secret_repository
+            .set_secret("first", "first-refresh-token")
+            .await
+            .unwrap();
+        secret_repository
+            .set_secret("second", "second-refresh-token")
+            .await
+            .unwrap();
+        let provider = Arc::new(AccessTokenProvider::new(
+            EntraIdRestRepository::new(server.url(), "client".to_string()),
+            secret_repository.clone(),
+        ));
+
+        let mut handles = vec![];
+        for key in ["first", "second", "first", "second", "first"] {
+            let provider = Arc::clone(&provider);
+            handles.push(tokio::spawn(async move {
+                (key, provider.get_access_token(key).await.unwrap())
+            }));
+        }
+        for handle in handles {
+            let (key, access_token) = handle.await.unwrap();
+            assert_eq!(access_token, format!("{}-access-token", key));
+        }
+
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+        assert_eq!(
+            secret_repository.get_secret("first").await.unwrap(),
+            "first-refresh-token-rotated"
+        );
+        assert_eq!(
+            secret_repository.get_secret("second").await.unwrap(),
+            "second-refresh-token-rotated"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_refresh_tokens_are_not_cached() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/oauth2/v2.0/token")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":"invalid_grant"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let secret_repository = Arc::new(InMemorySecretRepository::default());
+        secret_repository
+            .set_secret("revoked", "revoked-refresh-token")
+            .await
+            .unwrap();
+        let provider = AccessTokenProvider::new(
+            EntraIdRestRepository::new(server.url(), "client".to_string()),
+            secret_repository.clone(),
+        );
+
+        assert!(provider.get_access_token("revoked").await.is_err());
+        assert!(provider.get_access_token("revoked").await.is_err());
+
+        mock.assert_async().await;
+        assert_eq!(
+            secret_repository.get_secret("revoked").await.unwrap(),
+            "revoked-refresh-token"
+        );
+    }
+}
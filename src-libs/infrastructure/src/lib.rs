@@ -1,3 +1,6 @@
 pub mod azure_devops;
 pub mod database;
+pub mod entra_id;
+pub mod profiles;
 pub mod secret_storage;
+pub mod settings;
@@ -0,0 +1,319 @@
+pub mod active_profile;
+
+use crate::{database::connection::init_db_connection, secret_storage::KeyringRepository};
+use anyhow::Result;
+use application::traits::ProfileRepository;
+use async_trait::async_trait;
+use chacha20poly1305::aead::{OsRng, rand_core::RngCore};
+use domain::models::Profile;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+/// The name of the default profile, if it was never renamed
+const DEFAULT_PROFILE_NAME: &str = "Default";
+/// The service name to store the secrets of the default profile with in the keyring
+const DEFAULT_SECRET_SERVICE_NAME: &str = "pr-hub";
+
+/// The profiles as stored in the profiles file
+#[derive(Serialize, Deserialize, Clone)]
+struct ProfilesFile {
+    #[serde(rename = "activeProfileId")]
+    active_profile_id: String,
+    profiles: Vec<ProfileValue>,
+}
+
+impl Default for ProfilesFile {
+    fn default() -> Self {
+        Self {
+            active_profile_id: Profile::DEFAULT_ID.to_string(),
+            profiles: vec![],
+        }
+    }
+}
+
+/// A single profile as stored in the profiles file
+#[derive(Serialize, Deserialize, Clone)]
+struct ProfileValue {
+    id: String,
+    name: String,
+}
+
+impl From<&ProfileValue> for Profile {
+    fn from(value: &ProfileValue) -> Self {
+        Self {
+            id: value.id.to_string(),
+            name: value.name.to_string(),
+        }
+    }
+}
+
+/// Repository to access the profiles stored as JSON file
+///
+/// The default profile always exists and keeps using the data in the app
+/// data dir, so the data created before profiles were introduced is kept.
+/// Every other profile stores its data in its own directory below.
+#[derive(Clone)]
+pub struct ProfileFileRepository {
+    app_data_dir_path: PathBuf,
+    profiles_file: Arc<RwLock<ProfilesFile>>,
+}
+
+impl ProfileFileRepository {
+    /// Create a new instance, loading the profiles from the file if it exists
+    ///
+    /// # Arguments
+    ///
+    /// * `app_data_dir_path` - The path to the app data dir, containing the profiles file
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file is not valid JSON
+    pub fn new(app_data_dir_path: PathBuf) -> Result<Self> {
+        let file_path = app_data_dir_path.join("profiles.json");
+        let mut profiles_file = match file_path.exists() {
+            true => serde_json::from_str::<ProfilesFile>(&std::fs::read_to_string(&file_path)?)?,
+            false => ProfilesFile::default(),
+        };
+        if !profiles_file
+            .profiles
+            .iter()
+            .any(|x| x.id == Profile::DEFAULT_ID)
+        {
+            profiles_file.profiles.insert(
+                0,
+                ProfileValue {
+                    id: Profile::DEFAULT_ID.to_string(),
+                    name: DEFAULT_PROFILE_NAME.to_string(),
+                },
+            );
+        }
+        if !profiles_file
+            .profiles
+            .iter()
+            .any(|x| x.id == profiles_file.active_profile_id)
+        {
+            profiles_file.active_profile_id = Profile::DEFAULT_ID.to_string();
+        }
+        Ok(Self {
+            app_data_dir_path,
+            profiles_file: Arc::new(RwLock::new(profiles_file)),
+        })
+    }
+
+    /// Get the profile that is currently active without waiting
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Profile>` - The active profile
+    ///
+    /// # Errors
+    ///
+    /// If the profiles can not be read
+    pub fn current(&self) -> Result<Profile> {
+        let profiles_file = self.read()?;
+        profiles_file
+            .profiles
+            .iter()
+            .find(|x| x.id == profiles_file.active_profile_id)
+            .map(|x| x.into())
+            .ok_or_else(|| anyhow::anyhow!("The active profile does not exist"))
+    }
+
+    /// Get the directory to store the data of a profile in, such as its database
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile to get the directory of
+    pub fn data_dir_path(&self, profile: &Profile) -> PathBuf {
+        match profile.is_default() {
+            true => self.app_data_dir_path.clone(),
+            false => self.app_data_dir_path.join("profiles").join(&profile.id),
+        }
+    }
+
+    /// Get the service name to store the secrets of a profile with in the keyring
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile to get the service name of
+    pub fn secret_service_name(&self, profile: &Profile) -> String {
+        match profile.is_default() {
+            true => DEFAULT_SECRET_SERVICE_NAME.to_string(),
+            false => format!("{}-{}", DEFAULT_SECRET_SERVICE_NAME, profile.id),
+        }
+    }
+
+    /// Helper to access the profiles held in memory
+    fn read(&self) -> Result<std::sync::RwLockReadGuard<'_, ProfilesFile>> {
+        self.profiles_file
+            .read()
+            .map_err(|_| anyhow::anyhow!("The profiles could not be read"))
+    }
+
+    /// Change the profiles, writing them to the file before applying them in memory
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - Changes the profiles, returning a value on success
+    ///
+    /// # Errors
+    ///
+    /// Any errors of the change or while writing the file
+    fn update<T, F>(&self, change: F) -> Result<T>
+    where
+        F: FnOnce(&mut ProfilesFile) -> Result<T>,
+    {
+        let mut current = self
+            .profiles_file
+            .write()
+            .map_err(|_| anyhow::anyhow!("The profiles could not be updated"))?;
+        let mut profiles_file = current.clone();
+        let value = change(&mut profiles_file)?;
+        std::fs::create_dir_all(&self.app_data_dir_path)?;
+        std::fs::write(
+            self.app_data_dir_path.join("profiles.json"),
+            serde_json::to_string_pretty(&profiles_file)?,
+        )?;
+        *current = profiles_file;
+        Ok(value)
+    }
+}
+
+#[async_trait]
+impl ProfileRepository for ProfileFileRepository {
+    async fn get_profiles(&self) -> Result<Vec<Profile>> {
+        Ok(self.read()?.profiles.iter().map(|x| x.into()).collect())
+    }
+
+    async fn get_active_profile(&self) -> Result<Profile> {
+        self.current()
+    }
+
+    async fn create_profile(&self, name: &str) -> Result<Profile> {
+        self.update(|profiles_file| {
+            let profile = ProfileValue {
+                id: generate_profile_id(name, &profiles_file.profiles),
+                name: name.to_string(),
+            };
+            profiles_file.profiles.push(profile.clone());
+            Ok((&profile).into())
+        })
+    }
+
+    async fn rename_profile(&self, id: &str, name: &str) -> Result<()> {
+        self.update(|profiles_file| {
+            let profile = profiles_file
+                .profiles
+                .iter_mut()
+                .find(|x| x.id == id)
+                .ok_or_else(|| anyhow::anyhow!("The profile `{}` does not exist", id))?;
+            profile.name = name.to_string();
+            Ok(())
+        })
+    }
+
+    async fn delete_profile(&self, id: &str) -> Result<()> {
+        let profile = self
+            .read()?
+            .profiles
+            .iter()
+            .find(|x| x.id == id)
+            .map(Profile::from)
+            .ok_or_else(|| anyhow::anyhow!("The profile `{}` does not exist", id))?;
+        if profile.is_default() {
+            return Err(anyhow::anyhow!("The default profile can not be removed"));
+        }
+        let data_dir_path = self.data_dir_path(&profile);
+        // The secrets are deleted first, so a failure keeps the profile and
+        // removing it can be retried instead of leaving credentials behind.
+        // The encrypted secret file is removed along with the data dir.
+        let credential_keys = match data_dir_path.exists() {
+            true => get_credential_keys(&data_dir_path).await?,
+            false => vec![],
+        };
+        KeyringRepository::new(self.secret_service_name(&profile))
+            .delete_all_secrets(credential_keys)
+            .await?;
+        self.update(|profiles_file| {
+            profiles_file.profiles.retain(|x| x.id != id);
+            Ok(())
+        })?;
+        if data_dir_path.exists() {
+            std::fs::remove_dir_all(data_dir_path)?;
+        }
+        Ok(())
+    }
+
+    async fn set_active_profile(&self, id: &str) -> Result<()> {
+        self.update(|profiles_file| {
+            if !profiles_file.profiles.iter().any(|x| x.id == id) {
+                return Err(anyhow::anyhow!("The profile `{}` does not exist", id));
+            }
+            profiles_file.active_profile_id = id.to_string();
+            Ok(())
+        })
+    }
+}
+
+/// Helper to get the keys of the secrets referenced in the database of a profile
+///
+/// # Arguments
+///
+/// * `data_dir_path` - The directory the data of the profile is stored in
+///
+/// # Errors
+///
+/// Any errors that might occur, e.g. when the database can not be opened
+async fn get_credential_keys(data_dir_path: &Path) -> Result<Vec<String>> {
+    let pool = init_db_connection(
+        data_dir_path
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("The data dir needs to be represented as string"))?,
+    )
+    .await?;
+    let credential_keys = sqlx::query_scalar::<_, String>(
+        "SELECT credential_key FROM git_repositories UNION SELECT credential_key FROM pat_metadata",
+    )
+    .fetch_all(&pool)
+    .await;
+    // The database file is removed afterwards, so it must no longer be open
+    pool.close().await;
+    Ok(credential_keys?)
+}
+
+/// Helper to generate a unique identifier for a new profile from its name,
+/// which is safe to use as directory and keyring service name
+///
+/// A random suffix is appended to the name, so a new profile never reuses the
+/// identifier of a deleted one and with it any secrets it failed to delete.
+///
+/// # Arguments
+///
+/// * `name` - The name of the profile
+/// * `profiles` - The existing profiles
+fn generate_profile_id(name: &str, profiles: &[ProfileValue]) -> String {
+    let slug = name
+        .chars()
+        .map(|x| match x.is_ascii_alphanumeric() {
+            true => x.to_ascii_lowercase(),
+            false => '-',
+        })
+        .collect::<String>()
+        .split('-')
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-");
+    let slug = match slug.is_empty() {
+        true => "profile".to_string(),
+        false => slug,
+    };
+    loop {
+        let id = format!("{}-{:08x}", slug, OsRng.next_u32());
+        if id != Profile::DEFAULT_ID && !profiles.iter().any(|x| x.id == id) {
+            return id;
+        }
+    }
+}
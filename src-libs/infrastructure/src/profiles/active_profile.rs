@@ -0,0 +1,162 @@
+use super::ProfileFileRepository;
+use crate::{
+    azure_devops::cache::{ChangeStatisticsCache, PullRequestIterationsCache},
+    database::connection::init_db_connection,
+    entra_id::{repository::EntraIdRestRepository, token_provider::AccessTokenProvider},
+    secret_storage::{
+        KeyringRepository, configured::ConfiguredSecretRepository,
+        encrypted_file::EncryptedFileRepository,
+    },
+    settings::SettingsFileRepository,
+};
+use anyhow::Result;
+use application::traits::ProfileActivator;
+use async_trait::async_trait;
+use domain::models::Profile;
+use sqlx::SqlitePool;
+use std::sync::{Arc, RwLock};
+
+/// The dependencies bound to a single profile
+struct ProfileDependencies {
+    database_connection_pool: Arc<SqlitePool>,
+    change_statistics_cache: Arc<ChangeStatisticsCache>,
+    pull_request_iterations_cache: Arc<PullRequestIterationsCache>,
+    access_token_provider: Arc<AccessTokenProvider>,
+    secret_repository: ConfiguredSecretRepository,
+}
+
+impl ProfileDependencies {
+    /// Open the database and secret storage of a profile
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to locate the data of the profile
+    /// * `settings_repository` - The repository to access the settings, shared by all profiles
+    /// * `profile` - The profile to load the dependencies of
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the database can not be opened
+    async fn load(
+        profile_repository: &ProfileFileRepository,
+        settings_repository: &SettingsFileRepository,
+        profile: &Profile,
+    ) -> Result<Self> {
+        let data_dir_path = profile_repository.data_dir_path(profile);
+        std::fs::create_dir_all(&data_dir_path)?;
+        let database_connection_pool =
+            init_db_connection(data_dir_path.to_str().ok_or_else(|| {
+                anyhow::anyhow!("The data dir needs to be represented as string")
+            })?)
+            .await?;
+        let secret_repository = ConfiguredSecretRepository::new(
+            settings_repository.clone(),
+            Arc::new(KeyringRepository::new(
+                profile_repository.secret_service_name(profile),
+            )),
+            Arc::new(EncryptedFileRepository::new(
+                data_dir_path.join("secrets.json"),
+            )),
+        );
+        Ok(Self {
+            database_connection_pool: Arc::new(database_connection_pool),
+            change_statistics_cache: Arc::new(ChangeStatisticsCache::default()),
+            pull_request_iterations_cache: Arc::new(PullRequestIterationsCache::default()),
+            access_token_provider: Arc::new(AccessTokenProvider::new(
+                EntraIdRestRepository::default(),
+                Arc::new(secret_repository.clone()),
+            )),
+            secret_repository,
+        })
+    }
+}
+
+/// Holds the dependencies bound to the active profile, such as the
+/// database connection pool and the secret storage
+///
+/// Switching the profile replaces all of them at once. Repositories created
+/// before keep using the previous ones until they are dropped, so requests
+/// still running complete against the profile they were started in.
+#[derive(Clone)]
+pub struct ActiveProfile {
+    profile_repository: ProfileFileRepository,
+    settings_repository: SettingsFileRepository,
+    dependencies: Arc<RwLock<Arc<ProfileDependencies>>>,
+}
+
+impl ActiveProfile {
+    /// Load the dependencies of the profile that was active the last time
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_repository` - The repository to access profiles
+    /// * `settings_repository` - The repository to access the settings, shared by all profiles
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the database can not be opened
+    pub async fn load(
+        profile_repository: ProfileFileRepository,
+        settings_repository: SettingsFileRepository,
+    ) -> Result<Self> {
+        let profile = profile_repository.current()?;
+        let dependencies =
+            ProfileDependencies::load(&profile_repository, &settings_repository, &profile).await?;
+        Ok(Self {
+            profile_repository,
+            settings_repository,
+            dependencies: Arc::new(RwLock::new(Arc::new(dependencies))),
+        })
+    }
+
+    /// Get the connection pool to the database of the active profile
+    pub fn database_connection_pool(&self) -> Arc<SqlitePool> {
+        Arc::clone(&self.dependencies().database_connection_pool)
+    }
+
+    /// Get the change statistics cache of the active profile
+    pub fn change_statistics_cache(&self) -> Arc<ChangeStatisticsCache> {
+        Arc::clone(&self.dependencies().change_statistics_cache)
+    }
+
+    /// Get the pull request iterations cache of the active profile
+    pub fn pull_request_iterations_cache(&self) -> Arc<PullRequestIterationsCache> {
+        Arc::clone(&self.dependencies().pull_request_iterations_cache)
+    }
+
+    /// Get the provider of OAuth access tokens of the active profile
+    pub fn access_token_provider(&self) -> Arc<AccessTokenProvider> {
+        Arc::clone(&self.dependencies().access_token_provider)
+    }
+
+    /// Get the repository to access the secrets of the active profile
+    pub fn secret_repository(&self) -> ConfiguredSecretRepository {
+        self.dependencies().secret_repository.clone()
+    }
+
+    /// Helper to access the dependencies of the active profile
+    fn dependencies(&self) -> Arc<ProfileDependencies> {
+        match self.dependencies.read() {
+            Ok(x) => Arc::clone(&x),
+            // The lock is only held to replace the dependencies as a whole,
+            // so they are consistent even if that panicked
+            Err(err) => Arc::clone(&err.into_inner()),
+        }
+    }
+}
+
+#[async_trait]
+impl ProfileActivator for ActiveProfile {
+    async fn activate_profile(&self, profile: &Profile) -> Result<()> {
+        let dependencies =
+            ProfileDependencies::load(&self.profile_repository, &self.settings_repository, profile)
+                .await?;
+        let mut current = self
+            .dependencies
+            .write()
+            .map_err(|_| anyhow::anyhow!("The active profile could not be switched"))?;
+        *current = Arc::new(dependencies);
+        log::info!("Switched to profile `{}`", profile.id);
+        Ok(())
+    }
+}
@@ -1,10 +1,35 @@
+pub mod configured;
+pub mod credential_helper;
+mod crypto;
+pub mod encrypted_file;
+pub mod environment;
+pub mod passphrase_cipher;
+
 use anyhow::Result;
 use application::traits::SecretRepository;
+use async_trait::async_trait;
+use domain::errors::SecretNotFoundError;
 use keyring::Entry;
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
+use tokio::task::spawn_blocking;
+
+/// The key of the entry listing the keys of all stored secrets, as the
+/// keyring can not be enumerated
+const KEYRING_INDEX_KEY: &str = "pr-hub-secret-keys";
 
 /// Repository to access secrets using keyring
+///
+/// The keyring is accessed synchronously, e.g. via D-Bus on Linux, therefore
+/// all calls are run on the threads dedicated to blocking operations. The keys
+/// of the stored secrets are listed in an additional entry, which only knows
+/// about secrets stored after it was introduced.
+#[derive(Clone)]
 pub struct KeyringRepository {
     service_name: String,
+    index_lock: Arc<Mutex<()>>,
 }
 
 impl KeyringRepository {
@@ -14,20 +39,147 @@ impl KeyringRepository {
     ///
     /// * `service_name` - The service identifier to use for storing secrets
     pub fn new(service_name: String) -> Self {
-        Self { service_name }
+        Self {
+            service_name,
+            index_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Get the keys listed in the index entry, blocking the current thread
+    fn read_index(&self) -> Result<BTreeSet<String>> {
+        match Entry::new(&self.service_name, KEYRING_INDEX_KEY)?.get_password() {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(keyring::Error::NoEntry) => Ok(BTreeSet::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Add or remove a key from the index entry, blocking the current thread
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the stored or deleted secret
+    /// * `is_stored` - Whether the secret was stored or deleted
+    fn update_index(&self, key: &str, is_stored: bool) -> Result<()> {
+        let _index_lock = self
+            .index_lock
+            .lock()
+            .map_err(|_| anyhow::anyhow!("The keyring index could not be accessed"))?;
+        let mut keys = self.read_index()?;
+        let is_changed = match is_stored {
+            true => keys.insert(key.to_string()),
+            false => keys.remove(key),
+        };
+        if is_changed {
+            Entry::new(&self.service_name, KEYRING_INDEX_KEY)?
+                .set_password(&serde_json::to_string(&keys)?)?;
+        }
+        Ok(())
+    }
+
+    /// Get a secret, blocking the current thread
+    fn get_secret_blocking(&self, key: &str) -> Result<String> {
+        match Entry::new(&self.service_name, key)?.get_password() {
+            Ok(secret) => Ok(secret),
+            Err(keyring::Error::NoEntry) => Err(SecretNotFoundError {
+                key: key.to_string(),
+            }
+            .into()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Store a secret, blocking the current thread
+    fn set_secret_blocking(&self, key: &str, value: &str) -> Result<()> {
+        Entry::new(&self.service_name, key)?.set_password(value)?;
+        // The secret itself is stored, it is only not listed
+        if let Err(err) = self.update_index(key, true) {
+            log::warn!(
+                "Error listing secret `{}` in the keyring index: {}",
+                key,
+                err
+            );
+        }
+        Ok(())
+    }
+
+    /// Delete a secret, blocking the current thread
+    fn delete_secret_blocking(&self, key: &str) -> Result<()> {
+        match Entry::new(&self.service_name, key)?.delete_password() {
+            Ok(()) => {}
+            Err(keyring::Error::NoEntry) => {
+                return Err(SecretNotFoundError {
+                    key: key.to_string(),
+                }
+                .into());
+            }
+            Err(err) => return Err(err.into()),
+        }
+        if let Err(err) = self.update_index(key, false) {
+            log::warn!(
+                "Error removing secret `{}` from the keyring index: {}",
+                key,
+                err
+            );
+        }
+        Ok(())
+    }
+
+    /// Delete the listed secrets, the given ones and the index entry itself,
+    /// blocking the current thread
+    fn delete_all_secrets_blocking(&self, keys: Vec<String>) -> Result<()> {
+        let mut keys = keys.into_iter().collect::<BTreeSet<String>>();
+        keys.extend(self.read_index()?);
+        keys.insert(KEYRING_INDEX_KEY.to_string());
+        for key in keys {
+            match Entry::new(&self.service_name, &key)?.delete_password() {
+                Ok(()) | Err(keyring::Error::NoEntry) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete all secrets stored with the service name, e.g. when the profile
+    /// owning them is removed
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys of secrets that might not be listed in the index,
+    ///   e.g. the ones referenced in the database
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the keyring is not accessible
+    pub async fn delete_all_secrets(&self, keys: Vec<String>) -> Result<()> {
+        let repository = self.clone();
+        spawn_blocking(move || repository.delete_all_secrets_blocking(keys)).await?
     }
 }
 
+#[async_trait]
 impl SecretRepository for KeyringRepository {
-    fn get_secret(&self, key: &str) -> Result<String> {
-        Ok(Entry::new(&self.service_name, key)?.get_password()?)
+    async fn get_secret(&self, key: &str) -> Result<String> {
+        let repository = self.clone();
+        let key = key.to_string();
+        spawn_blocking(move || repository.get_secret_blocking(&key)).await?
+    }
+
+    async fn get_secret_keys(&self) -> Result<Vec<String>> {
+        let repository = self.clone();
+        let keys = spawn_blocking(move || repository.read_index()).await??;
+        Ok(keys.into_iter().collect())
     }
 
-    fn set_secret(&self, key: &str, value: &str) -> Result<()> {
-        Ok(Entry::new(&self.service_name, key)?.set_password(value)?)
+    async fn set_secret(&self, key: &str, value: &str) -> Result<()> {
+        let repository = self.clone();
+        let (key, value) = (key.to_string(), value.to_string());
+        spawn_blocking(move || repository.set_secret_blocking(&key, &value)).await?
     }
 
-    fn delete_secret(&self, key: &str) -> Result<()> {
-        Ok(Entry::new(&self.service_name, key)?.delete_password()?)
+    async fn delete_secret(&self, key: &str) -> Result<()> {
+        let repository = self.clone();
+        let key = key.to_string();
+        spawn_blocking(move || repository.delete_secret_blocking(&key)).await?
     }
 }
@@ -0,0 +1,168 @@
+use super::{
+    KeyringRepository, credential_helper::CredentialHelperRepository,
+    encrypted_file::EncryptedFileRepository, environment::EnvironmentSecretRepository,
+};
+use crate::settings::SettingsFileRepository;
+use anyhow::Result;
+use application::traits::{LockableSecretRepository, SecretRepository};
+use async_trait::async_trait;
+use domain::{
+    enums::{SecretSource, SecretStorageBackend},
+    errors::SecretNotFoundError,
+};
+use std::{future::Future, pin::Pin, sync::Arc};
+
+/// The future of an operation on a secret storage backend
+type BackendFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// Repository to access secrets in the sources and backend selected by the settings
+///
+/// Secrets are looked up in the configured sources in order, but only stored in
+/// and deleted from the selected backend. When the keyring is selected but not
+/// available, e.g. on Linux machines without a Secret Service daemon, the
+/// encrypted file is used instead.
+#[derive(Clone)]
+pub struct ConfiguredSecretRepository {
+    settings_repository: SettingsFileRepository,
+    environment_repository: Arc<EnvironmentSecretRepository>,
+    keyring_repository: Arc<KeyringRepository>,
+    encrypted_file_repository: Arc<EncryptedFileRepository>,
+}
+
+impl ConfiguredSecretRepository {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `settings_repository` - The repository to access the selected backend
+    /// * `keyring_repository` - The backend to access the keyring with
+    /// * `encrypted_file_repository` - The backend to access the encrypted file with
+    pub fn new(
+        settings_repository: SettingsFileRepository,
+        keyring_repository: Arc<KeyringRepository>,
+        encrypted_file_repository: Arc<EncryptedFileRepository>,
+    ) -> Self {
+        Self {
+            settings_repository,
+            environment_repository: Arc::new(EnvironmentSecretRepository::default()),
+            keyring_repository,
+            encrypted_file_repository,
+        }
+    }
+
+    /// Get a single secret storage backend, regardless of the settings
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The backend to get
+    pub fn backend(&self, backend: SecretStorageBackend) -> Arc<dyn SecretRepository> {
+        match backend {
+            SecretStorageBackend::Keyring => self.keyring_repository.clone(),
+            SecretStorageBackend::EncryptedFile => self.encrypted_file_repository.clone(),
+        }
+    }
+
+    /// Perform an operation on the selected backend, falling back to the
+    /// encrypted file if the keyring is not available
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation to perform
+    ///
+    /// # Errors
+    ///
+    /// Any errors of the operation
+    async fn with_backend<'a, T, F>(&'a self, operation: F) -> Result<T>
+    where
+        F: Fn(&'a dyn SecretRepository) -> BackendFuture<'a, T>,
+    {
+        match self.settings_repository.current().secret_storage_backend {
+            SecretStorageBackend::EncryptedFile => {
+                operation(self.encrypted_file_repository.as_ref()).await
+            }
+            SecretStorageBackend::Keyring => {
+                match operation(self.keyring_repository.as_ref()).await {
+                    Err(err) if is_keyring_unavailable(&err) => {
+                        log::warn!(
+                            "Keyring is not available, falling back to the encrypted file: {}",
+                            err
+                        );
+                        operation(self.encrypted_file_repository.as_ref()).await
+                    }
+                    result => result,
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl SecretRepository for ConfiguredSecretRepository {
+    async fn get_secret(&self, key: &str) -> Result<String> {
+        let settings = self.settings_repository.current();
+        let mut last_err = None::<anyhow::Error>;
+        for source in settings.secret_sources {
+            let result = match source {
+                SecretSource::Environment => self.environment_repository.get_secret(key).await,
+                SecretSource::CredentialHelper => match &settings.credential_helper_command {
+                    Some(command) => {
+                        CredentialHelperRepository::new(command.to_string())
+                            .get_secret(key)
+                            .await
+                    }
+                    None => continue,
+                },
+                SecretSource::Storage => self.with_backend(|x| x.get_secret(key)).await,
+            };
+            match result {
+                Ok(secret) => {
+                    log::debug!("Secret `{}` provided by {:?}", key, source);
+                    return Ok(secret);
+                }
+                Err(err) => {
+                    log::debug!("Secret `{}` not found in {:?}: {}", key, source, err);
+                    // Failing sources are reported rather than the secret being missing
+                    if last_err
+                        .as_ref()
+                        .is_none_or(|x| x.is::<SecretNotFoundError>())
+                    {
+                        last_err = Some(err);
+                    }
+                }
+            }
+        }
+        Err(last_err.unwrap_or_else(|| anyhow::anyhow!("No secret source is configured")))
+    }
+
+    async fn get_secret_keys(&self) -> Result<Vec<String>> {
+        self.with_backend(|x| x.get_secret_keys()).await
+    }
+
+    async fn set_secret(&self, key: &str, value: &str) -> Result<()> {
+        self.with_backend(|x| x.set_secret(key, value)).await
+    }
+
+    async fn delete_secret(&self, key: &str) -> Result<()> {
+        self.with_backend(|x| x.delete_secret(key)).await
+    }
+}
+
+#[async_trait]
+impl LockableSecretRepository for ConfiguredSecretRepository {
+    async fn unlock(&self, passphrase: &str) -> Result<()> {
+        self.encrypted_file_repository.unlock(passphrase).await
+    }
+}
+
+/// Helper to check whether an error indicates, that the keyring itself can
+/// not be used, rather than a single secret is missing
+///
+/// # Arguments
+///
+/// * `err` - The error returned by the keyring
+fn is_keyring_unavailable(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<keyring::Error>(),
+        Some(keyring::Error::PlatformFailure(_) | keyring::Error::NoStorageAccess(_))
+    )
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use application::traits::SecretRepository;
+use async_trait::async_trait;
+use domain::errors::SecretNotFoundError;
+use std::{process::Stdio, time::Duration};
+use tokio::process::Command;
+
+/// The environment variable the key of the requested secret is passed in
+const CREDENTIAL_HELPER_KEY_ENV: &str = "PR_HUB_SECRET_KEY";
+/// How long the credential helper may run, before it is killed, e.g. when
+/// it waits for input that is never entered
+const CREDENTIAL_HELPER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Repository to read secrets by running an external credential helper
+///
+/// The command is run by the shell, with the key of the requested secret in
+/// the `PR_HUB_SECRET_KEY` environment variable, e.g.
+/// `pass show "pr-hub/$PR_HUB_SECRET_KEY"`. The first line the command
+/// prints is used as secret, so helpers that print metadata after the
+/// secret itself are supported as well. Helpers that do not finish within
+/// the timeout are killed.
+pub struct CredentialHelperRepository {
+    command: String,
+}
+
+impl CredentialHelperRepository {
+    /// Creates a new instance
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The shell command to run to get a secret
+    pub fn new(command: String) -> Self {
+        Self { command }
+    }
+
+    /// Create the process to run the command with the shell of the platform
+    fn shell_command(&self) -> Command {
+        #[cfg(windows)]
+        {
+            let mut command = Command::new("cmd");
+            command.args(["/C", &self.command]);
+            command
+        }
+        #[cfg(not(windows))]
+        {
+            let mut command = Command::new("sh");
+            command.args(["-c", &self.command]);
+            command
+        }
+    }
+}
+
+#[async_trait]
+impl SecretRepository for CredentialHelperRepository {
+    async fn get_secret(&self, key: &str) -> Result<String> {
+        let mut command = self.shell_command();
+        command
+            .env(CREDENTIAL_HELPER_KEY_ENV, key)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        let child = command.spawn()?;
+        // The child is killed when dropped after the timeout expired
+        let output = tokio::time::timeout(CREDENTIAL_HELPER_TIMEOUT, child.wait_with_output())
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "The credential helper did not finish for key `{}` within {} seconds",
+                    key,
+                    CREDENTIAL_HELPER_TIMEOUT.as_secs()
+                )
+            })??;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "The credential helper failed for key `{}` with `{}`: {}",
+                key,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let stdout = String::from_utf8(output.stdout)?;
+        match stdout.lines().next().map(|x| x.trim()) {
+            Some(secret) if !secret.is_empty() => Ok(secret.to_string()),
+            // Helpers that fail for unknown keys can not be told apart from
+            // failing ones, only an empty output means the secret is missing
+            _ => Err(SecretNotFoundError {
+                key: key.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    async fn get_secret_keys(&self) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn set_secret(&self, _key: &str, _value: &str) -> Result<()> {
+        Err(anyhow::anyhow!(
+            "Secrets can not be stored with the credential helper"
+        ))
+    }
+
+    async fn delete_secret(&self, _key: &str) -> Result<()> {
+        Err(anyhow::anyhow!(
+            "Secrets can not be deleted with the credential helper"
+        ))
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::Result;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::Engine;
+use chacha20poly1305::{
+    Key, XChaCha20Poly1305, XNonce,
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload, rand_core::RngCore},
+};
+use serde::{Deserialize, Serialize};
+
+const SALT_LENGTH: usize = 16;
+
+/// The parameters to derive the key from the passphrase with Argon2id
+#[derive(Serialize, Deserialize)]
+pub(super) struct KeyDerivation {
+    pub(super) salt: String,
+    #[serde(rename = "memoryKib")]
+    pub(super) memory_kib: u32,
+    pub(super) iterations: u32,
+    pub(super) parallelism: u32,
+}
+
+impl KeyDerivation {
+    /// Generate the parameters for a new key, with a random salt and the
+    /// default costs recommended for Argon2id
+    pub(super) fn generate() -> Self {
+        let mut salt = [0u8; SALT_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        Self {
+            salt: base64::engine::general_purpose::STANDARD.encode(salt),
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// A single value encrypted with XChaCha20-Poly1305
+#[derive(Serialize, Deserialize)]
+pub(super) struct EncryptedValue {
+    pub(super) nonce: String,
+    pub(super) ciphertext: String,
+}
+
+/// Helper to derive the encryption key from a passphrase with Argon2id
+///
+/// # Arguments
+///
+/// * `passphrase` - The passphrase entered by the user
+/// * `key_derivation` - The salt and cost parameters to derive the key with
+///
+/// # Errors
+///
+/// If the parameters are invalid
+pub(super) fn derive_key(passphrase: &str, key_derivation: &KeyDerivation) -> Result<Key> {
+    let salt = base64::engine::general_purpose::STANDARD.decode(&key_derivation.salt)?;
+    let params = Params::new(
+        key_derivation.memory_kib,
+        key_derivation.iterations,
+        key_derivation.parallelism,
+        None,
+    )
+    .map_err(|err| anyhow::anyhow!("Invalid key derivation parameters: {}", err))?;
+    let mut key = Key::default();
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+        .map_err(|err| anyhow::anyhow!("The key could not be derived: {}", err))?;
+    Ok(key)
+}
+
+/// Helper to encrypt a value with a random nonce
+///
+/// # Arguments
+///
+/// * `key` - The encryption key
+/// * `plaintext` - The value to encrypt
+/// * `aad` - The additional data the ciphertext is bound to
+///
+/// # Errors
+///
+/// If the value could not be encrypted
+pub(super) fn encrypt(key: &Key, plaintext: &[u8], aad: &str) -> Result<EncryptedValue> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = XChaCha20Poly1305::new(key)
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad: aad.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow::anyhow!("The secret could not be encrypted"))?;
+    Ok(EncryptedValue {
+        nonce: base64::engine::general_purpose::STANDARD.encode(nonce),
+        ciphertext: base64::engine::general_purpose::STANDARD.encode(ciphertext),
+    })
+}
+
+/// Helper to decrypt and authenticate a value
+///
+/// # Arguments
+///
+/// * `key` - The encryption key
+/// * `value` - The encrypted value
+/// * `aad` - The additional data the ciphertext is bound to
+///
+/// # Errors
+///
+/// If the value was encrypted with another key, bound to other data or was tampered with
+pub(super) fn decrypt(key: &Key, value: &EncryptedValue, aad: &str) -> Result<Vec<u8>> {
+    let nonce = base64::engine::general_purpose::STANDARD.decode(&value.nonce)?;
+    if nonce.len() != 24 {
+        return Err(anyhow::anyhow!("The nonce of the secret is invalid"));
+    }
+    let ciphertext = base64::engine::general_purpose::STANDARD.decode(&value.ciphertext)?;
+    let plaintext = XChaCha20Poly1305::new(key)
+        .decrypt(
+            XNonce::from_slice(&nonce),
+            Payload {
+                msg: &ciphertext,
+                aad: aad.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow::anyhow!("The secret could not be decrypted"))?;
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Key derivation with the lowest costs, to keep the tests fast
+    fn cheap_key_derivation() -> KeyDerivation {
+        KeyDerivation {
+            iterations: 1,
+            memory_kib: 8,
+            parallelism: 1,
+            ..KeyDerivation::generate()
+        }
+    }
+
+    #[test]
+    fn encrypted_value_is_decrypted_with_the_same_key_and_data() {
+        let key = derive_key("passphrase", &cheap_key_derivation()).unwrap();
+        let value = encrypt(&key, b"secret", "key").unwrap();
+        assert_eq!(decrypt(&key, &value, "key").unwrap(), b"secret");
+    }
+
+    #[test]
+    fn key_of_wrong_passphrase_is_rejected() {
+        let key_derivation = cheap_key_derivation();
+        let key = derive_key("passphrase", &key_derivation).unwrap();
+        let wrong_key = derive_key("wrong passphrase", &key_derivation).unwrap();
+        let value = encrypt(&key, b"secret", "key").unwrap();
+        assert!(decrypt(&wrong_key, &value, "key").is_err());
+    }
+
+    #[test]
+    fn value_bound_to_other_data_is_rejected() {
+        let key = derive_key("passphrase", &cheap_key_derivation()).unwrap();
+        let value = encrypt(&key, b"secret", "key").unwrap();
+        assert!(decrypt(&key, &value, "other key").is_err());
+    }
+
+    #[test]
+    fn tampered_ciphertext_is_rejected() {
+        let key = derive_key("passphrase", &cheap_key_derivation()).unwrap();
+        let value = encrypt(&key, b"secret", "key").unwrap();
+        let mut ciphertext = base64::engine::general_purpose::STANDARD
+            .decode(&value.ciphertext)
+            .unwrap();
+        ciphertext[0] ^= 1;
+        let tampered = EncryptedValue {
+            ciphertext: base64::engine::general_purpose::STANDARD.encode(ciphertext),
+            ..value
+        };
+        assert!(decrypt(&key, &tampered, "key").is_err());
+    }
+
+    #[test]
+    fn tampered_nonce_is_rejected() {
+        let key = derive_key("passphrase", &cheap_key_derivation()).unwrap();
+        let value = encrypt(&key, b"secret", "key").unwrap();
+        let mut nonce = base64::engine::general_purpose::STANDARD
+            .decode(&value.nonce)
+            .unwrap();
+        nonce[0] ^= 1;
+        let tampered = EncryptedValue {
+            nonce: base64::engine::general_purpose::STANDARD.encode(nonce),
+            ..value
+        };
+        assert!(decrypt(&key, &tampered, "key").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+use super::crypto::{EncryptedValue, KeyDerivation, decrypt, derive_key, encrypt};
+use anyhow::Result;
+use application::traits::{LockableSecretRepository, SecretRepository};
+use async_trait::async_trait;
+use chacha20poly1305::Key;
+use domain::errors::SecretNotFoundError;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+use tokio::task::spawn_blocking;
+
+/// The version of the file format, to be able to migrate it later on
+const SECRET_FILE_VERSION: u32 = 1;
+/// Allows to unlock the secrets without user interaction, e.g. on headless machines
+const SECRET_FILE_PASSPHRASE_ENV: &str = "PR_HUB_SECRET_STORAGE_PASSPHRASE";
+/// The additional data the verifier is bound to
+const SECRET_FILE_VERIFIER_AAD: &str = "pr-hub-secret-storage-verifier";
+
+/// Counts the written temporary files, to give each of them a unique name
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// The content of the encrypted secret file
+#[derive(Serialize, Deserialize)]
+struct SecretFile {
+    version: u32,
+    #[serde(rename = "keyDerivation")]
+    key_derivation: KeyDerivation,
+    /// An encrypted empty value, to detect a wrong passphrase on unlock
+    verifier: EncryptedValue,
+    secrets: BTreeMap<String, EncryptedValue>,
+}
+
+/// Repository to access secrets stored in a local file
+///
+/// Every secret is encrypted with XChaCha20-Poly1305 and bound to its key. The
+/// encryption key is derived from a passphrase with Argon2id and only kept in
+/// memory, once the repository was unlocked. Deriving the key and accessing
+/// the file is run on the threads dedicated to blocking operations. Changes
+/// of the file are serialized, so concurrent changes do not overwrite each other.
+#[derive(Clone)]
+pub struct EncryptedFileRepository {
+    file_path: PathBuf,
+    key: Arc<Mutex<Option<Key>>>,
+    file_lock: Arc<Mutex<()>>,
+}
+
+impl EncryptedFileRepository {
+    /// Creates a new, locked instance
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file to store the secrets in
+    pub fn new(file_path: PathBuf) -> Self {
+        Self {
+            file_path,
+            key: Arc::new(Mutex::new(None)),
+            file_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Get the encryption key, unlocking the secrets with the passphrase
+    /// from the environment if still locked
+    ///
+    /// # Errors
+    ///
+    /// If the secrets are locked and can not be unlocked
+    fn key(&self) -> Result<Key> {
+        if let Some(key) = *self.lock_key()? {
+            return Ok(key);
+        }
+        match std::env::var(SECRET_FILE_PASSPHRASE_ENV) {
+            Ok(passphrase) => {
+                self.unlock_blocking(&passphrase)?;
+                self.lock_key()?
+                    .ok_or_else(|| anyhow::anyhow!("The encrypted secret storage is locked"))
+            }
+            Err(_) => Err(anyhow::anyhow!(
+                "The encrypted secret storage is locked, please unlock it with the passphrase"
+            )),
+        }
+    }
+
+    /// Helper to access the encryption key held in memory
+    fn lock_key(&self) -> Result<std::sync::MutexGuard<'_, Option<Key>>> {
+        self.key
+            .lock()
+            .map_err(|_| anyhow::anyhow!("The encryption key could not be accessed"))
+    }
+
+    /// Helper to get exclusive access to the secret file, to be held from
+    /// reading the file until the changed content was written
+    fn lock_file(&self) -> Result<std::sync::MutexGuard<'_, ()>> {
+        self.file_lock
+            .lock()
+            .map_err(|_| anyhow::anyhow!("The secret file could not be accessed"))
+    }
+
+    /// Read the secret file
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file does not exist
+    fn read_file(&self) -> Result<SecretFile> {
+        let content = std::fs::read_to_string(&self.file_path)?;
+        let secret_file = serde_json::from_str::<SecretFile>(&content)?;
+        if secret_file.version != SECRET_FILE_VERSION {
+            return Err(anyhow::anyhow!(
+                "The secret file version `{}` is not supported",
+                secret_file.version
+            ));
+        }
+        Ok(secret_file)
+    }
+
+    /// Write the secret file, replacing it only after it was fully written
+    ///
+    /// The content is written to a uniquely named temporary file, that is only
+    /// readable by the current user from its creation on.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_file` - The content to write
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur
+    fn write_file(&self, secret_file: &SecretFile) -> Result<()> {
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(secret_file)?;
+        let temp_file_path = self.file_path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let result = options.open(&temp_file_path).and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()?;
+            std::fs::rename(&temp_file_path, &self.file_path)
+        });
+        if let Err(err) = result {
+            let _ = std::fs::remove_file(&temp_file_path);
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    /// Get a secret, blocking the current thread
+    fn get_secret_blocking(&self, key: &str) -> Result<String> {
+        let encryption_key = self.key()?;
+        let secret_file = self.read_file()?;
+        let value = secret_file
+            .secrets
+            .get(key)
+            .ok_or_else(|| SecretNotFoundError {
+                key: key.to_string(),
+            })?;
+        let plaintext = decrypt(&encryption_key, value, key)?;
+        Ok(String::from_utf8(plaintext)?)
+    }
+
+    /// Get the keys of the stored secrets, blocking the current thread
+    ///
+    /// The keys are not encrypted, so they are available while still locked.
+    fn get_secret_keys_blocking(&self) -> Result<Vec<String>> {
+        if !self.file_path.exists() {
+            return Ok(vec![]);
+        }
+        Ok(self.read_file()?.secrets.into_keys().collect())
+    }
+
+    /// Store a secret, blocking the current thread
+    fn set_secret_blocking(&self, key: &str, value: &str) -> Result<()> {
+        let encryption_key = self.key()?;
+        let _file_lock = self.lock_file()?;
+        let mut secret_file = self.read_file()?;
+        secret_file.secrets.insert(
+            key.to_string(),
+            encrypt(&encryption_key, value.as_bytes(), key)?,
+        );
+        self.write_file(&secret_file)
+    }
+
+    /// Delete a secret, blocking the current thread
+    fn delete_secret_blocking(&self, key: &str) -> Result<()> {
+        self.key()?;
+        let _file_lock = self.lock_file()?;
+        let mut secret_file = self.read_file()?;
+        if secret_file.secrets.remove(key).is_none() {
+            return Err(SecretNotFoundError {
+                key: key.to_string(),
+            }
+            .into());
+        }
+        self.write_file(&secret_file)
+    }
+
+    /// Unlock the secrets, blocking the current thread
+    fn unlock_blocking(&self, passphrase: &str) -> Result<()> {
+        let _file_lock = self.lock_file()?;
+        let key = match self.file_path.exists() {
+            true => {
+                let secret_file = self.read_file()?;
+                let key = derive_key(passphrase, &secret_file.key_derivation)?;
+                decrypt(&key, &secret_file.verifier, SECRET_FILE_VERIFIER_AAD)
+                    .map_err(|_| anyhow::anyhow!("The passphrase is wrong"))?;
+                key
+            }
+            // The first passphrase defines the one to use from now on
+            false => {
+                let key_derivation = KeyDerivation::generate();
+                let key = derive_key(passphrase, &key_derivation)?;
+                self.write_file(&SecretFile {
+                    version: SECRET_FILE_VERSION,
+                    verifier: encrypt(&key, &[], SECRET_FILE_VERIFIER_AAD)?,
+                    key_derivation,
+                    secrets: BTreeMap::new(),
+                })?;
+                key
+            }
+        };
+        *self.lock_key()? = Some(key);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SecretRepository for EncryptedFileRepository {
+    async fn get_secret(&self, key: &str) -> Result<String> {
+        let repository = self.clone();
+        let key = key.to_string();
+        spawn_blocking(move || repository.get_secret_blocking(&key)).await?
+    }
+
+    async fn get_secret_keys(&self) -> Result<Vec<String>> {
+        let repository = self.clone();
+        spawn_blocking(move || repository.get_secret_keys_blocking()).await?
+    }
+
+    async fn set_secret(&self, key: &str, value: &str) -> Result<()> {
+        let repository = self.clone();
+        let (key, value) = (key.to_string(), value.to_string());
+        spawn_blocking(move || repository.set_secret_blocking(&key, &value)).await?
+    }
+
+    async fn delete_secret(&self, key: &str) -> Result<()> {
+        let repository = self.clone();
+        let key = key.to_string();
+        spawn_blocking(move || repository.delete_secret_blocking(&key)).await?
+    }
+}
+
+#[async_trait]
+impl LockableSecretRepository for EncryptedFileRepository {
+    async fn unlock(&self, passphrase: &str) -> Result<()> {
+        let repository = self.clone();
+        let passphrase = passphrase.to_string();
+        spawn_blocking(move || repository.unlock_blocking(&passphrase)).await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn secrets_are_persisted_across_reopening_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("secrets.json");
+        let repository = EncryptedFileRepository::new(file_path.clone());
+        repository.unlock("passphrase").await.unwrap();
+        repository.set_secret("first", "one").await.unwrap();
+        repository.set_secret("second", "two").await.unwrap();
+        repository.delete_secret("second").await.unwrap();
+
+        let reopened = EncryptedFileRepository::new(file_path);
+        reopened.unlock("passphrase").await.unwrap();
+        assert_eq!(reopened.get_secret("first").await.unwrap(), "one");
+        let err = reopened.get_secret("second").await.unwrap_err();
+        assert!(err.is::<SecretNotFoundError>());
+        assert_eq!(reopened.get_secret_keys().await.unwrap(), vec!["first"]);
+    }
+
+    #[tokio::test]
+    async fn wrong_passphrase_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("secrets.json");
+        let repository = EncryptedFileRepository::new(file_path.clone());
+        repository.unlock("passphrase").await.unwrap();
+        repository.set_secret("first", "one").await.unwrap();
+
+        let reopened = EncryptedFileRepository::new(file_path);
+        assert!(reopened.unlock("wrong passphrase").await.is_err());
+        assert!(reopened.lock_key().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn secret_moved_to_another_key_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("secrets.json");
+        let repository = EncryptedFileRepository::new(file_path.clone());
+        repository.unlock("passphrase").await.unwrap();
+        repository.set_secret("first", "one").await.unwrap();
+        // Swap the encrypted value to another key, as an attacker with write
+        // access to the file could do
+        let mut secret_file = repository.read_file().unwrap();
+        let value = secret_file.secrets.remove("first").unwrap();
+        secret_file.secrets.insert("second".to_string(), value);
+        repository.write_file(&secret_file).unwrap();
+
+        assert!(repository.get_secret("second").await.is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn file_is_only_readable_by_the_current_user() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("secrets.json");
+        let repository = EncryptedFileRepository::new(file_path.clone());
+        repository.unlock("passphrase").await.unwrap();
+        repository.set_secret("first", "one").await.unwrap();
+
+        let mode = std::fs::metadata(&file_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -0,0 +1,66 @@
+use anyhow::Result;
+use application::traits::SecretRepository;
+use async_trait::async_trait;
+use domain::errors::SecretNotFoundError;
+
+/// The prefix of the environment variables secrets are read from
+const SECRET_ENV_PREFIX: &str = "PR_HUB_SECRET_";
+
+/// Repository to read secrets from environment variables
+///
+/// The variable of a secret is named after its key in upper case, with all
+/// characters other than letters and digits replaced by underscores. For
+/// example, the key `azuredevops-my-org` is read from `PR_HUB_SECRET_AZUREDEVOPS_MY_ORG`.
+#[derive(Default)]
+pub struct EnvironmentSecretRepository {}
+
+impl EnvironmentSecretRepository {
+    /// Get the name of the environment variable of a secret
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the secret
+    pub fn variable_name(key: &str) -> String {
+        let name = key
+            .chars()
+            .map(|x| match x.is_ascii_alphanumeric() {
+                true => x.to_ascii_uppercase(),
+                false => '_',
+            })
+            .collect::<String>();
+        format!("{}{}", SECRET_ENV_PREFIX, name)
+    }
+}
+
+#[async_trait]
+impl SecretRepository for EnvironmentSecretRepository {
+    async fn get_secret(&self, key: &str) -> Result<String> {
+        let variable_name = Self::variable_name(key);
+        match std::env::var(&variable_name) {
+            Ok(value) if !value.is_empty() => Ok(value),
+            _ => {
+                log::debug!("The environment variable `{}` is not set", variable_name);
+                Err(SecretNotFoundError {
+                    key: key.to_string(),
+                }
+                .into())
+            }
+        }
+    }
+
+    async fn get_secret_keys(&self) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn set_secret(&self, _key: &str, _value: &str) -> Result<()> {
+        Err(anyhow::anyhow!(
+            "Secrets can not be stored in environment variables"
+        ))
+    }
+
+    async fn delete_secret(&self, _key: &str) -> Result<()> {
+        Err(anyhow::anyhow!(
+            "Secrets can not be deleted from environment variables"
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+use super::crypto::{EncryptedValue, KeyDerivation, decrypt, derive_key, encrypt};
+use anyhow::Result;
+use application::traits::PassphraseCipher;
+use async_trait::async_trait;
+use domain::models::PassphraseEncryptedData;
+use tokio::task::spawn_blocking;
+
+/// The additional data all values encrypted by the cipher are bound to
+const PASSPHRASE_CIPHER_AAD: &str = "pr-hub-passphrase-cipher";
+
+/// Cipher to encrypt data with XChaCha20-Poly1305 and a key derived from a
+/// passphrase with Argon2id, the same way the encrypted secret file does
+///
+/// A new random salt is used for every encryption, so the same passphrase
+/// never results in the same key twice. Deriving the key is run on the
+/// threads dedicated to blocking operations.
+#[derive(Default)]
+pub struct XChaChaPassphraseCipher {}
+
+#[async_trait]
+impl PassphraseCipher for XChaChaPassphraseCipher {
+    async fn encrypt(&self, passphrase: &str, plaintext: &[u8]) -> Result<PassphraseEncryptedData> {
+        let passphrase = passphrase.to_string();
+        let plaintext = plaintext.to_vec();
+        spawn_blocking(move || {
+            let key_derivation = KeyDerivation::generate();
+            let key = derive_key(&passphrase, &key_derivation)?;
+            let value = encrypt(&key, &plaintext, PASSPHRASE_CIPHER_AAD)?;
+            Ok(PassphraseEncryptedData {
+                salt: key_derivation.salt,
+                memory_kib: key_derivation.memory_kib,
+                iterations: key_derivation.iterations,
+                parallelism: key_derivation.parallelism,
+                nonce: value.nonce,
+                ciphertext: value.ciphertext,
+            })
+        })
+        .await?
+    }
+
+    async fn decrypt(&self, passphrase: &str, data: &PassphraseEncryptedData) -> Result<Vec<u8>> {
+        let passphrase = passphrase.to_string();
+        let key_derivation = KeyDerivation {
+            salt: data.salt.to_string(),
+            memory_kib: data.memory_kib,
+            iterations: data.iterations,
+            parallelism: data.parallelism,
+        };
+        let value = EncryptedValue {
+            nonce: data.nonce.to_string(),
+            ciphertext: data.ciphertext.to_string(),
+        };
+        spawn_blocking(move || {
+            let key = derive_key(&passphrase, &key_derivation)?;
+            decrypt(&key, &value, PASSPHRASE_CIPHER_AAD).map_err(|_| {
+                anyhow::anyhow!("The passphrase is wrong or the data was tampered with")
+            })
+        })
+        .await?
+    }
+}
@@ -0,0 +1,183 @@
+use anyhow::Result;
+use application::traits::SettingsRepository;
+use async_trait::async_trait;
+use chrono::NaiveTime;
+use domain::{
+    enums::{SecretSource, SecretStorageBackend},
+    models::Settings,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+/// The settings as stored in the settings file
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct SettingsFile {
+    #[serde(rename = "secretStorageBackend")]
+    secret_storage_backend: SecretStorageBackendValue,
+    #[serde(rename = "secretSources")]
+    secret_sources: Vec<SecretSourceValue>,
+    #[serde(rename = "credentialHelperCommand")]
+    credential_helper_command: Option<String>,
+    #[serde(rename = "patExpiryWarningDays")]
+    pat_expiry_warning_days: u32,
+    #[serde(rename = "businessHoursStart")]
+    business_hours_start: NaiveTime,
+    #[serde(rename = "businessHoursEnd")]
+    business_hours_end: NaiveTime,
+    #[serde(rename = "businessTimeZone")]
+    business_time_zone: String,
+}
+
+impl Default for SettingsFile {
+    /// Business hours are evaluated in the time zone of the host, until
+    /// another one is configured
+    fn default() -> Self {
+        let mut settings = Settings::default();
+        if let Ok(time_zone) = iana_time_zone::get_timezone() {
+            settings.business_time_zone = time_zone;
+        }
+        (&settings).into()
+    }
+}
+
+/// The secret storage backend as stored in the settings file
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+enum SecretStorageBackendValue {
+    #[default]
+    Keyring,
+    EncryptedFile,
+}
+
+/// A secret source as stored in the settings file
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum SecretSourceValue {
+    Environment,
+    CredentialHelper,
+    Storage,
+}
+
+impl From<&SecretSourceValue> for SecretSource {
+    fn from(value: &SecretSourceValue) -> Self {
+        match value {
+            SecretSourceValue::Environment => SecretSource::Environment,
+            SecretSourceValue::CredentialHelper => SecretSource::CredentialHelper,
+            SecretSourceValue::Storage => SecretSource::Storage,
+        }
+    }
+}
+
+impl From<&SecretSource> for SecretSourceValue {
+    fn from(value: &SecretSource) -> Self {
+        match value {
+            SecretSource::Environment => SecretSourceValue::Environment,
+            SecretSource::CredentialHelper => SecretSourceValue::CredentialHelper,
+            SecretSource::Storage => SecretSourceValue::Storage,
+        }
+    }
+}
+
+impl From<&SettingsFile> for Settings {
+    fn from(value: &SettingsFile) -> Self {
+        Self {
+            secret_storage_backend: match value.secret_storage_backend {
+                SecretStorageBackendValue::Keyring => SecretStorageBackend::Keyring,
+                SecretStorageBackendValue::EncryptedFile => SecretStorageBackend::EncryptedFile,
+            },
+            secret_sources: value.secret_sources.iter().map(|x| x.into()).collect(),
+            credential_helper_command: value.credential_helper_command.clone(),
+            pat_expiry_warning_days: value.pat_expiry_warning_days,
+            business_hours_start: value.business_hours_start,
+            business_hours_end: value.business_hours_end,
+            business_time_zone: value.business_time_zone.to_string(),
+        }
+    }
+}
+
+impl From<&Settings> for SettingsFile {
+    fn from(value: &Settings) -> Self {
+        Self {
+            secret_storage_backend: match value.secret_storage_backend {
+                SecretStorageBackend::Keyring => SecretStorageBackendValue::Keyring,
+                SecretStorageBackend::EncryptedFile => SecretStorageBackendValue::EncryptedFile,
+            },
+            secret_sources: value.secret_sources.iter().map(|x| x.into()).collect(),
+            credential_helper_command: value.credential_helper_command.clone(),
+            pat_expiry_warning_days: value.pat_expiry_warning_days,
+            business_hours_start: value.business_hours_start,
+            business_hours_end: value.business_hours_end,
+            business_time_zone: value.business_time_zone.to_string(),
+        }
+    }
+}
+
+/// Repository to access the settings stored as JSON file
+///
+/// The settings are kept in memory and shared between all clones, so
+/// saved settings immediately apply to everything depending on them.
+#[derive(Clone)]
+pub struct SettingsFileRepository {
+    file_path: PathBuf,
+    settings: Arc<RwLock<Settings>>,
+}
+
+impl SettingsFileRepository {
+    /// Create a new instance, loading the settings from the file if it exists
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the settings file
+    ///
+    /// # Errors
+    ///
+    /// Any errors that might occur, e.g. when the file is not valid JSON
+    pub fn new(file_path: PathBuf) -> Result<Self> {
+        let settings = match file_path.exists() {
+            true => {
+                let content = std::fs::read_to_string(&file_path)?;
+                let settings_file = serde_json::from_str::<SettingsFile>(&content)?;
+                Settings::from(&settings_file)
+            }
+            false => Settings::from(&SettingsFile::default()),
+        };
+        Ok(Self {
+            file_path,
+            settings: Arc::new(RwLock::new(settings)),
+        })
+    }
+
+    /// Get the current settings without waiting
+    ///
+    /// # Returns
+    ///
+    /// * `Settings` - The current settings, the defaults if they can not be read
+    pub fn current(&self) -> Settings {
+        self.settings.read().map(|x| x.clone()).unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for SettingsFileRepository {
+    async fn get_settings(&self) -> Result<Settings> {
+        Ok(self.current())
+    }
+
+    async fn save_settings(&self, settings: Settings) -> Result<()> {
+        let content = serde_json::to_string_pretty(&SettingsFile::from(&settings))?;
+        if let Some(parent) = self.file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.file_path, content)?;
+        let mut current = self
+            .settings
+            .write()
+            .map_err(|_| anyhow::anyhow!("The settings could not be updated"))?;
+        *current = settings;
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{commands, dependency_container::DependencyContainer};
+use crate::{commands, dependency_container::DependencyContainer, jobs};
 use std::{env, str::FromStr};
 use tauri::Manager;
 
@@ -26,15 +26,64 @@ impl TauriApp {
                     .expect("App data dir needs to be represented as string");
                 let di_container = DependencyContainer::new(&data_dir);
                 app.manage(di_container);
+                tauri::async_runtime::spawn(jobs::cleanup_pull_request_annotations(
+                    app.handle().clone(),
+                ));
+                tauri::async_runtime::spawn(jobs::check_pat_expiry(app.handle().clone()));
                 Ok(())
             })
             .invoke_handler(tauri::generate_handler![
                 commands::get_git_repositories,
+                commands::discover_azure_devops_organization_repositories,
                 commands::import_azure_devops_organization_repositories,
+                commands::sync_azure_devops_organization_repositories,
+                commands::add_git_repository_by_url,
                 commands::toggle_git_repository_active_state,
                 commands::remove_git_repository,
+                commands::activate_git_repositories,
+                commands::deactivate_git_repositories,
+                commands::remove_git_repositories,
                 commands::update_pat_for_git_repository,
-                commands::get_open_pull_requests
+                commands::start_device_code_sign_in,
+                commands::complete_device_code_sign_in,
+                commands::get_open_pull_requests,
+                commands::mark_pull_request_reviewed,
+                commands::mark_pull_request_seen,
+                commands::mark_pull_request_unread,
+                commands::set_pull_request_pin_state,
+                commands::snooze_pull_request,
+                commands::clear_pull_request_snooze,
+                commands::update_pull_request_annotation,
+                commands::get_repository_groups,
+                commands::create_repository_group,
+                commands::rename_repository_group,
+                commands::remove_repository_group,
+                commands::assign_git_repositories_to_group,
+                commands::unassign_git_repositories_from_group,
+                commands::get_pat_expiry_warnings,
+                commands::set_pat_expiry_date,
+                commands::get_settings,
+                commands::update_settings,
+                commands::unlock_secret_storage,
+                commands::check_secret_health,
+                commands::migrate_secrets,
+                commands::export_configuration,
+                commands::import_configuration,
+                commands::get_profiles,
+                commands::create_profile,
+                commands::rename_profile,
+                commands::remove_profile,
+                commands::switch_profile,
+                commands::create_database_backup,
+                commands::restore_database_backup,
+                commands::get_pull_request_analytics,
+                commands::get_reviewer_workload,
+                commands::get_sla_rules,
+                commands::create_sla_rule,
+                commands::update_sla_rule,
+                commands::remove_sla_rule,
+                commands::get_holidays,
+                commands::update_holidays
             ])
             .run(tauri::generate_context!())
             .expect("error while running tauri application");
@@ -1,15 +1,85 @@
 use super::dependency_container::DependencyContainer;
 use application::{
-    dtos::{GitRepositoryDto, PullRequestDto},
+    analytics::get_pull_request_analytics::PullRequestAnalyticsQuery,
+    authentication::{
+        complete_device_code_sign_in::CompleteDeviceCodeSignInCommand,
+        start_device_code_sign_in::StartDeviceCodeSignInCommand,
+    },
+    configuration::{
+        export_configuration::ExportConfigurationQuery,
+        import_configuration::ImportConfigurationCommand,
+    },
+    database_backups::{
+        create_database_backup::CreateDatabaseBackupCommand,
+        restore_database_backup::RestoreDatabaseBackupCommand,
+    },
+    dtos::{
+        ConfigurationImportResultDto, ConflictResolution, DeviceCodeChallengeDto,
+        DiscoveredProjectDto, GitRepositoryDto, GitRepositoryImportFilterDto,
+        GitRepositorySelectionDto, GitRepositorySyncResultDto, HolidayDto, PatExpiryWarningDto,
+        ProfileDto, PullRequestAnalyticsDto, PullRequestDto, PullRequestFilterDto,
+        PullRequestSnoozeDto, PullRequestSortingDto, RepositoryGroupDto, ReviewerWorkloadDto,
+        SecretHealthReportDto, SecretMigrationResultDto, SecretStorageBackend, SettingsDto,
+        SlaRuleDto,
+    },
     git_repositories::{
+        add_git_repository_by_url::AddGitRepositoryByUrlCommand,
         get_git_repositories::GitRepositoriesQuery,
         import_azure_devops_organization_repositories::DevOpsOrgaImporter,
+        remove_git_repositories::RemoveGitRepositoriesCommand,
         remove_git_repository::RemoveGitRepositoryCommand,
+        set_git_repositories_active_state::SetGitRepositoriesActiveStateCommand,
+        sync_azure_devops_organization_repositories::SyncAzureDevOpsOrganizationRepositoriesCommand,
         toggle_git_repository_active_state::ToggleGitRepositoryActiveStateCommand,
         update_pat_for_git_repository::UpdatePatForGitRepositoryCommand,
     },
-    pull_requests::get_open_pull_requests::GetOpenPullRequestsQuery,
+    personal_access_tokens::{
+        get_pat_expiry_warnings::GetPatExpiryWarningsQuery,
+        set_pat_expiry_date::SetPatExpiryDateCommand,
+    },
+    profiles::{
+        create_profile::CreateProfileCommand, get_profiles::ProfilesQuery,
+        remove_profile::RemoveProfileCommand, rename_profile::RenameProfileCommand,
+        switch_profile::SwitchProfileCommand,
+    },
+    pull_requests::{
+        clear_pull_request_snooze::ClearPullRequestSnoozeCommand,
+        get_open_pull_requests::GetOpenPullRequestsQuery,
+        get_reviewer_workload::GetReviewerWorkloadQuery,
+        mark_pull_request_reviewed::MarkPullRequestReviewedCommand,
+        mark_pull_request_seen::MarkPullRequestSeenCommand,
+        mark_pull_request_unread::MarkPullRequestUnreadCommand,
+        open_pull_request_source::OpenPullRequestSource,
+        set_pull_request_pin_state::SetPullRequestPinStateCommand,
+        snooze_pull_request::SnoozePullRequestCommand,
+        track_pull_request_annotations::PullRequestAnnotationTracker,
+        track_pull_request_lifecycles::PullRequestLifecycleTracker,
+        track_pull_request_reviews::PullRequestReviewTracker,
+        update_pull_request_annotation::UpdatePullRequestAnnotationCommand,
+    },
+    repository_groups::{
+        assign_git_repositories_to_group::AssignGitRepositoriesToGroupCommand,
+        create_repository_group::CreateRepositoryGroupCommand,
+        get_repository_groups::RepositoryGroupsQuery,
+        remove_repository_group::RemoveRepositoryGroupCommand,
+        rename_repository_group::RenameRepositoryGroupCommand,
+        unassign_git_repositories_from_group::UnassignGitRepositoriesFromGroupCommand,
+    },
+    secrets::{
+        check_secret_health::CheckSecretHealthQuery, migrate_secrets::MigrateSecretsCommand,
+    },
+    settings::{
+        get_settings::SettingsQuery, unlock_secret_storage::UnlockSecretStorageCommand,
+        update_settings::UpdateSettingsCommand,
+    },
+    sla_rules::{
+        create_sla_rule::CreateSlaRuleCommand, evaluate_sla_rules::SlaRuleEvaluator,
+        get_holidays::HolidaysQuery, get_sla_rules::SlaRulesQuery,
+        remove_sla_rule::RemoveSlaRuleCommand, update_holidays::UpdateHolidaysCommand,
+        update_sla_rule::UpdateSlaRuleCommand,
+    },
 };
+use chrono::{DateTime, Utc};
 use tauri::State;
 
 /// Tauri command to query for all imported git repositories
@@ -17,6 +87,7 @@ use tauri::State;
 /// # Arguments
 ///
 /// * `di_container` - The container to resolve dependencies
+/// * `group_id` - Optional repository group to restrict the result to
 ///
 /// # Returns
 ///
@@ -28,11 +99,63 @@ use tauri::State;
 #[tauri::command]
 pub async fn get_git_repositories(
     di_container: State<'_, DependencyContainer>,
+    group_id: Option<u32>,
 ) -> Result<Vec<GitRepositoryDto>, String> {
     log::info!("Invoking command `get_git_repositories`");
     let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
-    let query = GitRepositoriesQuery::new(git_repository_repository);
-    let result = query.execute().await;
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let query = GitRepositoriesQuery::new(git_repository_repository, repository_group_repository);
+    let result = query.execute(group_id).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to discover the projects and git repositories of a single
+/// Azure DevOps organization, without importing them
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `organization_name` - The name of the Azure DevOps organization
+/// * `pat` - The private access token to access all git repositories
+/// * `filter` - The optional patterns the project and repository names must match
+///
+/// # Returns
+///
+/// * `Result<Vec<DiscoveredProjectDto>, String>` - The found git repositories grouped by project
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn discover_azure_devops_organization_repositories(
+    di_container: State<'_, DependencyContainer>,
+    organization_name: &str,
+    pat: &str,
+    filter: Option<GitRepositoryImportFilterDto>,
+) -> Result<Vec<DiscoveredProjectDto>, String> {
+    log::info!(
+        "Invoking command `discover_azure_devops_organization_repositories` with organization name `{}`",
+        organization_name
+    );
+    let azure_devops_repository = (di_container.azure_devops_repository_fac)(&di_container);
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+    let importer = DevOpsOrgaImporter::new(
+        azure_devops_repository,
+        git_repository_repository,
+        secret_repository,
+        pat_metadata_repository,
+    );
+    let result = importer
+        .discover(organization_name, pat, &filter.unwrap_or_default())
+        .await;
     match result {
         Ok(data) => Ok(data),
         Err(err) => {
@@ -42,7 +165,7 @@ pub async fn get_git_repositories(
     }
 }
 
-/// Tauri command to import all git repositories from a single Azure DevOps
+/// Tauri command to import the git repositories from a single Azure DevOps
 /// organization into the application
 ///
 /// # Arguments
@@ -50,6 +173,13 @@ pub async fn get_git_repositories(
 /// * `di_container` - The container to resolve dependencies
 /// * `organization_name` - The name of the Azure DevOps organization
 /// * `pat` - The private access token to access all git repositories
+/// * `filter` - The optional patterns the project and repository names must match
+/// * `remote_ids` - The optional subset of discovered git repositories to import
+/// * `pat_expires_at` - When the PAT expires, if known
+///
+/// # Returns
+///
+/// * `Result<usize, String>` - The number of imported git repositories
 ///
 /// # Errors
 ///
@@ -59,20 +189,73 @@ pub async fn import_azure_devops_organization_repositories(
     di_container: State<'_, DependencyContainer>,
     organization_name: &str,
     pat: &str,
-) -> Result<(), String> {
+    filter: Option<GitRepositoryImportFilterDto>,
+    remote_ids: Option<Vec<String>>,
+    pat_expires_at: Option<DateTime<Utc>>,
+) -> Result<usize, String> {
     log::info!(
         "Invoking command `import_azure_devops_organization_repositories` with organization name `{}`",
         organization_name
     );
-    let azure_devops_repository = (di_container.azure_devops_repository_fac)();
+    let azure_devops_repository = (di_container.azure_devops_repository_fac)(&di_container);
     let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
-    let secret_repository = (di_container.secret_repository_fac)();
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
     let importer = DevOpsOrgaImporter::new(
         azure_devops_repository,
         git_repository_repository,
         secret_repository,
+        pat_metadata_repository,
+    );
+    let result = importer
+        .import(
+            organization_name,
+            pat,
+            &filter.unwrap_or_default(),
+            remote_ids.as_deref(),
+            pat_expires_at,
+        )
+        .await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to import a single git repository, identified by its clone
+/// or web URL, into the application
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `url` - The clone or web URL of the git repository
+/// * `pat` - The private access token to access the git repository
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn add_git_repository_by_url(
+    di_container: State<'_, DependencyContainer>,
+    url: &str,
+    pat: &str,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `add_git_repository_by_url` with url `{}`",
+        url
+    );
+    let azure_devops_repository = (di_container.azure_devops_repository_fac)(&di_container);
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let command = AddGitRepositoryByUrlCommand::new(
+        azure_devops_repository,
+        git_repository_repository,
+        secret_repository,
     );
-    let result = importer.import(organization_name, pat).await;
+    let result = command.execute(url, pat).await;
     match result {
         Ok(_) => Ok(()),
         Err(err) => {
@@ -82,6 +265,58 @@ pub async fn import_azure_devops_organization_repositories(
     }
 }
 
+/// Tauri command to synchronize the imported git repositories of a single
+/// Azure DevOps organization with the remote
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `organization_name` - The name of the Azure DevOps organization
+/// * `pat` - The optional new PAT, the stored one is used if not set
+/// * `activate_new` - Whether newly found git repositories are marked as active
+///
+/// # Returns
+///
+/// * `Result<GitRepositorySyncResultDto, String>` - The summary of the applied changes
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn sync_azure_devops_organization_repositories(
+    di_container: State<'_, DependencyContainer>,
+    organization_name: &str,
+    pat: Option<String>,
+    activate_new: Option<bool>,
+) -> Result<GitRepositorySyncResultDto, String> {
+    log::info!(
+        "Invoking command `sync_azure_devops_organization_repositories` with organization name `{}`",
+        organization_name
+    );
+    let azure_devops_repository = (di_container.azure_devops_repository_fac)(&di_container);
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let command = SyncAzureDevOpsOrganizationRepositoriesCommand::new(
+        azure_devops_repository,
+        git_repository_repository,
+        secret_repository,
+    );
+    let result = command
+        .execute(
+            organization_name,
+            pat.as_deref(),
+            activate_new.unwrap_or_default(),
+        )
+        .await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
 /// Tauri command to remove a single imported git repository from the application
 ///
 /// # Arguments
@@ -102,7 +337,7 @@ pub async fn remove_git_repository(
         id
     );
     let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
-    let secret_repository = (di_container.secret_repository_fac)();
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
     let command = RemoveGitRepositoryCommand::new(git_repository_repository, secret_repository);
     let result = command.execute(&id).await;
     match result {
@@ -145,6 +380,103 @@ pub async fn toggle_git_repository_active_state(
     }
 }
 
+/// Tauri command to activate multiple imported git repositories at once
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `selection` - The ids and/or pattern to select the git repositories by
+///
+/// # Returns
+///
+/// * `Result<usize, String>` - The number of activated git repositories
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn activate_git_repositories(
+    di_container: State<'_, DependencyContainer>,
+    selection: GitRepositorySelectionDto,
+) -> Result<usize, String> {
+    log::info!("Invoking command `activate_git_repositories`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let command = SetGitRepositoriesActiveStateCommand::new(git_repository_repository);
+    let result = command.execute(&selection, true).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to deactivate multiple imported git repositories at once
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `selection` - The ids and/or pattern to select the git repositories by
+///
+/// # Returns
+///
+/// * `Result<usize, String>` - The number of deactivated git repositories
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn deactivate_git_repositories(
+    di_container: State<'_, DependencyContainer>,
+    selection: GitRepositorySelectionDto,
+) -> Result<usize, String> {
+    log::info!("Invoking command `deactivate_git_repositories`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let command = SetGitRepositoriesActiveStateCommand::new(git_repository_repository);
+    let result = command.execute(&selection, false).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to remove multiple imported git repositories at once
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `selection` - The ids and/or pattern to select the git repositories by
+///
+/// # Returns
+///
+/// * `Result<usize, String>` - The number of removed git repositories
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn remove_git_repositories(
+    di_container: State<'_, DependencyContainer>,
+    selection: GitRepositorySelectionDto,
+) -> Result<usize, String> {
+    log::info!("Invoking command `remove_git_repositories`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let command = RemoveGitRepositoriesCommand::new(git_repository_repository, secret_repository);
+    let result = command.execute(&selection).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
 /// Tauri command to update the PAT of an already imported git repository
 ///
 /// # Arguments
@@ -152,6 +484,7 @@ pub async fn toggle_git_repository_active_state(
 /// * `di_container` - The container to resolve dependencies
 /// * `id` - The unique identifier of the repository
 /// * `pat` - The new value for the PAT
+/// * `expires_at` - When the PAT expires, if known
 ///
 /// # Errors
 ///
@@ -161,16 +494,23 @@ pub async fn update_pat_for_git_repository(
     di_container: State<'_, DependencyContainer>,
     id: u32,
     pat: &str,
+    expires_at: Option<DateTime<Utc>>,
 ) -> Result<(), String> {
     log::info!(
         "Invoking command `update_pat_for_git_repository` for git repository with id `{}`",
         id
     );
+    let azure_devops_repository = (di_container.azure_devops_repository_fac)(&di_container);
     let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
-    let secret_repository = (di_container.secret_repository_fac)();
-    let command =
-        UpdatePatForGitRepositoryCommand::new(git_repository_repository, secret_repository);
-    let result = command.execute(&id, pat).await;
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+    let command = UpdatePatForGitRepositoryCommand::new(
+        azure_devops_repository,
+        git_repository_repository,
+        secret_repository,
+        pat_metadata_repository,
+    );
+    let result = command.execute(&id, pat, expires_at).await;
     match result {
         Ok(_) => Ok(()),
         Err(err) => {
@@ -180,12 +520,92 @@ pub async fn update_pat_for_git_repository(
     }
 }
 
+/// Tauri command to start an OAuth sign in with Microsoft Entra ID using
+/// the device code flow
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<DeviceCodeChallengeDto, String>` - The challenge the user needs to complete in the browser
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn start_device_code_sign_in(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<DeviceCodeChallengeDto, String> {
+    log::info!("Invoking command `start_device_code_sign_in`");
+    let oauth_repository = (di_container.oauth_repository_fac)();
+    let command = StartDeviceCodeSignInCommand::new(oauth_repository);
+    let result = command.execute().await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to complete an OAuth sign in started with
+/// `start_device_code_sign_in` for an Azure DevOps organization
+///
+/// Needs to be invoked repeatedly, respecting the interval of the challenge,
+/// until the user completed the sign in in the browser.
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `organization_name` - The name of the Azure DevOps organization to sign in for
+/// * `device_code` - The device code of the started sign in
+///
+/// # Returns
+///
+/// * `Result<bool, String>` - Whether the sign in is completed, `false` while still pending
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn complete_device_code_sign_in(
+    di_container: State<'_, DependencyContainer>,
+    organization_name: &str,
+    device_code: &str,
+) -> Result<bool, String> {
+    log::info!(
+        "Invoking command `complete_device_code_sign_in` for organization `{}`",
+        organization_name
+    );
+    let oauth_repository = (di_container.oauth_repository_fac)();
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let command = CompleteDeviceCodeSignInCommand::new(
+        oauth_repository,
+        git_repository_repository,
+        secret_repository,
+    );
+    let result = command.execute(organization_name, device_code).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
 /// Tauri command to get all open pull requests across active imported
 /// git repositories
 ///
 /// # Arguments
 ///
 /// * `di_container` - The container to resolve dependencies
+/// * `filter` - The optional criteria the returned pull requests must match
+/// * `sorting` - The optional order of the returned pull requests
 ///
 /// # Errors
 ///
@@ -193,17 +613,48 @@ pub async fn update_pat_for_git_repository(
 #[tauri::command]
 pub async fn get_open_pull_requests(
     di_container: State<'_, DependencyContainer>,
+    filter: Option<PullRequestFilterDto>,
+    sorting: Option<PullRequestSortingDto>,
 ) -> Result<Vec<PullRequestDto>, String> {
     log::info!("Invoking command `get_open_pull_requests`");
-    let azure_devops_repository = (di_container.azure_devops_repository_fac)();
-    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
-    let secret_repository = (di_container.secret_repository_fac)();
+    let open_pull_request_source = OpenPullRequestSource::new(
+        (di_container.azure_devops_repository_fac)(&di_container),
+        (di_container.git_repository_repository_fac)(&di_container),
+        (di_container.secret_repository_fac)(&di_container),
+        (di_container.repository_group_repository_fac)(&di_container),
+    );
+    let pull_request_state_repository =
+        (di_container.pull_request_state_repository_fac)(&di_container);
+    let pull_request_triage_repository =
+        (di_container.pull_request_triage_repository_fac)(&di_container);
+    let pull_request_review_tracker = PullRequestReviewTracker::new((di_container
+        .pull_request_review_repository_fac)(
+        &di_container
+    ));
+    let pull_request_annotation_tracker = PullRequestAnnotationTracker::new((di_container
+        .pull_request_annotation_repository_fac)(
+        &di_container
+    ));
+    let pull_request_lifecycle_tracker = PullRequestLifecycleTracker::new(
+        (di_container.azure_devops_repository_fac)(&di_container),
+        (di_container.pull_request_lifecycle_repository_fac)(&di_container),
+    );
+    let sla_rule_evaluator = SlaRuleEvaluator::new(
+        (di_container.sla_rule_repository_fac)(&di_container),
+        (di_container.settings_repository_fac)(&di_container),
+    );
     let query = GetOpenPullRequestsQuery::new(
-        azure_devops_repository,
-        git_repository_repository,
-        secret_repository,
+        open_pull_request_source,
+        pull_request_state_repository,
+        pull_request_triage_repository,
+        pull_request_review_tracker,
+        pull_request_annotation_tracker,
+        pull_request_lifecycle_tracker,
+        sla_rule_evaluator,
     );
-    let result = query.execute().await;
+    let result = query
+        .execute(&filter.unwrap_or_default(), &sorting.unwrap_or_default())
+        .await;
     match result {
         Ok(data) => Ok(data),
         Err(err) => {
@@ -212,3 +663,1307 @@ pub async fn get_open_pull_requests(
         }
     }
 }
+
+/// Tauri command to remember that the current user reviewed a specific
+/// iteration of a pull request
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+/// * `iteration_id` - The identifier of the reviewed iteration
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn mark_pull_request_reviewed(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+    iteration_id: u32,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `mark_pull_request_reviewed` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pull_request_review_repository =
+        (di_container.pull_request_review_repository_fac)(&di_container);
+    let command = MarkPullRequestReviewedCommand::new(
+        git_repository_repository,
+        pull_request_review_repository,
+    );
+    let result = command
+        .execute(&git_repository_id, &pull_request_id, &iteration_id)
+        .await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to mark a single pull request as seen (read)
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+/// * `comment_count` - The total number of comments the user has seen
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn mark_pull_request_seen(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+    comment_count: usize,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `mark_pull_request_seen` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pull_request_state_repository =
+        (di_container.pull_request_state_repository_fac)(&di_container);
+    let command =
+        MarkPullRequestSeenCommand::new(git_repository_repository, pull_request_state_repository);
+    let result = command
+        .execute(&git_repository_id, &pull_request_id, &comment_count)
+        .await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to mark a single pull request as unread
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn mark_pull_request_unread(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `mark_pull_request_unread` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let pull_request_state_repository =
+        (di_container.pull_request_state_repository_fac)(&di_container);
+    let command = MarkPullRequestUnreadCommand::new(pull_request_state_repository);
+    let result = command.execute(&git_repository_id, &pull_request_id).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to pin or unpin a single pull request
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+/// * `is_pinned` - Whether the pull request should be pinned
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn set_pull_request_pin_state(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+    is_pinned: bool,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `set_pull_request_pin_state` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pull_request_triage_repository =
+        (di_container.pull_request_triage_repository_fac)(&di_container);
+    let command = SetPullRequestPinStateCommand::new(
+        git_repository_repository,
+        pull_request_triage_repository,
+    );
+    let result = command
+        .execute(&git_repository_id, &pull_request_id, is_pinned)
+        .await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to snooze a single pull request
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+/// * `snooze` - The conditions to wake the pull request up again
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn snooze_pull_request(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+    snooze: PullRequestSnoozeDto,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `snooze_pull_request` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pull_request_triage_repository =
+        (di_container.pull_request_triage_repository_fac)(&di_container);
+    let command =
+        SnoozePullRequestCommand::new(git_repository_repository, pull_request_triage_repository);
+    let result = command
+        .execute(&git_repository_id, &pull_request_id, &snooze)
+        .await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to wake up a snoozed pull request
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn clear_pull_request_snooze(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `clear_pull_request_snooze` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let pull_request_triage_repository =
+        (di_container.pull_request_triage_repository_fac)(&di_container);
+    let command = ClearPullRequestSnoozeCommand::new(pull_request_triage_repository);
+    let result = command.execute(&git_repository_id, &pull_request_id).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to replace the private note and local tags of a single pull request
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of the imported git repository
+/// * `pull_request_id` - The unique identifier of the pull request
+/// * `note` - The free text note, removed when empty
+/// * `tags` - The free-form local tags
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn update_pull_request_annotation(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    pull_request_id: u32,
+    note: Option<String>,
+    tags: Vec<String>,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `update_pull_request_annotation` for pull request with id `{}` in git repository with id `{}`",
+        pull_request_id,
+        git_repository_id
+    );
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pull_request_annotation_repository =
+        (di_container.pull_request_annotation_repository_fac)(&di_container);
+    let command = UpdatePullRequestAnnotationCommand::new(
+        git_repository_repository,
+        pull_request_annotation_repository,
+    );
+    let result = command
+        .execute(&git_repository_id, &pull_request_id, note, tags)
+        .await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to query for all repository groups
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<Vec<RepositoryGroupDto>, String>` - The list of retrieved repository groups
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_repository_groups(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<Vec<RepositoryGroupDto>, String> {
+    log::info!("Invoking command `get_repository_groups`");
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let query = RepositoryGroupsQuery::new(repository_group_repository);
+    let result = query.execute().await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to create a new, empty repository group
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `name` - The name of the repository group
+///
+/// # Returns
+///
+/// * `Result<u32, String>` - The unique identifier of the created repository group
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn create_repository_group(
+    di_container: State<'_, DependencyContainer>,
+    name: &str,
+) -> Result<u32, String> {
+    log::info!(
+        "Invoking command `create_repository_group` with name `{}`",
+        name
+    );
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = CreateRepositoryGroupCommand::new(repository_group_repository);
+    let result = command.execute(name).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to rename a single repository group
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the repository group
+/// * `name` - The new name of the repository group
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn rename_repository_group(
+    di_container: State<'_, DependencyContainer>,
+    id: u32,
+    name: &str,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `rename_repository_group` for repository group with id `{}`",
+        id
+    );
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = RenameRepositoryGroupCommand::new(repository_group_repository);
+    let result = command.execute(&id, name).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to remove a single repository group
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the repository group to remove
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn remove_repository_group(
+    di_container: State<'_, DependencyContainer>,
+    id: u32,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `remove_repository_group` for repository group with id `{}`",
+        id
+    );
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = RemoveRepositoryGroupCommand::new(repository_group_repository);
+    let result = command.execute(&id).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to assign multiple git repositories to a repository group
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the repository group
+/// * `git_repository_ids` - The unique identifiers of the git repositories to assign
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn assign_git_repositories_to_group(
+    di_container: State<'_, DependencyContainer>,
+    id: u32,
+    git_repository_ids: Vec<u32>,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `assign_git_repositories_to_group` for repository group with id `{}`",
+        id
+    );
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = AssignGitRepositoriesToGroupCommand::new(repository_group_repository);
+    let result = command.execute(&id, &git_repository_ids).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to remove multiple git repositories from a repository group
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the repository group
+/// * `git_repository_ids` - The unique identifiers of the git repositories to remove
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn unassign_git_repositories_from_group(
+    di_container: State<'_, DependencyContainer>,
+    id: u32,
+    git_repository_ids: Vec<u32>,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `unassign_git_repositories_from_group` for repository group with id `{}`",
+        id
+    );
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = UnassignGitRepositoriesFromGroupCommand::new(repository_group_repository);
+    let result = command.execute(&id, &git_repository_ids).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to get the stored PATs that are expired or about to expire
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `days` - How many days before their expiry PATs are included, the configured warning period if not set
+///
+/// # Returns
+///
+/// * `Result<Vec<PatExpiryWarningDto>, String>` - The warnings, ordered by the expiry date
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_pat_expiry_warnings(
+    di_container: State<'_, DependencyContainer>,
+    days: Option<i64>,
+) -> Result<Vec<PatExpiryWarningDto>, String> {
+    log::info!("Invoking command `get_pat_expiry_warnings`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+    let settings_repository = (di_container.settings_repository_fac)(&di_container);
+    let query = GetPatExpiryWarningsQuery::new(
+        git_repository_repository,
+        pat_metadata_repository,
+        settings_repository,
+    );
+    let result = query.execute(days).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to set the expiry date of the PAT used by a single
+/// imported git repository
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `git_repository_id` - The unique identifier of a git repository using the PAT
+/// * `expires_at` - When the PAT expires, removed if not set
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn set_pat_expiry_date(
+    di_container: State<'_, DependencyContainer>,
+    git_repository_id: u32,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `set_pat_expiry_date` for git repository with id `{}`",
+        git_repository_id
+    );
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+    let command = SetPatExpiryDateCommand::new(git_repository_repository, pat_metadata_repository);
+    let result = command.execute(&git_repository_id, expires_at).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to get the current settings
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<SettingsDto, String>` - The current settings
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_settings(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<SettingsDto, String> {
+    log::info!("Invoking command `get_settings`");
+    let settings_repository = (di_container.settings_repository_fac)(&di_container);
+    let query = SettingsQuery::new(settings_repository);
+    let result = query.execute().await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to update the settings
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `settings` - The settings to apply
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn update_settings(
+    di_container: State<'_, DependencyContainer>,
+    settings: SettingsDto,
+) -> Result<(), String> {
+    log::info!("Invoking command `update_settings`");
+    let settings_repository = (di_container.settings_repository_fac)(&di_container);
+    let command = UpdateSettingsCommand::new(settings_repository);
+    let result = command.execute(&settings).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to unlock the encrypted secret storage with a passphrase,
+/// which creates it if it does not exist yet
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `passphrase` - The passphrase the secrets are encrypted with
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn unlock_secret_storage(
+    di_container: State<'_, DependencyContainer>,
+    passphrase: &str,
+) -> Result<(), String> {
+    log::info!("Invoking command `unlock_secret_storage`");
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let command = UnlockSecretStorageCommand::new(secret_repository);
+    let result = command.execute(passphrase).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to check that the secrets referenced by imported git
+/// repositories are present and to find orphaned ones
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<SecretHealthReportDto, String>` - The present, missing and orphaned secrets
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn check_secret_health(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<SecretHealthReportDto, String> {
+    log::info!("Invoking command `check_secret_health`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let query = CheckSecretHealthQuery::new(
+        git_repository_repository,
+        pat_metadata_repository,
+        secret_repository,
+    );
+    let result = query.execute().await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to migrate all secrets from one secret storage backend to
+/// another one, which is selected afterwards
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `from` - The backend to migrate the secrets from
+/// * `to` - The backend to migrate the secrets to
+/// * `remove_from_source` - Whether the migrated secrets are removed from the source, defaults to `false`
+///
+/// # Returns
+///
+/// * `Result<SecretMigrationResultDto, String>` - The number of migrated secrets and the keys missing in the source
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn migrate_secrets(
+    di_container: State<'_, DependencyContainer>,
+    from: SecretStorageBackend,
+    to: SecretStorageBackend,
+    remove_from_source: Option<bool>,
+) -> Result<SecretMigrationResultDto, String> {
+    log::info!("Invoking command `migrate_secrets`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+    let source_secret_repository =
+        (di_container.secret_storage_backend_fac)(&di_container, from.into());
+    let target_secret_repository =
+        (di_container.secret_storage_backend_fac)(&di_container, to.into());
+    let settings_repository = (di_container.settings_repository_fac)(&di_container);
+    let command = MigrateSecretsCommand::new(
+        git_repository_repository,
+        pat_metadata_repository,
+        source_secret_repository,
+        target_secret_repository,
+        settings_repository,
+    );
+    let result = command
+        .execute(from, to, remove_from_source.unwrap_or(false))
+        .await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to export the imported git repositories, repository groups
+/// and settings as versioned JSON document
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `passphrase` - Optional passphrase to include the PATs encrypted with it
+///
+/// # Returns
+///
+/// * `Result<String, String>` - The configuration as JSON document
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn export_configuration(
+    di_container: State<'_, DependencyContainer>,
+    passphrase: Option<String>,
+) -> Result<String, String> {
+    log::info!("Invoking command `export_configuration`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let settings_repository = (di_container.settings_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let passphrase_cipher = (di_container.passphrase_cipher_fac)();
+    let query = ExportConfigurationQuery::new(
+        git_repository_repository,
+        repository_group_repository,
+        settings_repository,
+        secret_repository,
+        passphrase_cipher,
+    );
+    let result = query.execute(passphrase.as_deref()).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to import a configuration exported before
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `content` - The configuration as JSON document
+/// * `conflict_resolution` - How to handle what is already configured, defaults to skipping it
+/// * `passphrase` - Optional passphrase to import the PATs included in the configuration
+///
+/// # Returns
+///
+/// * `Result<ConfigurationImportResultDto, String>` - What was created, updated and skipped
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn import_configuration(
+    di_container: State<'_, DependencyContainer>,
+    content: String,
+    conflict_resolution: Option<ConflictResolution>,
+    passphrase: Option<String>,
+) -> Result<ConfigurationImportResultDto, String> {
+    log::info!("Invoking command `import_configuration`");
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let settings_repository = (di_container.settings_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let passphrase_cipher = (di_container.passphrase_cipher_fac)();
+    let command = ImportConfigurationCommand::new(
+        git_repository_repository,
+        repository_group_repository,
+        settings_repository,
+        secret_repository,
+        passphrase_cipher,
+    );
+    let result = command
+        .execute(
+            &content,
+            conflict_resolution.unwrap_or_default(),
+            passphrase.as_deref(),
+        )
+        .await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to query for all profiles
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<Vec<ProfileDto>, String>` - The list of profiles, marking the active one
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_profiles(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<Vec<ProfileDto>, String> {
+    log::info!("Invoking command `get_profiles`");
+    let profile_repository = (di_container.profile_repository_fac)(&di_container);
+    let query = ProfilesQuery::new(profile_repository);
+    let result = query.execute().await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to create a new profile with its own database and secrets
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `name` - The name of the profile
+///
+/// # Returns
+///
+/// * `Result<ProfileDto, String>` - The created profile
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn create_profile(
+    di_container: State<'_, DependencyContainer>,
+    name: String,
+) -> Result<ProfileDto, String> {
+    log::info!("Invoking command `create_profile`");
+    let profile_repository = (di_container.profile_repository_fac)(&di_container);
+    let command = CreateProfileCommand::new(profile_repository);
+    let result = command.execute(&name).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to rename a profile
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the profile
+/// * `name` - The new name of the profile
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn rename_profile(
+    di_container: State<'_, DependencyContainer>,
+    id: String,
+    name: String,
+) -> Result<(), String> {
+    log::info!("Invoking command `rename_profile`");
+    let profile_repository = (di_container.profile_repository_fac)(&di_container);
+    let command = RenameProfileCommand::new(profile_repository);
+    let result = command.execute(&id, &name).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to remove a profile including its database and encrypted secrets
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the profile
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn remove_profile(
+    di_container: State<'_, DependencyContainer>,
+    id: String,
+) -> Result<(), String> {
+    log::info!("Invoking command `remove_profile`");
+    let profile_repository = (di_container.profile_repository_fac)(&di_container);
+    let command = RemoveProfileCommand::new(profile_repository);
+    let result = command.execute(&id).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to switch to another profile without restarting the application
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the profile to switch to
+///
+/// # Returns
+///
+/// * `Result<ProfileDto, String>` - The now active profile
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn switch_profile(
+    di_container: State<'_, DependencyContainer>,
+    id: String,
+) -> Result<ProfileDto, String> {
+    log::info!("Invoking command `switch_profile`");
+    let profile_repository = (di_container.profile_repository_fac)(&di_container);
+    let profile_activator = (di_container.profile_activator_fac)(&di_container);
+    let command = SwitchProfileCommand::new(profile_repository, profile_activator);
+    let result = command.execute(&id).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to back up the database of the active profile to a new file
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `file_path` - The path to the backup file, which must not exist yet
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn create_database_backup(
+    di_container: State<'_, DependencyContainer>,
+    file_path: String,
+) -> Result<(), String> {
+    log::info!("Invoking command `create_database_backup`");
+    let database_backup_repository = (di_container.database_backup_repository_fac)(&di_container);
+    let command = CreateDatabaseBackupCommand::new(database_backup_repository);
+    let result = command.execute(&file_path).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to restore the database of the active profile from a backup,
+/// after validating its schema version
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `file_path` - The path to the backup file
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn restore_database_backup(
+    di_container: State<'_, DependencyContainer>,
+    file_path: String,
+) -> Result<(), String> {
+    log::info!("Invoking command `restore_database_backup`");
+    let database_backup_repository = (di_container.database_backup_repository_fac)(&di_container);
+    let command = RestoreDatabaseBackupCommand::new(database_backup_repository);
+    let result = command.execute(&file_path).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to get the review metrics of the pull requests,
+/// such as the time to first review, within a date range
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `from` - The inclusive start of the date range
+/// * `to` - The exclusive end of the date range
+///
+/// # Returns
+///
+/// * `Result<PullRequestAnalyticsDto, String>` - The metrics in total and per repository, group and author
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_pull_request_analytics(
+    di_container: State<'_, DependencyContainer>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<PullRequestAnalyticsDto, String> {
+    log::info!("Invoking command `get_pull_request_analytics`");
+    let pull_request_lifecycle_repository =
+        (di_container.pull_request_lifecycle_repository_fac)(&di_container);
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let query = PullRequestAnalyticsQuery::new(
+        pull_request_lifecycle_repository,
+        git_repository_repository,
+        repository_group_repository,
+    );
+    let result = query.execute(&from, &to).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to get the workload of each reviewer across the open pull requests
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `group_id` - The optional unique identifier of the repository group to limit the pull requests to
+///
+/// # Returns
+///
+/// * `Result<Vec<ReviewerWorkloadDto>, String>` - The workload per reviewer
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_reviewer_workload(
+    di_container: State<'_, DependencyContainer>,
+    group_id: Option<u32>,
+) -> Result<Vec<ReviewerWorkloadDto>, String> {
+    log::info!("Invoking command `get_reviewer_workload`");
+    let azure_devops_repository = (di_container.azure_devops_repository_fac)(&di_container);
+    let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+    let secret_repository = (di_container.secret_repository_fac)(&di_container);
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let query = GetReviewerWorkloadQuery::new(
+        azure_devops_repository,
+        git_repository_repository,
+        secret_repository,
+        repository_group_repository,
+    );
+    let result = query.execute(group_id).await;
+    match result {
+        Ok(x) => Ok(x),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to get all SLA rules
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<Vec<SlaRuleDto>, String>` - The SLA rules ordered by name
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_sla_rules(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<Vec<SlaRuleDto>, String> {
+    log::info!("Invoking command `get_sla_rules`");
+    let sla_rule_repository = (di_container.sla_rule_repository_fac)(&di_container);
+    let query = SlaRulesQuery::new(sla_rule_repository);
+    let result = query.execute().await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to create a new SLA rule
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `sla_rule` - The SLA rule to create
+///
+/// # Returns
+///
+/// * `Result<u32, String>` - The unique identifier of the created SLA rule
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn create_sla_rule(
+    di_container: State<'_, DependencyContainer>,
+    sla_rule: SlaRuleDto,
+) -> Result<u32, String> {
+    log::info!(
+        "Invoking command `create_sla_rule` with name `{}`",
+        sla_rule.name
+    );
+    let sla_rule_repository = (di_container.sla_rule_repository_fac)(&di_container);
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = CreateSlaRuleCommand::new(sla_rule_repository, repository_group_repository);
+    let result = command.execute(&sla_rule).await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to update a single SLA rule
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `sla_rule` - The SLA rule with its new values
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn update_sla_rule(
+    di_container: State<'_, DependencyContainer>,
+    sla_rule: SlaRuleDto,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `update_sla_rule` for SLA rule with id `{}`",
+        sla_rule.id
+    );
+    let sla_rule_repository = (di_container.sla_rule_repository_fac)(&di_container);
+    let repository_group_repository = (di_container.repository_group_repository_fac)(&di_container);
+    let command = UpdateSlaRuleCommand::new(sla_rule_repository, repository_group_repository);
+    let result = command.execute(&sla_rule).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to remove a single SLA rule
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `id` - The unique identifier of the SLA rule to remove
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn remove_sla_rule(
+    di_container: State<'_, DependencyContainer>,
+    id: u32,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `remove_sla_rule` for SLA rule with id `{}`",
+        id
+    );
+    let sla_rule_repository = (di_container.sla_rule_repository_fac)(&di_container);
+    let command = RemoveSlaRuleCommand::new(sla_rule_repository);
+    let result = command.execute(&id).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to get the holidays excluded from business hours
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+///
+/// # Returns
+///
+/// * `Result<Vec<HolidayDto>, String>` - The holidays ordered by date
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn get_holidays(
+    di_container: State<'_, DependencyContainer>,
+) -> Result<Vec<HolidayDto>, String> {
+    log::info!("Invoking command `get_holidays`");
+    let sla_rule_repository = (di_container.sla_rule_repository_fac)(&di_container);
+    let query = HolidaysQuery::new(sla_rule_repository);
+    let result = query.execute().await;
+    match result {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Tauri command to replace the holidays excluded from business hours
+///
+/// # Arguments
+///
+/// * `di_container` - The container to resolve dependencies
+/// * `holidays` - The new holidays
+///
+/// # Errors
+///
+/// Any errors that might occur as string message
+#[tauri::command]
+pub async fn update_holidays(
+    di_container: State<'_, DependencyContainer>,
+    holidays: Vec<HolidayDto>,
+) -> Result<(), String> {
+    log::info!(
+        "Invoking command `update_holidays` with {} holidays",
+        holidays.len()
+    );
+    let sla_rule_repository = (di_container.sla_rule_repository_fac)(&di_container);
+    let command = UpdateHolidaysCommand::new(sla_rule_repository);
+    let result = command.execute(&holidays).await;
+    match result {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            log::error!("Error: {}", err.to_string());
+            Err(err.to_string())
+        }
+    }
+}
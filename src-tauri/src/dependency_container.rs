@@ -1,35 +1,140 @@
+use application::traits::SecretRepository;
+use domain::enums::SecretStorageBackend;
 use infrastructure::{
     azure_devops::repository::AzureDevOpsRestRepository,
-    database::{connection::init_db_connection, repositories::GitRepositoryDatabaseRepository},
-    secret_storage::KeyringRepository,
+    database::{
+        backup::DatabaseBackupSqliteRepository,
+        repositories::{
+            GitRepositoryDatabaseRepository, PatMetadataDatabaseRepository,
+            PullRequestAnnotationDatabaseRepository, PullRequestLifecycleDatabaseRepository,
+            PullRequestReviewDatabaseRepository, PullRequestStateDatabaseRepository,
+            PullRequestTriageDatabaseRepository, RepositoryGroupDatabaseRepository,
+            SlaRuleDatabaseRepository,
+        },
+    },
+    entra_id::repository::EntraIdRestRepository,
+    profiles::{ProfileFileRepository, active_profile::ActiveProfile},
+    secret_storage::{
+        configured::ConfiguredSecretRepository, passphrase_cipher::XChaChaPassphraseCipher,
+    },
+    settings::SettingsFileRepository,
 };
-use sqlx::SqlitePool;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 use tokio;
 
 /// Container that manages how dependencies are resolved
 pub struct DependencyContainer {
-    pub database_connection_pool: Arc<SqlitePool>,
+    pub active_profile: ActiveProfile,
+    pub profile_repository: ProfileFileRepository,
+    pub settings_repository: SettingsFileRepository,
     pub git_repository_repository_fac: fn(&Self) -> GitRepositoryDatabaseRepository,
-    pub azure_devops_repository_fac: fn() -> AzureDevOpsRestRepository,
-    pub secret_repository_fac: fn() -> KeyringRepository,
+    pub azure_devops_repository_fac: fn(&Self) -> AzureDevOpsRestRepository,
+    pub secret_repository_fac: fn(&Self) -> ConfiguredSecretRepository,
+    pub secret_storage_backend_fac: fn(&Self, SecretStorageBackend) -> Arc<dyn SecretRepository>,
+    pub settings_repository_fac: fn(&Self) -> SettingsFileRepository,
+    pub profile_repository_fac: fn(&Self) -> ProfileFileRepository,
+    pub profile_activator_fac: fn(&Self) -> ActiveProfile,
+    pub oauth_repository_fac: fn() -> EntraIdRestRepository,
+    pub passphrase_cipher_fac: fn() -> XChaChaPassphraseCipher,
+    pub pull_request_review_repository_fac: fn(&Self) -> PullRequestReviewDatabaseRepository,
+    pub pull_request_state_repository_fac: fn(&Self) -> PullRequestStateDatabaseRepository,
+    pub pull_request_triage_repository_fac: fn(&Self) -> PullRequestTriageDatabaseRepository,
+    pub pull_request_annotation_repository_fac:
+        fn(&Self) -> PullRequestAnnotationDatabaseRepository,
+    pub pull_request_lifecycle_repository_fac: fn(&Self) -> PullRequestLifecycleDatabaseRepository,
+    pub repository_group_repository_fac: fn(&Self) -> RepositoryGroupDatabaseRepository,
+    pub pat_metadata_repository_fac: fn(&Self) -> PatMetadataDatabaseRepository,
+    pub database_backup_repository_fac: fn(&Self) -> DatabaseBackupSqliteRepository,
+    pub sla_rule_repository_fac: fn(&Self) -> SlaRuleDatabaseRepository,
 }
 
 impl DependencyContainer {
     pub fn new(app_data_dir_path: &str) -> Self {
-        let database_connection_pool = tokio::runtime::Runtime::new()
+        let settings_repository =
+            SettingsFileRepository::new(Path::new(app_data_dir_path).join("settings.json"))
+                .expect("Could not read the settings");
+        let profile_repository = ProfileFileRepository::new(Path::new(app_data_dir_path).into())
+            .expect("Could not read the profiles");
+        let active_profile = tokio::runtime::Runtime::new()
             .expect("Tokio runtime needs to be created for syncronously setting up the connection pool at app start")
-            .block_on(init_db_connection(app_data_dir_path))
+            .block_on(ActiveProfile::load(
+                profile_repository.clone(),
+                settings_repository.clone(),
+            ))
             .expect("Could not create a connection to the database");
         Self {
-            database_connection_pool: Arc::new(database_connection_pool),
+            active_profile,
+            profile_repository,
+            settings_repository,
             git_repository_repository_fac: |di_container| {
-                GitRepositoryDatabaseRepository::new(Arc::clone(
-                    &di_container.database_connection_pool,
-                ))
+                GitRepositoryDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            azure_devops_repository_fac: |di_container| {
+                AzureDevOpsRestRepository::new(
+                    di_container.active_profile.change_statistics_cache(),
+                    di_container.active_profile.pull_request_iterations_cache(),
+                    di_container.active_profile.access_token_provider(),
+                )
+            },
+            secret_repository_fac: |di_container| di_container.active_profile.secret_repository(),
+            secret_storage_backend_fac: |di_container, backend| {
+                di_container
+                    .active_profile
+                    .secret_repository()
+                    .backend(backend)
+            },
+            settings_repository_fac: |di_container| di_container.settings_repository.clone(),
+            profile_repository_fac: |di_container| di_container.profile_repository.clone(),
+            profile_activator_fac: |di_container| di_container.active_profile.clone(),
+            oauth_repository_fac: EntraIdRestRepository::default,
+            passphrase_cipher_fac: XChaChaPassphraseCipher::default,
+            pull_request_review_repository_fac: |di_container| {
+                PullRequestReviewDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            pull_request_state_repository_fac: |di_container| {
+                PullRequestStateDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            pull_request_triage_repository_fac: |di_container| {
+                PullRequestTriageDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            pull_request_annotation_repository_fac: |di_container| {
+                PullRequestAnnotationDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            pull_request_lifecycle_repository_fac: |di_container| {
+                PullRequestLifecycleDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            repository_group_repository_fac: |di_container| {
+                RepositoryGroupDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            pat_metadata_repository_fac: |di_container| {
+                PatMetadataDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            database_backup_repository_fac: |di_container| {
+                DatabaseBackupSqliteRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
+            },
+            sla_rule_repository_fac: |di_container| {
+                SlaRuleDatabaseRepository::new(
+                    di_container.active_profile.database_connection_pool(),
+                )
             },
-            azure_devops_repository_fac: || AzureDevOpsRestRepository::default(),
-            secret_repository_fac: || KeyringRepository::new("pr-hub".to_string()),
         }
     }
 }
@@ -0,0 +1,67 @@
+use crate::dependency_container::DependencyContainer;
+use application::{
+    personal_access_tokens::get_pat_expiry_warnings::GetPatExpiryWarningsQuery,
+    pull_requests::cleanup_pull_request_annotations::CleanupPullRequestAnnotationsCommand,
+};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// How long notes and tags are kept after the pull request was closed
+const PULL_REQUEST_ANNOTATION_RETENTION_DAYS: i64 = 30;
+/// How often closed pull requests are checked for expired notes and tags
+const PULL_REQUEST_ANNOTATION_CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// How often the stored PATs are checked for their upcoming expiry
+const PAT_EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+/// The event emitted to the frontend when PATs are expired or about to expire
+const PAT_EXPIRY_WARNING_EVENT: &str = "pat-expiry-warning";
+
+/// Background job that periodically removes the notes and tags of pull
+/// requests that are closed for longer than the retention period
+///
+/// # Arguments
+///
+/// * `app_handle` - The handle to access the managed dependency container
+pub async fn cleanup_pull_request_annotations(app_handle: AppHandle) {
+    loop {
+        let di_container = app_handle.state::<DependencyContainer>();
+        let pull_request_annotation_repository =
+            (di_container.pull_request_annotation_repository_fac)(&di_container);
+        let command = CleanupPullRequestAnnotationsCommand::new(pull_request_annotation_repository);
+        let retention = chrono::Duration::days(PULL_REQUEST_ANNOTATION_RETENTION_DAYS);
+        match command.execute(&retention).await {
+            Ok(removed) => log::info!("Removed `{}` expired pull request annotations", removed),
+            Err(err) => log::error!("Error: {}", err.to_string()),
+        }
+        tokio::time::sleep(PULL_REQUEST_ANNOTATION_CLEANUP_INTERVAL).await;
+    }
+}
+
+/// Background job that periodically checks the stored PATs and notifies
+/// the frontend about the ones that are expired or about to expire
+///
+/// # Arguments
+///
+/// * `app_handle` - The handle to access the managed dependency container
+pub async fn check_pat_expiry(app_handle: AppHandle) {
+    loop {
+        let di_container = app_handle.state::<DependencyContainer>();
+        let git_repository_repository = (di_container.git_repository_repository_fac)(&di_container);
+        let pat_metadata_repository = (di_container.pat_metadata_repository_fac)(&di_container);
+        let settings_repository = (di_container.settings_repository_fac)(&di_container);
+        let query = GetPatExpiryWarningsQuery::new(
+            git_repository_repository,
+            pat_metadata_repository,
+            settings_repository,
+        );
+        match query.execute(None).await {
+            Ok(warnings) if !warnings.is_empty() => {
+                if let Err(err) = app_handle.emit(PAT_EXPIRY_WARNING_EVENT, warnings) {
+                    log::error!("Error: {}", err.to_string());
+                }
+            }
+            Ok(_) => {}
+            Err(err) => log::error!("Error: {}", err.to_string()),
+        }
+        tokio::time::sleep(PAT_EXPIRY_CHECK_INTERVAL).await;
+    }
+}
@@ -1,3 +1,4 @@
 pub mod app;
 pub mod commands;
 pub mod dependency_container;
+pub mod jobs;